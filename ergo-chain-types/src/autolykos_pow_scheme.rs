@@ -0,0 +1,153 @@
+//! Autolykos PoW solution
+
+use num_bigint::BigInt;
+use num_bigint::Sign;
+use sigma_ser::vlq_encode::ReadSigmaVlqExt;
+use sigma_ser::vlq_encode::WriteSigmaVlqExt;
+use sigma_ser::ScorexParsingError;
+use sigma_ser::ScorexSerializable;
+use sigma_ser::ScorexSerializationError;
+
+use crate::EcPoint;
+
+/// Solution for an Autolykos PoW puzzle. In Autolykos v.1 all the four fields are used, in
+/// Autolykos v.2 only `miner_pk` and `nonce` fields are used.
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AutolykosSolution {
+    /// Public key of miner. Part of Autolykos v1 and v2.
+    #[cfg_attr(feature = "json", serde(rename = "pk"))]
+    pub miner_pk: Box<EcPoint>,
+    /// One-time public key. Prevents revealing of miners secret. Autolykos v1 only.
+    #[cfg_attr(
+        feature = "json",
+        serde(rename = "w", default, skip_serializing_if = "Option::is_none")
+    )]
+    pub pow_onetime_pk: Option<Box<EcPoint>>,
+    /// nonce
+    #[cfg_attr(
+        feature = "json",
+        serde(
+            rename = "n",
+            serialize_with = "crate::json::serialize_bytes",
+            deserialize_with = "crate::json::deserialize_bytes"
+        )
+    )]
+    pub nonce: Vec<u8>,
+    /// Distance between pseudo-random number, corresponding to nonce `nonce` and a secret,
+    /// corresponding to `miner_pk`. The lower `pow_distance` is, the harder it was to find this
+    /// solution. Autolykos v1 only.
+    #[cfg_attr(
+        feature = "json",
+        serde(
+            rename = "d",
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "crate::json::bigint_as_number"
+        )
+    )]
+    pub pow_distance: Option<BigInt>,
+}
+
+impl AutolykosSolution {
+    /// Serialized nonce size in bytes
+    pub const NONCE_SIZE: usize = 8;
+
+    /// Serialize the solution for a header of the given version
+    pub fn serialize_bytes<W: WriteSigmaVlqExt>(
+        &self,
+        version: u8,
+        w: &mut W,
+    ) -> Result<(), ScorexSerializationError> {
+        if self.nonce.len() != Self::NONCE_SIZE {
+            return Err(ScorexSerializationError::Misc(
+                "AutolykosSolution: nonce must be 8 bytes",
+            ));
+        }
+        if version == 1 {
+            let pow_onetime_pk =
+                self.pow_onetime_pk
+                    .as_ref()
+                    .ok_or(ScorexSerializationError::Misc(
+                        "AutolykosSolution: pow_onetime_pk is missing",
+                    ))?;
+            let pow_distance = self
+                .pow_distance
+                .as_ref()
+                .ok_or(ScorexSerializationError::Misc(
+                    "AutolykosSolution: pow_distance is missing",
+                ))?;
+            self.miner_pk.scorex_serialize(w)?;
+            pow_onetime_pk.scorex_serialize(w)?;
+            w.write_all(&self.nonce)?;
+            let d_bytes = pow_distance.magnitude().to_bytes_be();
+            w.put_u8(u8::try_from(d_bytes.len())?)?;
+            w.write_all(&d_bytes)?;
+        } else {
+            self.miner_pk.scorex_serialize(w)?;
+            w.write_all(&self.nonce)?;
+        }
+        Ok(())
+    }
+
+    /// Parse the solution for a header of the given version
+    pub fn parse_bytes<R: ReadSigmaVlqExt>(
+        version: u8,
+        r: &mut R,
+    ) -> Result<Self, ScorexParsingError> {
+        let miner_pk = EcPoint::scorex_parse(r)?.into();
+        if version == 1 {
+            let pow_onetime_pk = Some(EcPoint::scorex_parse(r)?.into());
+            let mut nonce = vec![0u8; Self::NONCE_SIZE];
+            r.read_exact(&mut nonce)?;
+            let d_len = r.get_u8()?;
+            let mut d_bytes = vec![0u8; d_len as usize];
+            r.read_exact(&mut d_bytes)?;
+            Ok(AutolykosSolution {
+                miner_pk,
+                pow_onetime_pk,
+                nonce,
+                pow_distance: Some(BigInt::from_bytes_be(Sign::Plus, &d_bytes)),
+            })
+        } else {
+            let mut nonce = vec![0u8; Self::NONCE_SIZE];
+            r.read_exact(&mut nonce)?;
+            Ok(AutolykosSolution {
+                miner_pk,
+                pow_onetime_pk: None,
+                nonce,
+                pow_distance: None,
+            })
+        }
+    }
+}
+
+/// Arbitrary impl
+#[cfg(feature = "arbitrary")]
+mod arbitrary {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    impl Arbitrary for AutolykosSolution {
+        /// Header version (1 for Autolykos v1 solution)
+        type Parameters = u8;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(version: Self::Parameters) -> Self::Strategy {
+            (
+                any::<EcPoint>(),
+                any::<EcPoint>(),
+                vec(any::<u8>(), AutolykosSolution::NONCE_SIZE),
+                any::<u64>(),
+            )
+                .prop_map(move |(miner_pk, w, nonce, d)| AutolykosSolution {
+                    miner_pk: miner_pk.into(),
+                    pow_onetime_pk: (version == 1).then(|| w.into()),
+                    nonce,
+                    pow_distance: (version == 1).then(|| BigInt::from(d)),
+                })
+                .boxed()
+        }
+    }
+}
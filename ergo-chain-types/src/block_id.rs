@@ -0,0 +1,14 @@
+//! Main "remote" type for [`Header`](crate::Header) id
+
+use derive_more::Display;
+use derive_more::From;
+use derive_more::Into;
+
+use crate::Digest32;
+
+/// Block id
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json", serde(transparent))]
+#[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, From, Into, Display)]
+pub struct BlockId(pub Digest32);
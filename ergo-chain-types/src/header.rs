@@ -0,0 +1,282 @@
+//! Block header
+
+use num_bigint::BigInt;
+use sigma_ser::vlq_encode::ReadSigmaVlqExt;
+use sigma_ser::vlq_encode::WriteSigmaVlqExt;
+use sigma_ser::ScorexParsingError;
+use sigma_ser::ScorexSerializable;
+use sigma_ser::ScorexSerializationError;
+use sigma_ser::ScorexSerializeResult;
+use std::io::Write;
+
+use crate::blake2b256_hash;
+use crate::ADDigest;
+use crate::AutolykosSolution;
+use crate::BlockId;
+use crate::Digest32;
+use crate::EcPoint;
+use crate::Votes;
+
+/// Represents data of the block header available in Sigma propositions.
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Header {
+    /// Block version, to be increased on every soft and hardfork.
+    #[cfg_attr(feature = "json", serde(rename = "version"))]
+    pub version: u8,
+    /// Bytes representation of ModifierId of this Header
+    #[cfg_attr(feature = "json", serde(rename = "id"))]
+    pub id: BlockId,
+    /// Bytes representation of ModifierId of the parent block
+    #[cfg_attr(feature = "json", serde(rename = "parentId"))]
+    pub parent_id: BlockId,
+    /// Hash of ADProofs for transactions in a block
+    #[cfg_attr(feature = "json", serde(rename = "adProofsRoot"))]
+    pub ad_proofs_root: Digest32,
+    /// AvlTree of a state after block application
+    #[cfg_attr(feature = "json", serde(rename = "stateRoot"))]
+    pub state_root: ADDigest,
+    /// Root hash (for a Merkle tree) of transactions in a block.
+    #[cfg_attr(feature = "json", serde(rename = "transactionsRoot"))]
+    pub transaction_root: Digest32,
+    /// Timestamp of a block in ms from UNIX epoch
+    #[cfg_attr(feature = "json", serde(rename = "timestamp"))]
+    pub timestamp: u64,
+    /// Current difficulty in a compressed view.
+    #[cfg_attr(feature = "json", serde(rename = "nBits"))]
+    pub n_bits: u64,
+    /// Block height
+    #[cfg_attr(feature = "json", serde(rename = "height"))]
+    pub height: u32,
+    /// Root hash of extension section
+    #[cfg_attr(feature = "json", serde(rename = "extensionHash"))]
+    pub extension_root: Digest32,
+    /// Solution for an Autolykos PoW puzzle
+    #[cfg_attr(feature = "json", serde(rename = "powSolutions"))]
+    pub autolykos_solution: AutolykosSolution,
+    /// Miner votes for changing system parameters.
+    #[cfg_attr(feature = "json", serde(rename = "votes"))]
+    pub votes: Votes,
+}
+
+impl Header {
+    /// Header version of the first (Autolykos v1) blocks
+    pub const INITIAL_VERSION: u8 = 1;
+
+    /// Serialize the header without the PoW solution (the message of the PoW puzzle)
+    pub fn serialize_without_pow(&self) -> Result<Vec<u8>, ScorexSerializationError> {
+        let mut data = Vec::new();
+        let w = &mut data;
+        w.put_u8(self.version)?;
+        self.parent_id.0.scorex_serialize(w)?;
+        self.ad_proofs_root.scorex_serialize(w)?;
+        self.transaction_root.scorex_serialize(w)?;
+        self.state_root.scorex_serialize(w)?;
+        w.put_u64(self.timestamp)?;
+        self.extension_root.scorex_serialize(w)?;
+        // n_bits fits into u32 and is serialized as 4 bytes in big-endian format
+        w.put_u32_be_bytes(u32::try_from(self.n_bits)?)?;
+        w.put_u32(self.height)?;
+        w.write_all(&self.votes.0)?;
+        // since version 2 the header carries the length of the (yet unused) extra fields
+        if self.version > Self::INITIAL_VERSION {
+            w.put_u8(0)?;
+        }
+        Ok(data)
+    }
+
+    /// Compute the header id (hash of the serialized header)
+    pub fn compute_id(&self) -> Result<BlockId, ScorexSerializationError> {
+        let bytes = self.scorex_serialize_bytes()?;
+        Ok(BlockId(blake2b256_hash(&bytes)))
+    }
+
+    /// Public key of the miner (part of the PoW solution)
+    pub fn miner_pk(&self) -> &EcPoint {
+        &self.autolykos_solution.miner_pk
+    }
+
+    /// One-time public key of the PoW solution (group generator for Autolykos v2 headers)
+    pub fn pow_onetime_pk(&self) -> EcPoint {
+        self.autolykos_solution
+            .pow_onetime_pk
+            .as_deref()
+            .cloned()
+            .unwrap_or_else(crate::ec_point::generator)
+    }
+
+    /// Distance of the PoW solution (zero for Autolykos v2 headers)
+    pub fn pow_distance(&self) -> BigInt {
+        self.autolykos_solution
+            .pow_distance
+            .clone()
+            .unwrap_or_default()
+    }
+}
+
+impl ScorexSerializable for Header {
+    fn scorex_serialize<W: WriteSigmaVlqExt>(&self, w: &mut W) -> ScorexSerializeResult {
+        w.write_all(&self.serialize_without_pow()?)?;
+        self.autolykos_solution.serialize_bytes(self.version, w)?;
+        Ok(())
+    }
+
+    fn scorex_parse<R: ReadSigmaVlqExt>(r: &mut R) -> Result<Self, ScorexParsingError> {
+        let version = r.get_u8()?;
+        let parent_id = BlockId(Digest32::scorex_parse(r)?);
+        let ad_proofs_root = Digest32::scorex_parse(r)?;
+        let transaction_root = Digest32::scorex_parse(r)?;
+        let state_root = ADDigest::scorex_parse(r)?;
+        let timestamp = r.get_u64()?;
+        let extension_root = Digest32::scorex_parse(r)?;
+        let mut n_bits_bytes = [0u8; 4];
+        r.read_exact(&mut n_bits_bytes)?;
+        let n_bits = u32::from_be_bytes(n_bits_bytes) as u64;
+        let height = r.get_u32()?;
+        let mut votes_bytes = [0u8; 3];
+        r.read_exact(&mut votes_bytes)?;
+        if version > Self::INITIAL_VERSION {
+            // extra fields are not used yet, so they are skipped
+            let extra_fields_len = r.get_u8()?;
+            let mut extra_fields = vec![0u8; extra_fields_len as usize];
+            r.read_exact(&mut extra_fields)?;
+        }
+        let autolykos_solution = AutolykosSolution::parse_bytes(version, r)?;
+        let mut header = Header {
+            version,
+            id: BlockId(Digest32::zero()),
+            parent_id,
+            ad_proofs_root,
+            state_root,
+            transaction_root,
+            timestamp,
+            n_bits,
+            height,
+            extension_root,
+            autolykos_solution,
+            votes: Votes(votes_bytes),
+        };
+        header.id = header
+            .compute_id()
+            .map_err(|e| ScorexParsingError::Misc(format!("cannot compute header id: {0}", e)))?;
+        Ok(header)
+    }
+}
+
+/// Arbitrary impl
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod arbitrary {
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Header {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (1u8..=2)
+                .prop_flat_map(|version| {
+                    (
+                        Just(version),
+                        any::<BlockId>(),
+                        any::<Digest32>(),
+                        any::<ADDigest>(),
+                        any::<Digest32>(),
+                        any::<u64>(),
+                        any::<u32>(),
+                        0..i32::MAX as u32,
+                        any::<Digest32>(),
+                        any_with::<AutolykosSolution>(version),
+                        any::<Votes>(),
+                    )
+                })
+                .prop_map(
+                    |(
+                        version,
+                        parent_id,
+                        ad_proofs_root,
+                        state_root,
+                        transaction_root,
+                        timestamp,
+                        n_bits,
+                        height,
+                        extension_root,
+                        autolykos_solution,
+                        votes,
+                    )| {
+                        let mut header = Header {
+                            version,
+                            id: BlockId(Digest32::zero()),
+                            parent_id,
+                            ad_proofs_root,
+                            state_root,
+                            transaction_root,
+                            timestamp,
+                            n_bits: n_bits as u64,
+                            height,
+                            extension_root,
+                            autolykos_solution,
+                            votes,
+                        };
+                        header.id = header.compute_id().unwrap();
+                        header
+                    },
+                )
+                .boxed()
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use sigma_ser::scorex_serialize_roundtrip;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Header>()) {
+            prop_assert_eq![scorex_serialize_roundtrip(&v), v];
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn parse_node_json_and_compute_id() {
+        let json = r#"{
+            "extensionId": "d16f25b14457186df4c5f6355579cc769261ce1aebc8209949ca6feadbac5a3f",
+            "difficulty": "626412390187008",
+            "votes": "040000",
+            "timestamp": 1618929697400,
+            "size": 221,
+            "stateRoot": "8ad868627ea4f7de6e2a2fe3f98fafe57f914e0f2ef3331c006def36c697f92713",
+            "height": 471746,
+            "nBits": 117586360,
+            "version": 2,
+            "id": "4caa17e62fe66ba7bd69597afdc996ae35b1ff12e0ba90c22ff288a4de10e91b",
+            "adProofsRoot": "d882aaf42e0a95eb95fcce5c3705adf758e591532f733efe790ac3c404730c39",
+            "transactionsRoot": "63eaa9aff76a1de3d71c81e4b2d92e8d97ae572a8e9ab9e66599ed0912dd2f8b",
+            "extensionHash": "3f91f3c680beb26615fdec251aee3f81aaf5a02740806c167c0f3c929471df44",
+            "powSolutions": {
+              "pk": "02b3a06d6eaa8671431ba1db4dd427a77f75a5c2acbd71bfb725d38adc2b55f669",
+              "w": "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+              "n": "5939ecfee6b0d7f4",
+              "d": 0
+            },
+            "adProofsId": "86eaa41f328bee598e33e52c9e515952ad3b7874102f762847f17318a776a7ae",
+            "transactionsId": "ac80245714f25aa2fafe5494ad02a26d46e7955b8f5709f3659f1b9440797b3e",
+            "parentId": "6481752bace5fa5acba5d5ef7124d48826664742d46c974c98a2d60ace229a34"
+        }"#;
+        let header: Header = serde_json::from_str(json).unwrap();
+        assert_eq!(header.height, 471746);
+        assert_eq!(header.compute_id().unwrap(), header.id);
+        let reparsed =
+            Header::scorex_parse_bytes(&header.scorex_serialize_bytes().unwrap()).unwrap();
+        assert_eq!(reparsed.id, header.id);
+    }
+}
@@ -0,0 +1,56 @@
+//! JSON serialization helpers
+
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+use serde::de::Error;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::Base16DecodedBytes;
+
+/// Serialize bytes as a Base16 encoded string
+pub(crate) fn serialize_bytes<S, T>(bytes: T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<[u8]>,
+{
+    serializer.serialize_str(&base16::encode_lower(bytes.as_ref()))
+}
+
+/// Deserialize bytes from a Base16 encoded string
+pub(crate) fn deserialize_bytes<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Base16DecodedBytes::deserialize(deserializer).map(|b| b.0)
+}
+
+/// Optional BigInt encoded as a JSON number (as the node does), without loss of precision
+pub(crate) mod bigint_as_number {
+    use super::*;
+    use serde::Serialize;
+
+    pub(crate) fn serialize<S>(v: &Option<BigInt>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match v {
+            Some(v) => serde_json::Number::from_str(&v.to_string())
+                .map_err(serde::ser::Error::custom)?
+                .serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<BigInt>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let n = serde_json::Number::deserialize(deserializer)?;
+        BigInt::from_str(&n.to_string())
+            .map(Some)
+            .map_err(|e| D::Error::custom(format!("invalid BigInt {0}: {1}", n, e)))
+    }
+}
@@ -18,15 +18,28 @@
 #![deny(clippy::unreachable)]
 #![deny(clippy::panic)]
 
+mod autolykos_pow_scheme;
 mod base16_bytes;
+mod block_id;
 mod digest32;
 pub mod ec_point;
+mod header;
+#[cfg(feature = "json")]
+mod json;
+mod preheader;
+mod votes;
 
+pub use autolykos_pow_scheme::AutolykosSolution;
 pub use base16_bytes::Base16DecodedBytes;
 pub use base16_bytes::Base16EncodedBytes;
+pub use block_id::BlockId;
 pub use digest32::blake2b256_hash;
 pub use digest32::ADDigest;
 pub use digest32::Digest;
 pub use digest32::Digest32;
 pub use digest32::DigestNError;
 pub use ec_point::EcPoint;
+pub use header::Header;
+pub use preheader::PreHeader;
+pub use votes::Votes;
+pub use votes::VotesError;
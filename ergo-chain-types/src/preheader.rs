@@ -0,0 +1,60 @@
+//! Block header with the current `spendingTransaction`, that can be predicted by a miner before
+//! it's formation
+
+use crate::BlockId;
+use crate::EcPoint;
+use crate::Header;
+use crate::Votes;
+
+/// Block header with the current `spendingTransaction`, that can be predicted by a miner before
+/// it's formation
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json", serde(rename_all = "camelCase"))]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct PreHeader {
+    /// Block version, to be increased on every soft and hardfork
+    pub version: u8,
+    /// Hash of parent block
+    pub parent_id: BlockId,
+    /// Timestamp of a block in ms from UNIX epoch
+    pub timestamp: u64,
+    /// Current difficulty in a compressed view.
+    pub n_bits: u64,
+    /// Block height
+    pub height: u32,
+    /// Public key of miner
+    #[cfg_attr(feature = "json", serde(rename = "minerPk"))]
+    pub miner_pk: Box<EcPoint>,
+    /// Votes
+    pub votes: Votes,
+}
+
+impl From<Header> for PreHeader {
+    fn from(bh: Header) -> Self {
+        PreHeader {
+            version: bh.version,
+            parent_id: bh.parent_id,
+            timestamp: bh.timestamp,
+            n_bits: bh.n_bits,
+            height: bh.height,
+            miner_pk: bh.autolykos_solution.miner_pk,
+            votes: bh.votes,
+        }
+    }
+}
+
+/// Arbitrary impl
+#[cfg(feature = "arbitrary")]
+mod arbitrary {
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for PreHeader {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any::<Header>().prop_map(PreHeader::from).boxed()
+        }
+    }
+}
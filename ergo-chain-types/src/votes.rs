@@ -0,0 +1,63 @@
+//! Main "remote" type for [`Header`](crate::Header) votes
+
+use std::convert::TryFrom;
+use std::convert::TryInto;
+
+use thiserror::Error;
+
+use crate::Base16DecodedBytes;
+use crate::Base16EncodedBytes;
+
+/// Votes for changing system parameters
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "json",
+    serde(into = "Base16EncodedBytes", try_from = "Base16DecodedBytes")
+)]
+#[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct Votes(pub [u8; 3]);
+
+impl Votes {
+    /// All zeros (no votes)
+    pub fn zero() -> Votes {
+        Votes([0u8; 3])
+    }
+}
+
+/// Votes errors
+#[derive(Error, Debug)]
+pub enum VotesError {
+    /// Invalid byte array size
+    #[error("Votes: Invalid byte array size ({0})")]
+    InvalidSize(#[from] std::array::TryFromSliceError),
+}
+
+impl TryFrom<Vec<u8>> for Votes {
+    type Error = VotesError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        let arr: [u8; 3] = bytes.as_slice().try_into()?;
+        Ok(Self(arr))
+    }
+}
+
+impl TryFrom<Base16DecodedBytes> for Votes {
+    type Error = VotesError;
+
+    fn try_from(bytes: Base16DecodedBytes) -> Result<Self, Self::Error> {
+        bytes.0.try_into()
+    }
+}
+
+impl From<Votes> for Base16EncodedBytes {
+    fn from(v: Votes) -> Self {
+        Base16EncodedBytes::new(v.0.as_ref())
+    }
+}
+
+impl From<Votes> for Vec<u8> {
+    fn from(v: Votes) -> Self {
+        v.0.to_vec()
+    }
+}
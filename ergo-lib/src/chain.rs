@@ -3,7 +3,9 @@
 #[cfg(feature = "json")]
 pub mod json;
 
+pub mod avl_tree_prover;
 pub mod contract;
 pub mod ergo_box;
 pub mod ergo_state_context;
+pub mod parameters;
 pub mod transaction;
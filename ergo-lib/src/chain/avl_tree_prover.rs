@@ -0,0 +1,387 @@
+//! Batch AVL+ prover for building authenticated dictionary (AvlTree) proofs off-chain
+use bytes::Bytes;
+use ergo_avltree_rust::authenticated_tree_ops::AuthenticatedTreeOps;
+use ergo_avltree_rust::batch_avl_prover::BatchAVLProver;
+use ergo_avltree_rust::batch_node::AVLTree;
+use ergo_avltree_rust::batch_node::Node;
+use ergo_avltree_rust::batch_node::NodeHeader;
+use ergo_avltree_rust::batch_node::NodeId;
+use ergo_avltree_rust::operation::KeyValue;
+use ergo_avltree_rust::operation::Operation;
+use ergo_chain_types::ADDigest;
+use ergo_chain_types::Digest32;
+use ergotree_ir::mir::avl_tree_data::AvlTreeData;
+use ergotree_ir::mir::avl_tree_data::AvlTreeFlags;
+use thiserror::Error;
+
+/// Errors of the AVL+ prover
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum AvlTreeProverError {
+    /// Operation could not be applied to the tree (e.g. inserting an existing key)
+    #[error("operation failed: {0}")]
+    OperationFailed(String),
+    /// Snapshot requested while there are operations not yet included in a proof
+    #[error("there are operations pending, generate a proof before taking a snapshot")]
+    PendingOperations,
+}
+
+/// Snapshot of the prover's tree taken with [`AvlTreeProver::snapshot`]
+#[derive(Clone)]
+pub struct AvlTreeSnapshot {
+    root: NodeId,
+    height: usize,
+    digest: ADDigest,
+}
+
+impl AvlTreeSnapshot {
+    /// Digest of the tree at the moment the snapshot was taken
+    pub fn digest(&self) -> ADDigest {
+        self.digest
+    }
+}
+
+impl std::fmt::Debug for AvlTreeSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AvlTreeSnapshot")
+            .field("digest", &self.digest)
+            .finish()
+    }
+}
+
+/// Batch AVL+ prover keeping the whole authenticated tree in memory.
+/// Operations are accumulated until [`AvlTreeProver::generate_proof`] is called, which returns
+/// the proof for all of them, to be checked by the AvlTree methods (`contains`, `insert`, etc.)
+/// against the tree digest taken before the operations.
+pub struct AvlTreeProver {
+    prover: BatchAVLProver,
+    has_pending_operations: bool,
+}
+
+impl AvlTreeProver {
+    /// Create an empty tree with the given key length and value length (if values are of fixed length)
+    pub fn new(key_length: usize, value_length_opt: Option<usize>) -> AvlTreeProver {
+        AvlTreeProver {
+            prover: BatchAVLProver::new(empty_tree(key_length, value_length_opt), true),
+            has_pending_operations: false,
+        }
+    }
+
+    /// Key length of the tree
+    pub fn key_length(&self) -> usize {
+        self.prover.base.tree.key_length
+    }
+
+    /// Value length of the tree (if values are of fixed length)
+    pub fn value_length_opt(&self) -> Option<usize> {
+        self.prover.base.tree.value_length
+    }
+
+    /// Current digest of the tree (root hash and tree height), including the pending operations
+    pub fn digest(&self) -> ADDigest {
+        #[allow(clippy::unwrap_used)]
+        // the root of the prover's tree is always set
+        let digest = self.prover.digest().unwrap();
+        #[allow(clippy::unwrap_used)]
+        // digest is always root label (32 bytes) followed by the tree height byte
+        ADDigest::try_from(digest.to_vec()).unwrap()
+    }
+
+    /// Current root hash of the tree, including the pending operations
+    pub fn root_hash(&self) -> Digest32 {
+        let digest = self.digest();
+        #[allow(clippy::unwrap_used)]
+        Digest32::try_from(&digest.0[..Digest32::SIZE]).unwrap()
+    }
+
+    /// Current tree as AvlTreeData with the given operation flags
+    pub fn avl_tree_data(&self, tree_flags: AvlTreeFlags) -> AvlTreeData {
+        AvlTreeData {
+            digest: self.digest(),
+            tree_flags,
+            key_length: self.key_length() as u32,
+            value_length_opt: self.value_length_opt().map(|v| Box::new(v as u32)),
+        }
+    }
+
+    /// Insert a new key into the tree, fails if the key is already in the tree
+    pub fn insert(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), AvlTreeProverError> {
+        self.perform(Operation::Insert(KeyValue {
+            key: Bytes::from(key),
+            value: Bytes::from(value),
+        }))
+        .map(|_| ())
+    }
+
+    /// Update the value of the key, fails if the key is not in the tree.
+    /// Returns the old value.
+    pub fn update(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<Vec<u8>, AvlTreeProverError> {
+        self.perform(Operation::Update(KeyValue {
+            key: Bytes::from(key),
+            value: Bytes::from(value),
+        }))
+        .and_then(old_value)
+    }
+
+    /// Insert the key or update its value if it's already in the tree.
+    /// Returns the old value (if any).
+    pub fn insert_or_update(
+        &mut self,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>, AvlTreeProverError> {
+        self.perform(Operation::InsertOrUpdate(KeyValue {
+            key: Bytes::from(key),
+            value: Bytes::from(value),
+        }))
+    }
+
+    /// Remove the key from the tree, fails if the key is not in the tree.
+    /// Returns the removed value.
+    pub fn remove(&mut self, key: Vec<u8>) -> Result<Vec<u8>, AvlTreeProverError> {
+        self.perform(Operation::Remove(Bytes::from(key)))
+            .and_then(old_value)
+    }
+
+    /// Look up the key, proving its presence (or absence) in the next proof
+    pub fn lookup(&mut self, key: Vec<u8>) -> Result<Option<Vec<u8>>, AvlTreeProverError> {
+        self.perform(Operation::Lookup(Bytes::from(key)))
+    }
+
+    /// Look up the key without including it in the next proof
+    pub fn unauthenticated_lookup(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.prover
+            .unauthenticated_lookup(&Bytes::copy_from_slice(key))
+            .map(|v| v.to_vec())
+    }
+
+    /// Generate the proof for all the operations performed (except the failed ones) since the last
+    /// `generate_proof` call
+    pub fn generate_proof(&mut self) -> Vec<u8> {
+        self.has_pending_operations = false;
+        self.prover.generate_proof().to_vec()
+    }
+
+    /// Take a snapshot of the tree to be restored later with [`AvlTreeProver::restore`].
+    /// Fails if there are operations performed since the last `generate_proof` call.
+    pub fn snapshot(&self) -> Result<AvlTreeSnapshot, AvlTreeProverError> {
+        if self.has_pending_operations {
+            return Err(AvlTreeProverError::PendingOperations);
+        }
+        let tree = &self.prover.base.tree;
+        #[allow(clippy::unwrap_used)]
+        // the root of the prover's tree is always set
+        let root = tree.root.clone().unwrap();
+        Ok(AvlTreeSnapshot {
+            root,
+            height: tree.height,
+            digest: self.digest(),
+        })
+    }
+
+    /// Roll the tree back to the snapshot, discarding the pending operations.
+    /// The snapshot must be taken from this prover.
+    pub fn restore(&mut self, snapshot: &AvlTreeSnapshot) {
+        // nodes of the snapshot are shared with the current tree and might have been marked as
+        // visited by the discarded operations
+        reset_nodes(&snapshot.root);
+        let mut tree = empty_tree(self.key_length(), self.value_length_opt());
+        tree.root = Some(snapshot.root.clone());
+        tree.height = snapshot.height;
+        self.prover = BatchAVLProver::new(tree, true);
+        self.has_pending_operations = false;
+    }
+
+    fn perform(&mut self, operation: Operation) -> Result<Option<Vec<u8>>, AvlTreeProverError> {
+        let res = self
+            .prover
+            .perform_one_operation(&operation)
+            .map_err(|e| AvlTreeProverError::OperationFailed(e.to_string()))?;
+        self.has_pending_operations = true;
+        Ok(res.map(|v| v.to_vec()))
+    }
+}
+
+impl std::fmt::Debug for AvlTreeProver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AvlTreeProver")
+            .field("digest", &self.digest())
+            .field("key_length", &self.key_length())
+            .field("value_length_opt", &self.value_length_opt())
+            .finish()
+    }
+}
+
+fn empty_tree(key_length: usize, value_length_opt: Option<usize>) -> AVLTree {
+    // the whole tree is kept in memory, so label-only nodes are never resolved
+    AVLTree::new(
+        |digest| Node::LabelOnly(NodeHeader::new(Some(*digest), None)),
+        key_length,
+        value_length_opt,
+    )
+}
+
+fn old_value(value: Option<Vec<u8>>) -> Result<Vec<u8>, AvlTreeProverError> {
+    value.ok_or_else(|| AvlTreeProverError::OperationFailed("key not found".to_string()))
+}
+
+fn reset_nodes(node: &NodeId) {
+    let children = match &mut *node.borrow_mut() {
+        Node::Internal(n) => {
+            n.hdr.visited = false;
+            n.hdr.is_new = false;
+            Some((n.left.clone(), n.right.clone()))
+        }
+        Node::Leaf(n) => {
+            n.hdr.visited = false;
+            n.hdr.is_new = false;
+            None
+        }
+        Node::LabelOnly(_) => None,
+    };
+    if let Some((left, right)) = children {
+        reset_nodes(&left);
+        reset_nodes(&right);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ergo_avltree_rust::batch_avl_verifier::BatchAVLVerifier;
+    use ergotree_interpreter::eval::context::Context;
+    use ergotree_interpreter::eval::reduce_to_crypto;
+    use ergotree_interpreter::eval::ReductionOptions;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::method_call::MethodCall;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+    use ergotree_ir::types::savltree;
+    use sigma_test_util::force_any_val;
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn key(i: u8) -> Vec<u8> {
+        vec![i; 32]
+    }
+
+    fn prover_with_keys(n: u8) -> AvlTreeProver {
+        let mut prover = AvlTreeProver::new(32, None);
+        for i in 1..=n {
+            prover.insert(key(i), vec![i]).unwrap();
+        }
+        prover.generate_proof();
+        prover
+    }
+
+    fn verify(
+        digest: ADDigest,
+        proof: Vec<u8>,
+        operations: &[Operation],
+        expected_digest: ADDigest,
+    ) {
+        let tree = empty_tree(32, None);
+        let mut verifier = BatchAVLVerifier::new(
+            &Bytes::copy_from_slice(&digest.0),
+            &Bytes::from(proof),
+            tree,
+            None,
+            None,
+        )
+        .unwrap();
+        for op in operations {
+            verifier.perform_one_operation(op).unwrap();
+        }
+        assert_eq!(
+            verifier.digest().unwrap().to_vec(),
+            Vec::<u8>::from(expected_digest)
+        );
+    }
+
+    #[test]
+    fn proof_is_verified() {
+        let mut prover = prover_with_keys(10);
+        let digest = prover.digest();
+        prover.insert(key(11), vec![11]).unwrap();
+        assert_eq!(prover.update(key(1), vec![100]).unwrap(), vec![1]);
+        assert_eq!(prover.remove(key(2)).unwrap(), vec![2]);
+        assert_eq!(prover.lookup(key(3)).unwrap(), Some(vec![3]));
+        let proof = prover.generate_proof();
+        let operations = [
+            Operation::Insert(KeyValue {
+                key: key(11).into(),
+                value: vec![11].into(),
+            }),
+            Operation::Update(KeyValue {
+                key: key(1).into(),
+                value: vec![100].into(),
+            }),
+            Operation::Remove(key(2).into()),
+            Operation::Lookup(key(3).into()),
+        ];
+        verify(digest, proof, &operations, prover.digest());
+    }
+
+    #[test]
+    fn failed_operations() {
+        let mut prover = prover_with_keys(3);
+        assert!(prover.insert(key(1), vec![1]).is_err());
+        assert!(prover.update(key(4), vec![4]).is_err());
+        assert!(prover.remove(key(4)).is_err());
+        assert_eq!(prover.lookup(key(4)).unwrap(), None);
+        assert_eq!(prover.unauthenticated_lookup(&key(2)), Some(vec![2]));
+    }
+
+    #[test]
+    fn proof_is_accepted_by_interpreter() {
+        let mut prover = prover_with_keys(5);
+        let tree = prover.avl_tree_data(AvlTreeFlags::new(true, true, true));
+        prover.lookup(key(4)).unwrap();
+        let proof = prover.generate_proof();
+        let expr: Expr = MethodCall::new(
+            tree.into(),
+            savltree::CONTAINS_METHOD.clone(),
+            vec![key(4).into(), proof.into()],
+        )
+        .unwrap()
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            reduce_to_crypto(&expr, ctx, ReductionOptions::default())
+                .unwrap()
+                .sigma_prop,
+            SigmaBoolean::TrivialProp(true)
+        );
+    }
+
+    #[test]
+    fn snapshot_restore() {
+        let mut prover = prover_with_keys(5);
+        let snapshot = prover.snapshot().unwrap();
+        assert_eq!(snapshot.digest(), prover.digest());
+
+        prover.insert(key(6), vec![6]).unwrap();
+        prover.remove(key(1)).unwrap();
+        assert_eq!(
+            prover.snapshot().unwrap_err(),
+            AvlTreeProverError::PendingOperations
+        );
+        prover.restore(&snapshot);
+        assert_eq!(prover.digest(), snapshot.digest());
+        assert_eq!(prover.unauthenticated_lookup(&key(1)), Some(vec![1]));
+        assert_eq!(prover.unauthenticated_lookup(&key(6)), None);
+
+        // proofs after restoring are verified against the snapshot digest
+        prover.insert(key(7), vec![7]).unwrap();
+        let proof = prover.generate_proof();
+        let operations = [Operation::Insert(KeyValue {
+            key: key(7).into(),
+            value: vec![7].into(),
+        })];
+        verify(snapshot.digest(), proof, &operations, prover.digest());
+
+        // restoring after the proof is generated rolls back the committed operations as well
+        prover.restore(&snapshot);
+        assert_eq!(prover.digest(), snapshot.digest());
+        assert_eq!(prover.unauthenticated_lookup(&key(7)), None);
+    }
+}
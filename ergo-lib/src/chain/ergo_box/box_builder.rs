@@ -1,7 +1,7 @@
 //! ErgoBoxCandidate builder
 
 use std::collections::HashMap;
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 
 use ergotree_ir::chain::address::AddressEncoderError;
 use ergotree_ir::chain::ergo_box::box_value::BoxValue;
@@ -126,10 +126,17 @@ impl ErgoBoxCandidateBuilder {
     /// Calculate minimal box value for the current box serialized size(in bytes)
     pub fn calc_min_box_value(&self) -> Result<BoxValue, ErgoBoxCandidateBuilderError> {
         let box_size_bytes = self.calc_box_size_bytes()?;
+        Ok(self.min_box_value(box_size_bytes))
+    }
 
+    /// Minimal box value for the given box size, but not less than [`BoxValue::MIN`]
+    /// (boxes smaller than the minimal box size are charged as the minimal box)
+    fn min_box_value(&self, box_size_bytes: usize) -> BoxValue {
+        let min_value =
+            (box_size_bytes as u64 * self.min_value_per_byte as u64).max(BoxValue::MIN_RAW);
         // Won't be overflowing an i64, so unwrap is safe.
         #[allow(clippy::unwrap_used)]
-        Ok(BoxValue::try_from(box_size_bytes as i64 * self.min_value_per_byte as i64).unwrap())
+        BoxValue::try_from(min_value).unwrap()
     }
 
     /// Set register with a given id (R4-R9) to the given value
@@ -253,12 +260,7 @@ impl ErgoBoxCandidateBuilder {
     fn build_box(&self) -> Result<ErgoBoxCandidate, ErgoBoxCandidateBuilderError> {
         let b = self.build_box_unchecked()?;
         let box_size_bytes = b.sigma_serialize_bytes()?.len();
-
-        // Won't be overflowing an i64, so unwrap is safe.
-        #[allow(clippy::unwrap_used)]
-        let min_box_value: BoxValue = (box_size_bytes as i64 * self.min_value_per_byte as i64)
-            .try_into()
-            .unwrap();
+        let min_box_value = self.min_box_value(box_size_bytes);
         if self.value >= min_box_value {
             Ok(b)
         } else {
@@ -282,6 +284,10 @@ mod tests {
 
     use ergotree_ir::base16_str::Base16Str;
     use ergotree_ir::chain::token::TokenId;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
     use sigma_test_util::force_any_val;
     use NonMandatoryRegisterId::*;
 
@@ -333,12 +339,31 @@ mod tests {
     fn test_calc_min_box_value() {
         let builder =
             ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, force_any_val::<ErgoTree>(), 1);
-        assert!(builder.calc_min_box_value().unwrap() > BoxValue::MIN);
+        let box_size_bytes = builder.calc_box_size_bytes().unwrap() as u64;
+        let expected =
+            (box_size_bytes * BoxValue::MIN_VALUE_PER_BOX_BYTE as u64).max(BoxValue::MIN_RAW);
+        assert_eq!(builder.calc_min_box_value().unwrap().as_u64(), &expected);
+    }
+
+    #[test]
+    fn test_calc_min_box_value_small_box() {
+        let expr: Expr = SigmaProp::new(SigmaBoolean::TrivialProp(true)).into();
+        let builder = ErgoBoxCandidateBuilder::new(
+            BoxValue::SAFE_USER_MIN,
+            ErgoTree::try_from(expr).unwrap(),
+            1,
+        );
+        assert!(builder.calc_box_size_bytes().unwrap() < 30);
+        assert_eq!(builder.calc_min_box_value().unwrap(), BoxValue::MIN);
+        assert!(builder.build().is_ok());
     }
 
     #[test]
     fn test_build_fail_box_value_too_low() {
-        let builder = ErgoBoxCandidateBuilder::new(BoxValue::MIN, force_any_val::<ErgoTree>(), 1);
+        // P2PK box is larger than the minimal box size
+        let pk = force_any_val::<ProveDlog>();
+        let tree = ErgoTree::try_from(Expr::Const(pk.into())).unwrap();
+        let builder = ErgoBoxCandidateBuilder::new(BoxValue::MIN, tree, 1);
         assert!(builder.build().is_err());
     }
 
@@ -1,19 +1,118 @@
 //! Blockchain state
+use ergo_chain_types::Header;
+use ergo_chain_types::PreHeader;
+use ergo_chain_types::Votes;
+use thiserror::Error;
+
+use super::parameters::Parameters;
+
+/// Fixed number of last block headers in descending order (first header is the newest one)
+pub type Headers = [Header; 10];
 
 /// Blockchain state (last headers, etc.)
-#[derive(PartialEq, Eq, Debug, Clone, Default)]
-pub struct ErgoStateContext {}
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ErgoStateContext {
+    /// Block header with the current `spendingTransaction`, that can be predicted
+    /// by a miner before its formation
+    pub pre_header: PreHeader,
+    /// Fixed number of last block headers in descending order (first header is the newest one)
+    pub headers: Headers,
+    /// Current blockchain parameters
+    pub parameters: Parameters,
+}
 
 impl ErgoStateContext {
     /// Create an ErgoStateContext instance
-    pub fn new() -> ErgoStateContext {
-        ErgoStateContext {}
+    pub fn new(
+        pre_header: PreHeader,
+        headers: Headers,
+        parameters: Parameters,
+    ) -> ErgoStateContext {
+        ErgoStateContext {
+            pre_header,
+            headers,
+            parameters,
+        }
+    }
+
+    /// Create an ErgoStateContext for the next block, predicting its pre-header from the
+    /// newest of the last block headers
+    pub fn from_last_headers(
+        headers: Headers,
+        parameters: Parameters,
+    ) -> Result<ErgoStateContext, ErgoStateContextError> {
+        let last = &headers[0];
+        let height = last
+            .height
+            .checked_add(1)
+            .ok_or(ErgoStateContextError::HeightOverflow(last.height))?;
+        let pre_header = PreHeader {
+            version: last.version,
+            parent_id: last.id,
+            timestamp: last.timestamp,
+            n_bits: last.n_bits,
+            height,
+            miner_pk: last.autolykos_solution.miner_pk.clone(),
+            votes: Votes::zero(),
+        };
+        Ok(ErgoStateContext::new(pre_header, headers, parameters))
+    }
+
+    /// Height of the block the spending transaction is going to be included into
+    pub fn height(&self) -> u32 {
+        self.pre_header.height
+    }
+}
+
+/// Errors on ErgoStateContext loading
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum ErgoStateContextError {
+    /// JSON parsing error
+    #[error("JSON parsing error: {0}")]
+    Json(String),
+    /// Unexpected number of block headers
+    #[error("expected {} block headers, got {0}", HEADERS_COUNT)]
+    InvalidHeadersCount(usize),
+    /// Height of the next block does not fit into u32
+    #[error("next block height overflows, last header height: {0}")]
+    HeightOverflow(u32),
+}
+
+const HEADERS_COUNT: usize = 10;
+
+#[cfg(feature = "json")]
+impl ErgoStateContext {
+    /// Load the state context from the node API responses:
+    /// `last_headers_json` is the `/blocks/lastHeaders/10` response (oldest header first),
+    /// `info_json` is the `/info` response (current blockchain parameters)
+    pub fn from_node_json(
+        last_headers_json: &str,
+        info_json: &str,
+    ) -> Result<ErgoStateContext, ErgoStateContextError> {
+        let mut headers: Vec<Header> = serde_json::from_str(last_headers_json)
+            .map_err(|e| ErgoStateContextError::Json(e.to_string()))?;
+        headers.sort_by_key(|h| std::cmp::Reverse(h.height));
+        let headers_count = headers.len();
+        let headers: Headers = headers
+            .try_into()
+            .map_err(|_| ErgoStateContextError::InvalidHeadersCount(headers_count))?;
+        let node_info: NodeInfo = serde_json::from_str(info_json)
+            .map_err(|e| ErgoStateContextError::Json(e.to_string()))?;
+        ErgoStateContext::from_last_headers(headers, node_info.parameters)
     }
 }
 
+/// Part of the node `/info` response
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+struct NodeInfo {
+    parameters: Parameters,
+}
+
 #[cfg(feature = "arbitrary")]
 mod arbitrary {
     use super::*;
+    use proptest::array::uniform10;
     use proptest::prelude::*;
 
     impl Arbitrary for ErgoStateContext {
@@ -21,7 +120,100 @@ mod arbitrary {
         type Strategy = BoxedStrategy<Self>;
 
         fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
-            (any::<()>()).prop_map(|_| Self::new()).boxed()
+            (any::<PreHeader>(), uniform10(any::<Header>()))
+                .prop_map(|(pre_header, headers)| {
+                    Self::new(pre_header, headers, Parameters::default())
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "json")]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use sigma_test_util::force_any_val;
+
+    const INFO_JSON: &str = r#"{
+        "currentTime": 1623232400510,
+        "name": "ergo-mainnet-4.0.12",
+        "stateType": "utxo",
+        "difficulty": 2256145366630400,
+        "bestFullHeaderId": "9a7c4c8b2bf8a4be54d8bc0ee5bbc5b0a5f29fc3e9e1bbc2b4f4c8b3e14ec6e1",
+        "fullHeight": 504006,
+        "headersHeight": 504006,
+        "parameters": {
+            "outputCost": 100,
+            "tokenAccessCost": 100,
+            "maxBlockCost": 1236290,
+            "height": 503808,
+            "maxBlockSize": 1271009,
+            "dataInputCost": 100,
+            "blockVersion": 2,
+            "inputCost": 2000,
+            "storageFeeFactor": 1250000,
+            "minValuePerByte": 360
+        }
+    }"#;
+
+    #[test]
+    fn parse_node_info_parameters() {
+        let node_info: NodeInfo = serde_json::from_str(INFO_JSON).unwrap();
+        assert_eq!(
+            node_info.parameters,
+            Parameters {
+                block_version: 2,
+                max_block_size: 1271009,
+                max_block_cost: 1236290,
+                ..Parameters::default()
+            }
+        );
+    }
+
+    #[test]
+    fn from_node_json_wrong_headers_count() {
+        assert_eq!(
+            ErgoStateContext::from_node_json("[]", INFO_JSON),
+            Err(ErgoStateContextError::InvalidHeadersCount(0))
+        );
+    }
+
+    #[test]
+    fn from_last_headers_height_overflow() {
+        let mut headers: Headers = force_any_val::<ErgoStateContext>().headers;
+        headers[0].height = u32::MAX;
+        assert_eq!(
+            ErgoStateContext::from_last_headers(headers, Parameters::default()),
+            Err(ErgoStateContextError::HeightOverflow(u32::MAX))
+        );
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn from_node_json(heights in vec(1u32..i32::MAX as u32, HEADERS_COUNT)) {
+            let headers: Vec<Header> = heights
+                .into_iter()
+                .map(|height| Header {
+                    height,
+                    ..force_any_val::<Header>()
+                })
+                .collect();
+            let last_headers_json = serde_json::to_string(&headers).unwrap();
+            let state_ctx = ErgoStateContext::from_node_json(&last_headers_json, INFO_JSON).unwrap();
+            let newest = headers.iter().max_by_key(|h| h.height).unwrap();
+            prop_assert_eq!(&state_ctx.headers[0], newest);
+            prop_assert!(state_ctx.headers.windows(2).all(|w| w[0].height >= w[1].height));
+            prop_assert_eq!(state_ctx.height(), newest.height + 1);
+            prop_assert_eq!(state_ctx.pre_header.parent_id, newest.id);
+            prop_assert_eq!(state_ctx.parameters.max_block_cost, 1236290);
         }
     }
 }
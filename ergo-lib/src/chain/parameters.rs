@@ -0,0 +1,43 @@
+//! Blockchain parameters
+
+/// Blockchain parameters which can be changed by miners' voting
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json", serde(rename_all = "camelCase"))]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Parameters {
+    /// Protocol version of the block
+    pub block_version: i32,
+    /// Storage fee factor (per byte per storage period)
+    pub storage_fee_factor: i32,
+    /// Minimum value per byte of a box
+    pub min_value_per_byte: i32,
+    /// Maximum block size (in bytes)
+    pub max_block_size: i32,
+    /// Maximum cumulative computational cost of the transactions in a block
+    pub max_block_cost: i32,
+    /// Cost of accessing a token in a transaction
+    pub token_access_cost: i32,
+    /// Cost of a transaction input
+    pub input_cost: i32,
+    /// Cost of a transaction data input
+    pub data_input_cost: i32,
+    /// Cost of a transaction output
+    pub output_cost: i32,
+}
+
+impl Default for Parameters {
+    /// Parameters at the launch of the mainnet
+    fn default() -> Self {
+        Parameters {
+            block_version: 1,
+            storage_fee_factor: 1250000,
+            min_value_per_byte: 30 * 12,
+            max_block_size: 512 * 1024,
+            max_block_cost: 1000000,
+            token_access_cost: 100,
+            input_cost: 2000,
+            data_input_cost: 100,
+            output_cost: 100,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Ergo transaction
 
+pub mod cost;
 mod data_input;
 pub mod input;
 pub mod reduced;
@@ -12,6 +13,7 @@ use ergotree_interpreter::eval::extract_sigma_boolean;
 use ergotree_interpreter::eval::EvalError;
 use ergotree_interpreter::sigma_protocol::verifier::verify_signature;
 use ergotree_interpreter::sigma_protocol::verifier::TestVerifier;
+use ergotree_interpreter::sigma_protocol::verifier::VerificationResult;
 use ergotree_interpreter::sigma_protocol::verifier::Verifier;
 use ergotree_interpreter::sigma_protocol::verifier::VerifierError;
 use ergotree_ir::chain::ergo_box::BoxId;
@@ -36,6 +38,8 @@ use crate::wallet::signing::make_context;
 use crate::wallet::signing::TransactionContext;
 use crate::wallet::tx_context::TransactionContextError;
 
+use self::cost::TxCostAccumulator;
+use self::cost::TxCostLimitExceeded;
 use self::unsigned::UnsignedTransaction;
 
 use indexmap::IndexSet;
@@ -346,6 +350,12 @@ pub enum TxVerifyError {
     /// VerifierError
     #[error("VerifierError: {0}")]
     VerifierError(#[from] VerifierError),
+    /// Input proof is not valid
+    #[error("Invalid proof for input {0}")]
+    InvalidProof(usize),
+    /// Transaction cost exceeds the block cost limit
+    #[error("TxCostLimitExceeded: {0}")]
+    TxCostLimitExceeded(#[from] TxCostLimitExceeded),
 }
 
 /// Verify transaction input's proof
@@ -354,6 +364,44 @@ pub fn verify_tx_input_proof(
     state_context: &ErgoStateContext,
     input_idx: usize,
 ) -> Result<bool, TxVerifyError> {
+    let message_to_sign = tx_context.spending_tx.bytes_to_sign()?;
+    Ok(verify_input(tx_context, state_context, input_idx, &message_to_sign, None)?.result)
+}
+
+/// Verify proofs of all transaction inputs and compute the transaction cost (in block cost
+/// units) using the blockchain parameters from `state_context`.
+/// Besides the input scripts, the cost includes interpreter initialization, inputs,
+/// data inputs, outputs and token access, and is checked against `maxBlockCost`.
+/// Returns the transaction cost.
+pub fn verify_tx(
+    tx_context: &TransactionContext<Transaction>,
+    state_context: &ErgoStateContext,
+) -> Result<u64, TxVerifyError> {
+    let mut cost_acc = TxCostAccumulator::new(tx_context, &state_context.parameters)?;
+    let message_to_sign = tx_context.spending_tx.bytes_to_sign()?;
+    for input_idx in 0..tx_context.spending_tx.inputs.len() {
+        let res = verify_input(
+            tx_context,
+            state_context,
+            input_idx,
+            &message_to_sign,
+            Some(cost_acc.script_cost_limit()),
+        )?;
+        if !res.result {
+            return Err(TxVerifyError::InvalidProof(input_idx));
+        }
+        cost_acc.add_script_cost(res.cost)?;
+    }
+    Ok(cost_acc.total_cost())
+}
+
+fn verify_input(
+    tx_context: &TransactionContext<Transaction>,
+    state_context: &ErgoStateContext,
+    input_idx: usize,
+    message_to_sign: &[u8],
+    cost_limit: Option<u64>,
+) -> Result<VerificationResult, TxVerifyError> {
     let input = tx_context
         .spending_tx
         .inputs
@@ -364,15 +412,13 @@ pub fn verify_tx_input_proof(
         .ok_or(TransactionContextError::InputBoxNotFound(input_idx))?;
     let ctx = Rc::new(make_context(state_context, tx_context, input_idx)?);
     let verifier = TestVerifier;
-    let message_to_sign = tx_context.spending_tx.bytes_to_sign()?;
-    Ok(verifier
-        .verify(
-            &input_box.ergo_tree,
-            ctx,
-            input.spending_proof.proof.clone(),
-            message_to_sign.as_slice(),
-        )?
-        .result)
+    Ok(verifier.verify(
+        &input_box.ergo_tree,
+        ctx,
+        input.spending_proof.proof.clone(),
+        message_to_sign,
+        cost_limit,
+    )?)
 }
 
 /// Arbitrary impl
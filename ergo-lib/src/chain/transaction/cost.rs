@@ -0,0 +1,212 @@
+//! Transaction cost estimation in block cost units, as charged by the node during
+//! transaction validation (interpreter initialization, inputs, data inputs, outputs,
+//! token access and input scripts) and checked against `maxBlockCost`.
+//! See `ErgoTransaction.statefulValidity` in the reference Scala implementation of the node.
+
+use std::collections::HashSet;
+
+use ergotree_ir::chain::ergo_box::ErgoBox;
+use thiserror::Error;
+
+use crate::chain::parameters::Parameters;
+use crate::wallet::signing::ErgoTransaction;
+use crate::wallet::signing::TransactionContext;
+
+/// Cost of the interpreter initialization, charged once per transaction
+pub const INTERPRETER_INIT_COST: u64 = 10_000;
+
+/// Script costs are computed by the interpreter in JIT cost units which are
+/// this many times finer than the block cost units
+pub const JIT_COST_SCALE: u64 = 10;
+
+/// Transaction cost exceeds the block cost limit
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+#[error("Transaction cost {cost} exceeds the limit {limit}")]
+pub struct TxCostLimitExceeded {
+    /// Accumulated transaction cost (at the moment the limit was hit)
+    pub cost: u64,
+    /// Cost limit (`maxBlockCost`)
+    pub limit: u64,
+}
+
+/// Accumulates transaction cost (in block cost units) and checks it against the limit
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub(crate) struct TxCostAccumulator {
+    cost: u64,
+    limit: u64,
+}
+
+impl TxCostAccumulator {
+    /// Start with the cost of interpreter initialization, inputs, data inputs, outputs
+    /// and token access of the given transaction
+    pub(crate) fn new<T: ErgoTransaction>(
+        tx_context: &TransactionContext<T>,
+        params: &Parameters,
+    ) -> Result<Self, TxCostLimitExceeded> {
+        let mut acc = TxCostAccumulator {
+            cost: 0,
+            limit: param_cost(params.max_block_cost),
+        };
+        let tx = &tx_context.spending_tx;
+        let data_inputs_count = tx.data_inputs().map(|d| d.len()).unwrap_or(0);
+        let outputs = tx.outputs();
+        let initial_cost = INTERPRETER_INIT_COST
+            + tx.inputs_ids().len() as u64 * param_cost(params.input_cost)
+            + data_inputs_count as u64 * param_cost(params.data_input_cost)
+            + outputs.len() as u64 * param_cost(params.output_cost);
+        acc.add(initial_cost)?;
+        acc.add(token_access_cost(
+            tx_context.boxes_to_spend.as_slice(),
+            outputs.as_slice(),
+            params,
+        ))?;
+        Ok(acc)
+    }
+
+    /// Add the cost of an input script (in JIT cost units)
+    pub(crate) fn add_script_cost(&mut self, jit_cost: u64) -> Result<(), TxCostLimitExceeded> {
+        self.add(jit_cost / JIT_COST_SCALE)
+    }
+
+    /// Cost limit (in JIT cost units) left for the next input script
+    pub(crate) fn script_cost_limit(&self) -> u64 {
+        (self.limit - self.cost).saturating_mul(JIT_COST_SCALE)
+    }
+
+    /// Accumulated transaction cost
+    pub(crate) fn total_cost(&self) -> u64 {
+        self.cost
+    }
+
+    fn add(&mut self, cost: u64) -> Result<(), TxCostLimitExceeded> {
+        let new_cost = self.cost.saturating_add(cost);
+        if new_cost > self.limit {
+            return Err(TxCostLimitExceeded {
+                cost: new_cost,
+                limit: self.limit,
+            });
+        }
+        self.cost = new_cost;
+        Ok(())
+    }
+}
+
+/// Cost of accessing the tokens of input and output boxes: every token entry is charged
+/// along with every distinct token id (in inputs and in outputs)
+fn token_access_cost(inputs: &[ErgoBox], outputs: &[ErgoBox], params: &Parameters) -> u64 {
+    let (in_assets_num, in_distinct) = count_tokens(inputs);
+    let (out_assets_num, out_distinct) = count_tokens(outputs);
+    (in_assets_num + out_assets_num + in_distinct + out_distinct) as u64
+        * param_cost(params.token_access_cost)
+}
+
+/// Number of token entries and number of distinct token ids in the given boxes
+fn count_tokens(boxes: &[ErgoBox]) -> (usize, usize) {
+    let mut entries = 0;
+    let mut distinct = HashSet::new();
+    boxes
+        .iter()
+        .flat_map(|b| b.tokens.iter().flat_map(|tokens| tokens.iter()))
+        .for_each(|t| {
+            entries += 1;
+            distinct.insert(t.token_id);
+        });
+    (entries, distinct.len())
+}
+
+fn param_cost(v: i32) -> u64 {
+    v.max(0) as u64
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    use ergotree_ir::chain::ergo_box::box_value::BoxValue;
+    use ergotree_ir::chain::ergo_box::NonMandatoryRegisters;
+    use ergotree_ir::chain::token::Token;
+    use ergotree_ir::chain::token::TokenId;
+    use ergotree_ir::ergo_tree::ErgoTree;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+    use sigma_test_util::force_any_val;
+
+    use crate::chain::transaction::unsigned::UnsignedTransaction;
+    use crate::chain::transaction::TxId;
+    use crate::chain::transaction::UnsignedInput;
+    use ergotree_ir::chain::ergo_box::ErgoBoxCandidate;
+
+    fn true_tree() -> ErgoTree {
+        let expr: Expr = SigmaProp::new(SigmaBoolean::TrivialProp(true)).into();
+        ErgoTree::try_from(expr).unwrap()
+    }
+
+    fn make_box(tokens: Vec<Token>) -> ErgoBox {
+        ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            true_tree(),
+            tokens.try_into().ok(),
+            NonMandatoryRegisters::empty(),
+            0,
+            TxId::zero(),
+            0,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn initial_cost() {
+        let token_id = force_any_val::<TokenId>();
+        let token = Token {
+            token_id,
+            amount: 10.try_into().unwrap(),
+        };
+        let input_box = make_box(vec![token.clone()]);
+        let output = ErgoBoxCandidate {
+            value: BoxValue::SAFE_USER_MIN,
+            ergo_tree: true_tree(),
+            tokens: Some(vec![token].try_into().unwrap()),
+            additional_registers: NonMandatoryRegisters::empty(),
+            creation_height: 0,
+        };
+        let tx = UnsignedTransaction::new_from_vec(
+            vec![UnsignedInput::from(input_box.clone())],
+            vec![],
+            vec![output.clone(), output],
+        )
+        .unwrap();
+        let tx_context = TransactionContext::new(tx, vec![input_box], vec![]).unwrap();
+        let params = Parameters::default();
+        let acc = TxCostAccumulator::new(&tx_context, &params).unwrap();
+        // 1 input, 2 outputs, 3 token entries, 2 distinct token ids (in inputs and outputs)
+        assert_eq!(acc.total_cost(), 10_000 + 2000 + 2 * 100 + (3 + 2) * 100);
+
+        let params = Parameters {
+            max_block_cost: 12_000,
+            ..Parameters::default()
+        };
+        assert_eq!(
+            TxCostAccumulator::new(&tx_context, &params),
+            Err(TxCostLimitExceeded {
+                cost: 12_200,
+                limit: 12_000
+            })
+        );
+    }
+
+    #[test]
+    fn script_cost_in_block_units() {
+        let mut acc = TxCostAccumulator {
+            cost: 100,
+            limit: 200,
+        };
+        assert_eq!(acc.script_cost_limit(), 1000);
+        acc.add_script_cost(555).unwrap();
+        assert_eq!(acc.total_cost(), 155);
+        assert!(acc.add_script_cost(500).is_err());
+        assert_eq!(acc.total_cost(), 155);
+    }
+}
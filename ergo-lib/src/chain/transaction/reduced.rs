@@ -4,8 +4,10 @@
 use std::rc::Rc;
 
 use ergotree_interpreter::eval::reduce_to_crypto;
+use ergotree_interpreter::eval::ReductionOptions;
 use ergotree_interpreter::sigma_protocol::prover::ContextExtension;
 use ergotree_interpreter::sigma_protocol::prover::ProverError;
+use ergotree_interpreter::sigma_protocol::verifier::estimate_crypto_verify_cost;
 use ergotree_ir::serialization::sigma_byte_reader::SigmaByteRead;
 use ergotree_ir::serialization::sigma_byte_writer::SigmaByteWrite;
 use ergotree_ir::serialization::SigmaParsingError;
@@ -21,6 +23,7 @@ use crate::wallet::signing::TransactionContext;
 use crate::wallet::signing::TxSigningError;
 use crate::wallet::tx_context::TransactionContextError;
 
+use super::cost::TxCostAccumulator;
 use super::unsigned::UnsignedTransaction;
 use super::TxIoVec;
 
@@ -50,7 +53,7 @@ pub struct ReducedInput {
 pub struct ReducedTransaction {
     /// Unsigned transation
     pub unsigned_tx: UnsignedTransaction,
-    /// Transaction cost according to the prover
+    /// Transaction cost (in block cost units) according to the prover
     tx_cost: u32,
     /// Reduction result for each unsigned tx input
     reduced_inputs: TxIoVec<ReducedInput>,
@@ -61,13 +64,23 @@ impl ReducedTransaction {
     pub fn reduced_inputs(&self) -> TxIoVec<ReducedInput> {
         self.reduced_inputs.clone()
     }
+
+    /// Returns transaction cost (in block cost units), including interpreter initialization,
+    /// inputs, data inputs, outputs, token access and input scripts (reduction and
+    /// verification of the proofs)
+    pub fn tx_cost(&self) -> u32 {
+        self.tx_cost
+    }
 }
 
-/// Reduce each input of unsigned transaction to sigma proposition
+/// Reduce each input of unsigned transaction to sigma proposition.
+/// The transaction cost is computed using the blockchain parameters from `state_context`
+/// and checked against `maxBlockCost`.
 pub fn reduce_tx(
     tx_context: TransactionContext<UnsignedTransaction>,
     state_context: &ErgoStateContext,
 ) -> Result<ReducedTransaction, TxSigningError> {
+    let mut cost_acc = TxCostAccumulator::new(&tx_context, &state_context.parameters)?;
     let tx = &tx_context.spending_tx;
     let reduced_inputs = tx
         .inputs
@@ -78,14 +91,29 @@ pub fn reduce_tx(
                 .get_input_box(&input.box_id)
                 .ok_or(TransactionContextError::InputBoxNotFound(idx))?;
             let ctx = Rc::new(make_context(state_context, &tx_context, idx)?);
-            let expr = input_box
+            let (expr, constants) = input_box
                 .ergo_tree
-                .proposition()
+                .proposition_with_placeholders()
+                .and_then(|expr| Ok((expr, input_box.ergo_tree.get_constants()?)))
                 .map_err(ProverError::ErgoTreeError)
                 .map_err(|e| TxSigningError::ProverError(e, idx))?;
-            let reduction_result = reduce_to_crypto(&expr, ctx)
-                .map_err(ProverError::EvalError)
-                .map_err(|e| TxSigningError::ProverError(e, idx))?;
+            let reduction_result = reduce_to_crypto(
+                &expr,
+                ctx,
+                ReductionOptions {
+                    constants: &constants,
+                    cost_limit: Some(cost_acc.script_cost_limit()),
+                    ..Default::default()
+                },
+            )
+            .map_err(ProverError::EvalError)
+            .map_err(|e| TxSigningError::ProverError(e, idx))?;
+            // the proof verification is charged as in `verify_tx`
+            cost_acc.add_script_cost(
+                reduction_result
+                    .cost
+                    .saturating_add(estimate_crypto_verify_cost(&reduction_result.sigma_prop)),
+            )?;
             Ok(ReducedInput {
                 extension: input.extension,
                 sigma_prop: reduction_result.sigma_prop,
@@ -95,7 +123,8 @@ pub fn reduce_tx(
     Ok(ReducedTransaction {
         unsigned_tx: tx.clone(),
         reduced_inputs,
-        tx_cost: 0,
+        // the total is bounded by `maxBlockCost` (i32)
+        tx_cost: u32::try_from(cost_acc.total_cost()).unwrap_or(u32::MAX),
     })
 }
 
@@ -3,6 +3,7 @@ use crate::chain::ergo_state_context::ErgoStateContext;
 use crate::chain::transaction::unsigned::UnsignedTransaction;
 use crate::chain::transaction::Transaction;
 use crate::ergotree_interpreter::eval::reduce_to_crypto;
+use crate::ergotree_interpreter::eval::ReductionOptions;
 use crate::ergotree_interpreter::sigma_protocol::dht_protocol::interactive_prover as dht_interactive_prover;
 use crate::ergotree_interpreter::sigma_protocol::dlog_protocol::interactive_prover as dlog_interactive_prover;
 use crate::ergotree_interpreter::sigma_protocol::proof_tree::ProofTreeLeaf;
@@ -255,7 +256,7 @@ pub fn generate_commitments(
             .proposition()
             .map_err(ProverError::ErgoTreeError)
             .map_err(|e| TxSigningError::ProverError(e, i))?;
-        let reduction_result = reduce_to_crypto(&exp, ctx)
+        let reduction_result = reduce_to_crypto(&exp, ctx, ReductionOptions::default())
             .map_err(ProverError::EvalError)
             .map_err(|e| TxSigningError::ProverError(e, i))?;
 
@@ -283,7 +284,7 @@ pub fn extract_hints(
             .proposition()
             .map_err(ProverError::ErgoTreeError)
             .map_err(|e| TxSigningError::ProverError(e, i))?;
-        let reduction_result = reduce_to_crypto(&exp, ctx)
+        let reduction_result = reduce_to_crypto(&exp, ctx, ReductionOptions::default())
             .map_err(ProverError::EvalError)
             .map_err(|e| TxSigningError::ProverError(e, i))?;
         let sigma_tree = reduction_result.sigma_prop;
@@ -389,6 +390,7 @@ mod tests {
     use crate::chain::transaction::Transaction;
     use crate::ergotree_interpreter::eval::context::Context;
     use crate::ergotree_interpreter::eval::reduce_to_crypto;
+    use crate::ergotree_interpreter::eval::ReductionOptions;
     use crate::ergotree_interpreter::sigma_protocol::private_input::DlogProverInput;
     use crate::ergotree_interpreter::sigma_protocol::prover::ProofBytes;
     // use crate::ergotree_interpreter::sigma_protocol::verifier::{TestVerifier, Verifier};
@@ -457,7 +459,7 @@ mod tests {
 
         let contx = Rc::new(force_any_val::<Context>());
         let exp = tree_m.proposition().unwrap();
-        let reduction_result = reduce_to_crypto(&exp, contx).unwrap();
+        let reduction_result = reduce_to_crypto(&exp, contx, ReductionOptions::default()).unwrap();
         let sigma_tree = reduction_result.sigma_prop;
         let stx: Transaction = serde_json::from_str(signed_tx).unwrap();
         let test: ProofBytes = stx.inputs.first().clone().spending_proof.proof;
@@ -1,5 +1,6 @@
 //! Transaction signing
 
+use crate::chain::transaction::cost::TxCostLimitExceeded;
 use crate::chain::transaction::reduced::ReducedTransaction;
 use crate::chain::transaction::{DataInput, Input, TransactionError};
 use crate::chain::{
@@ -40,6 +41,9 @@ pub enum TxSigningError {
     /// SigParsingError
     #[error("SigParsingError: {0}")]
     SigParsingError(#[from] SigParsingError),
+    /// Transaction cost exceeds the block cost limit
+    #[error("TxCostLimitExceeded: {0}")]
+    TxCostLimitExceeded(#[from] TxCostLimitExceeded),
 }
 
 /// Exposes common properties for signed and unsigned transactions
@@ -101,7 +105,7 @@ impl ErgoTransaction for Transaction {
 
 /// `self_index` - index of the SELF box in the tx_ctx.spending_tx.inputs
 pub fn make_context<T: ErgoTransaction>(
-    _state_ctx: &ErgoStateContext,
+    state_ctx: &ErgoStateContext,
     tx_ctx: &TransactionContext<T>,
     self_index: usize,
 ) -> Result<Context, TransactionContextError> {
@@ -148,11 +152,15 @@ pub fn make_context<T: ErgoTransaction>(
         .context_extension(self_index)
         .ok_or(TransactionError::InputNofFound(self_index))?;
     Ok(Context {
+        height: state_ctx.pre_header.height,
         self_box: self_box_ir,
         outputs: outputs_ir,
         data_inputs: data_inputs_ir,
         inputs: inputs_ir,
         extension,
+        miner_pubkey: state_ctx.pre_header.miner_pk.clone(),
+        headers: state_ctx.headers.to_vec(),
+        pre_header: state_ctx.pre_header.clone(),
     })
 }
 
@@ -284,8 +292,12 @@ mod tests {
     use rand::thread_rng;
     use sigma_test_util::force_any_val;
 
+    use crate::chain::parameters::Parameters;
+    use crate::chain::transaction::cost::INTERPRETER_INIT_COST;
     use crate::chain::transaction::reduced::reduce_tx;
+    use crate::chain::transaction::verify_tx;
     use crate::chain::transaction::DataInput;
+    use crate::chain::transaction::TxVerifyError;
     use crate::chain::{
         ergo_box::box_builder::ErgoBoxCandidateBuilder, transaction::UnsignedInput,
     };
@@ -314,6 +326,7 @@ mod tests {
                 Rc::new(force_any_val::<Context>()),
                 input.spending_proof.proof.clone(),
                 &message,
+                None,
             )?;
             Ok(res.result && acc)
         })
@@ -350,15 +363,74 @@ mod tests {
             let tx = UnsignedTransaction::new_from_vec(inputs, vec![], output_candidates).unwrap();
             let tx_context = TransactionContext::new(tx, boxes_to_spend.clone(), vec![]).unwrap();
             let tx_hint_bag=TransactionHintsBag::empty();
-            let res = sign_transaction(prover.as_ref(), tx_context.clone(), &force_any_val::<ErgoStateContext>(), Some(&tx_hint_bag));
+            let state_context = force_any_val::<ErgoStateContext>();
+            let res = sign_transaction(prover.as_ref(), tx_context.clone(), &state_context, Some(&tx_hint_bag));
             let signed_tx = res.unwrap();
             prop_assert!(verify_tx_proofs(&signed_tx, &boxes_to_spend).unwrap());
-            let reduced_tx = reduce_tx(tx_context, &force_any_val::<ErgoStateContext>()).unwrap();
+            let reduced_tx = reduce_tx(tx_context, &state_context).unwrap();
+            let tx_cost = reduced_tx.tx_cost() as u64;
             let signed_reduced_tx = sign_reduced_transaction(prover.as_ref(), reduced_tx,None).unwrap();
             prop_assert!(verify_tx_proofs(&signed_reduced_tx, &boxes_to_spend).unwrap());
+
+            let signed_tx_context = TransactionContext::new(signed_tx, boxes_to_spend.clone(), vec![]).unwrap();
+            let min_cost = INTERPRETER_INIT_COST + secrets.len() as u64 * 2000 + 100;
+            prop_assert!(tx_cost >= min_cost);
+            prop_assert_eq!(verify_tx(&signed_tx_context, &state_context).unwrap(), tx_cost);
+            let mut low_limit_state_context = state_context;
+            low_limit_state_context.parameters.max_block_cost = min_cost as i32 - 1;
+            prop_assert!(matches!(
+                verify_tx(&signed_tx_context, &low_limit_state_context),
+                Err(TxVerifyError::TxCostLimitExceeded(_))
+            ));
         }
     }
 
+    #[test]
+    fn test_p2pk_tx_cost() {
+        // 1 P2PK input, 2 outputs, no tokens
+        let secret = force_any_val::<DlogProverInput>();
+        let ergo_tree = ErgoTree::try_from(Expr::Const(secret.public_image().into())).unwrap();
+        let input_box = ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            ergo_tree.clone(),
+            None,
+            NonMandatoryRegisters::empty(),
+            0,
+            TxId::zero(),
+            0,
+        )
+        .unwrap();
+        let candidate = ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, ergo_tree, 0)
+            .build()
+            .unwrap();
+        let tx = UnsignedTransaction::new_from_vec(
+            vec![input_box.clone().into()],
+            vec![],
+            vec![candidate.clone(), candidate],
+        )
+        .unwrap();
+        let tx_context = TransactionContext::new(tx, vec![input_box.clone()], vec![]).unwrap();
+        let state_context = ErgoStateContext {
+            parameters: Parameters::default(),
+            ..force_any_val::<ErgoStateContext>()
+        };
+        let prover = TestProver {
+            secrets: vec![PrivateInput::DlogProverInput(secret)],
+        };
+        let reduced_tx = reduce_tx(tx_context.clone(), &state_context).unwrap();
+        let signed_tx = sign_transaction(&prover, tx_context, &state_context, None).unwrap();
+        let signed_tx_context =
+            TransactionContext::new(signed_tx, vec![input_box], vec![]).unwrap();
+        // cost charged by the node with the default parameters: interpreter init (10000),
+        // 1 input (2000), 2 outputs (2 * 100) and the ProveDlog verification (3980 / 10),
+        // the constant P2PK proposition does not add a reduction cost
+        assert_eq!(reduced_tx.tx_cost(), 12598);
+        assert_eq!(
+            verify_tx(&signed_tx_context, &state_context).unwrap(),
+            12598
+        );
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(16))]
 
@@ -456,6 +528,119 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_height_locked_contract() {
+        use ergotree_ir::mir::bin_op::BinOp;
+        use ergotree_ir::mir::bin_op::RelationOp;
+        use ergotree_ir::mir::global_vars::GlobalVars;
+
+        // HEIGHT > 100
+        let tree = ErgoTree::try_from(Expr::from(BinOp {
+            kind: RelationOp::Gt.into(),
+            left: Box::new(GlobalVars::Height.into()),
+            right: Box::new(100i32.into()),
+        }))
+        .unwrap();
+        let box_to_spend = ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            tree.clone(),
+            None,
+            NonMandatoryRegisters::empty(),
+            0,
+            TxId::zero(),
+            0,
+        )
+        .unwrap();
+        let candidate = ErgoBoxCandidate {
+            value: BoxValue::SAFE_USER_MIN,
+            ergo_tree: tree,
+            tokens: None,
+            additional_registers: NonMandatoryRegisters::empty(),
+            creation_height: 0,
+        };
+        let tx = UnsignedTransaction::new_from_vec(
+            vec![box_to_spend.clone().into()],
+            vec![],
+            vec![candidate],
+        )
+        .unwrap();
+        let tx_context = TransactionContext::new(tx, vec![box_to_spend], vec![]).unwrap();
+        let prover = TestProver { secrets: vec![] };
+        let mut state_context = force_any_val::<ErgoStateContext>();
+
+        state_context.pre_header.height = 101;
+        assert!(sign_transaction(&prover, tx_context.clone(), &state_context, None).is_ok());
+
+        state_context.pre_header.height = 100;
+        assert!(sign_transaction(&prover, tx_context, &state_context, None).is_err());
+    }
+
+    #[test]
+    fn test_height_locked_pk_contract() {
+        use ergotree_ir::mir::bin_op::BinOp;
+        use ergotree_ir::mir::bin_op::RelationOp;
+        use ergotree_ir::mir::bool_to_sigma::BoolToSigmaProp;
+        use ergotree_ir::mir::global_vars::GlobalVars;
+        use ergotree_ir::mir::sigma_and::SigmaAnd;
+        use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+
+        let secret = DlogProverInput::random();
+        // sigmaProp(HEIGHT > 100) && pk
+        let height_check: Expr = BinOp {
+            kind: RelationOp::Gt.into(),
+            left: Box::new(GlobalVars::Height.into()),
+            right: Box::new(100i32.into()),
+        }
+        .into();
+        let tree = ErgoTree::try_from(Expr::from(
+            SigmaAnd::new(vec![
+                BoolToSigmaProp::new(height_check).unwrap().into(),
+                SigmaProp::from(secret.public_image()).into(),
+            ])
+            .unwrap(),
+        ))
+        .unwrap();
+        let box_to_spend = ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            tree.clone(),
+            None,
+            NonMandatoryRegisters::empty(),
+            0,
+            TxId::zero(),
+            0,
+        )
+        .unwrap();
+        let candidate = ErgoBoxCandidate {
+            value: BoxValue::SAFE_USER_MIN,
+            ergo_tree: tree,
+            tokens: None,
+            additional_registers: NonMandatoryRegisters::empty(),
+            creation_height: 0,
+        };
+        let tx = UnsignedTransaction::new_from_vec(
+            vec![box_to_spend.clone().into()],
+            vec![],
+            vec![candidate],
+        )
+        .unwrap();
+        let tx_context = TransactionContext::new(tx, vec![box_to_spend], vec![]).unwrap();
+        let mut state_context = force_any_val::<ErgoStateContext>();
+        state_context.pre_header.height = 101;
+
+        let prover = TestProver {
+            secrets: vec![PrivateInput::DlogProverInput(secret)],
+        };
+        assert!(sign_transaction(&prover, tx_context.clone(), &state_context, None).is_ok());
+
+        let prover_wo_secret = TestProver { secrets: vec![] };
+        assert!(
+            sign_transaction(&prover_wo_secret, tx_context.clone(), &state_context, None).is_err()
+        );
+
+        state_context.pre_header.height = 100;
+        assert!(sign_transaction(&prover, tx_context, &state_context, None).is_err());
+    }
+
     #[test]
     fn test_proof_from_mainnet() {
         use crate::chain::transaction::Transaction;
@@ -550,6 +735,7 @@ mod tests {
             Rc::new(force_any_val::<Context>()),
             tx.inputs.get(1).unwrap().spending_proof.proof.clone(),
             message.as_slice(),
+            None,
         );
         assert!(ver_res.unwrap().result);
     }
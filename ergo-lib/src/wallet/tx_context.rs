@@ -15,7 +15,7 @@ pub struct TransactionContext<T: ErgoTransaction> {
     /// Unsigned transaction to sign
     pub spending_tx: T,
     /// Boxes corresponding to [`crate::chain::transaction::unsigned::UnsignedTransaction::inputs`]
-    boxes_to_spend: TxIoVec<ErgoBox>,
+    pub(crate) boxes_to_spend: TxIoVec<ErgoBox>,
     /// Boxes corresponding to [`crate::chain::transaction::unsigned::UnsignedTransaction::data_inputs`]
     pub(crate) data_boxes: Option<TxIoVec<ErgoBox>>,
 }
@@ -1,4 +1,5 @@
 //! Interpreter
+use ergotree_ir::mir::constant::Constant;
 use ergotree_ir::mir::constant::TryExtractInto;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
 use std::fmt::Display;
@@ -7,18 +8,91 @@ use std::rc::Rc;
 use ergotree_ir::mir::expr::Expr;
 use ergotree_ir::mir::value::Value;
 use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use ergotree_ir::types::smethod::MethodId;
+use ergotree_ir::types::smethod::SMethod;
 
 use cost_accum::CostAccumulator;
+use env::Env;
 
+use self::analysis::analyze_false_reduction;
+use self::analysis::FailedCondition;
 use self::context::Context;
+use self::tracer::EvalTracer;
+use self::tracer::TraceState;
 
+pub mod analysis;
+pub(crate) mod apply;
+pub(crate) mod atleast;
+pub(crate) mod bin_op;
+pub(crate) mod block;
+pub(crate) mod bool_to_sigma;
+pub(crate) mod coll_append;
+pub(crate) mod coll_by_index;
+pub(crate) mod coll_exists;
+pub(crate) mod coll_filter;
+pub(crate) mod coll_fold;
+pub(crate) mod coll_forall;
+pub(crate) mod coll_map;
+pub(crate) mod coll_size;
+pub(crate) mod coll_slice;
+pub(crate) mod constant_placeholder;
 /// Context(blockchain) for the interpreter
 pub mod context;
 
+pub(crate) mod byte_array_to_bigint;
+pub(crate) mod byte_array_to_long;
+pub(crate) mod calc_blake2b256;
+pub(crate) mod calc_sha256;
 pub(crate) mod cost_accum;
 pub(crate) mod costs;
+pub(crate) mod create_prove_dh_tuple;
+pub(crate) mod create_provedlog;
+pub(crate) mod decode_point;
+pub(crate) mod deserialize_context;
+pub(crate) mod deserialize_register;
+pub(crate) mod downcast;
+pub(crate) mod env;
 mod error;
+pub(crate) mod exponentiate;
 pub(crate) mod expr;
+pub(crate) mod extract_amount;
+pub(crate) mod extract_bytes;
+pub(crate) mod extract_bytes_with_no_ref;
+pub(crate) mod extract_creation_info;
+pub(crate) mod extract_id;
+pub(crate) mod extract_reg_as;
+pub(crate) mod extract_script_bytes;
+pub(crate) mod func_value;
+pub(crate) mod get_var;
+pub(crate) mod global_vars;
+pub(crate) mod group_generator;
+pub(crate) mod logical_not;
+pub(crate) mod long_to_byte_array;
+pub(crate) mod method_call;
+pub(crate) mod minus_mod_q;
+pub(crate) mod mod_q;
+pub(crate) mod multiply_group;
+pub(crate) mod negation;
+pub(crate) mod option_get;
+pub(crate) mod option_get_or_else;
+pub(crate) mod option_is_defined;
+pub(crate) mod plus_mod_q;
+pub(crate) mod property_call;
+pub(crate) mod savltree;
+pub(crate) mod scoll;
+pub(crate) mod scontext;
+pub(crate) mod select_field;
+pub(crate) mod sgroup_elem;
+pub(crate) mod sheader;
+pub(crate) mod sigma_and;
+pub(crate) mod sigma_or;
+pub(crate) mod sigma_prop_bytes;
+pub(crate) mod spreheader;
+pub mod tracer;
+pub(crate) mod tuple;
+pub(crate) mod upcast;
+pub(crate) mod val_use;
+pub(crate) mod xor;
 
 pub use error::EvalError;
 
@@ -27,6 +101,9 @@ pub use error::EvalError;
 pub struct ReductionDiagnosticInfo {
     /// expression pretty-printed
     pub pretty_printed_expr: Option<String>,
+    /// leaf conditions which made the expression reduce to `false`
+    /// (only filled with [`ReductionOptions::analyze`] set)
+    pub failed_conditions: Vec<FailedCondition>,
 }
 
 impl Display for ReductionDiagnosticInfo {
@@ -34,6 +111,12 @@ impl Display for ReductionDiagnosticInfo {
         if let Some(expr_str) = &self.pretty_printed_expr {
             writeln!(f, "Pretty printed expr:\n{}", expr_str)?;
         }
+        if !self.failed_conditions.is_empty() {
+            writeln!(f, "Failed conditions:")?;
+            for cond in &self.failed_conditions {
+                writeln!(f, "  {}", cond)?;
+            }
+        }
         write!(f, "")
     }
 }
@@ -49,55 +132,117 @@ pub struct ReductionResult {
     pub diag: ReductionDiagnosticInfo,
 }
 
+/// Options of the reduction (see [`reduce_to_crypto`])
+#[derive(Default)]
+pub struct ReductionOptions<'a> {
+    /// Segregated constants of the tree, ConstantPlaceholder nodes are resolved from them
+    /// (see `ErgoTree::proposition_with_placeholders` and `ErgoTree::get_constants`), so the
+    /// same parsed template can be evaluated with different constant values
+    pub constants: &'a [Constant],
+    /// Fail with `EvalError::CostError` if the evaluation cost exceeds this limit
+    pub cost_limit: Option<u64>,
+    /// Report every evaluated node to this tracer.
+    /// The expression is pretty printed before the evaluation (see [`EvalTracer::on_start`]),
+    /// so the reported source spans point into the printed expression.
+    /// See [`tracer::SourceTracer`] for a tracer that renders an annotated trace.
+    pub tracer: Option<&'a mut dyn EvalTracer>,
+    /// On a false reduction, evaluate the expression again to find the conditions which made it
+    /// reduce to `false` (reported in [`ReductionDiagnosticInfo::failed_conditions`],
+    /// see [`analysis::analyze_false_reduction`]).
+    /// Meant for the diagnostics of the prover, not for the verification.
+    pub analyze: bool,
+}
+
 /// Evaluate the given expression by reducing it to SigmaBoolean value.
-pub fn reduce_to_crypto(expr: &Expr, _ctx: Rc<Context>) -> Result<ReductionResult, EvalError> {
-    fn inner(expr: &Expr) -> Result<ReductionResult, EvalError> {
-        let cost_accum = CostAccumulator::new(0, None);
-        let mut ectx = EvalContext::new(cost_accum);
-        expr.eval(&mut ectx)
-            .and_then(|v| -> Result<ReductionResult, EvalError> {
-                match v {
-                    Value::Boolean(b) => Ok(ReductionResult {
-                        sigma_prop: SigmaBoolean::TrivialProp(b),
-                        cost: 0,
-                        diag: ReductionDiagnosticInfo {
-                            pretty_printed_expr: None,
-                        },
-                    }),
-                    Value::SigmaProp(sp) => Ok(ReductionResult {
-                        sigma_prop: sp.value().clone(),
-                        cost: 0,
-                        diag: ReductionDiagnosticInfo {
-                            pretty_printed_expr: None,
-                        },
-                    }),
-                    _ => Err(EvalError::InvalidResultType),
-                }
-            })
+/// On a false reduction the pretty printed expression is reported in the diagnostic info.
+pub fn reduce_to_crypto(
+    expr: &Expr,
+    ctx: Rc<Context>,
+    opts: ReductionOptions,
+) -> Result<ReductionResult, EvalError> {
+    let ReductionOptions {
+        constants,
+        cost_limit,
+        tracer,
+        analyze,
+    } = opts;
+    // Without a tracer the expression is pretty printed only when needed (on a false reduction,
+    // or on an error, in which case it is evaluated again to locate the error in the source).
+    let pretty_print = || {
+        expr.pretty_print()
+            .map_err(|e| EvalError::Misc(e.to_string()))
+    };
+    let with_src = |e: EvalError, printed_expr_str: String| match e {
+        e @ EvalError::Spanned(_) => e.wrap_spanned_with_src(printed_expr_str),
+        e => e,
+    };
+    let mut reduction = match tracer {
+        Some(tracer) => {
+            let (spanned_expr, printed_expr_str) = pretty_print()?;
+            tracer.on_start(&printed_expr_str);
+            reduce(
+                &spanned_expr,
+                constants,
+                ctx.clone(),
+                cost_limit,
+                Some(tracer),
+            )
+            .map_err(|e| with_src(e, printed_expr_str))?
+        }
+        None => match reduce(expr, constants, ctx.clone(), cost_limit, None) {
+            Ok(reduction) => reduction,
+            Err(_) => {
+                let (spanned_expr, printed_expr_str) = pretty_print()?;
+                reduce(&spanned_expr, constants, ctx.clone(), cost_limit, None)
+                    .map_err(|e| with_src(e, printed_expr_str))?
+            }
+        },
+    };
+    if reduction.sigma_prop == SigmaBoolean::TrivialProp(false) {
+        let (_, printed_expr_str) = pretty_print()?;
+        reduction.diag.pretty_printed_expr = Some(printed_expr_str);
+        if analyze {
+            // the analysis is a best effort, it should not fail the reduction
+            reduction.diag.failed_conditions =
+                analyze_false_reduction(expr, constants, ctx, cost_limit).unwrap_or_default();
+        }
     }
+    Ok(reduction)
+}
 
-    let res = inner(expr);
-    if let Ok(reduction) = res {
-        if reduction.sigma_prop == SigmaBoolean::TrivialProp(false) {
-            let (_, printed_expr_str) = expr
-                .pretty_print()
-                .map_err(|e| EvalError::Misc(e.to_string()))?;
-            let new_reduction = ReductionResult {
-                sigma_prop: SigmaBoolean::TrivialProp(false),
-                cost: reduction.cost,
-                diag: ReductionDiagnosticInfo {
-                    pretty_printed_expr: Some(printed_expr_str),
-                },
-            };
-            return Ok(new_reduction);
-        } else {
-            return Ok(reduction);
-        }
+fn reduce(
+    expr: &Expr,
+    constants: &[Constant],
+    ctx: Rc<Context>,
+    cost_limit: Option<u64>,
+    tracer: Option<&mut dyn EvalTracer>,
+) -> Result<ReductionResult, EvalError> {
+    let cost_accum = CostAccumulator::new(0, cost_limit);
+    let mut ectx = EvalContext::new(ctx, cost_accum).with_constants(constants.to_vec());
+    if let Some(tracer) = tracer {
+        ectx = ectx.with_tracer(tracer);
+    }
+    let v = expr.eval(&mut ectx)?;
+    let cost = ectx.cost_accum.total_cost();
+    match v {
+        Value::Boolean(b) => Ok(ReductionResult {
+            sigma_prop: SigmaBoolean::TrivialProp(b),
+            cost,
+            diag: ReductionDiagnosticInfo {
+                pretty_printed_expr: None,
+                failed_conditions: Vec::new(),
+            },
+        }),
+        Value::SigmaProp(sp) => Ok(ReductionResult {
+            sigma_prop: sp.value().clone(),
+            cost,
+            diag: ReductionDiagnosticInfo {
+                pretty_printed_expr: None,
+                failed_conditions: Vec::new(),
+            },
+        }),
+        _ => Err(EvalError::InvalidResultType),
     }
-    let (spanned_expr, printed_expr_str) = expr
-        .pretty_print()
-        .map_err(|e| EvalError::Misc(e.to_string()))?;
-    inner(&spanned_expr).map_err(|e| e.wrap_spanned_with_src(printed_expr_str.to_string()))
 }
 
 /// Expects SigmaProp constant value and returns it's value. Otherwise, returns an error.
@@ -109,16 +254,160 @@ pub fn extract_sigma_boolean(expr: &Expr) -> Result<SigmaBoolean, EvalError> {
 }
 
 #[derive(Debug)]
-pub(crate) struct EvalContext {
+pub(crate) struct EvalContext<'a> {
+    pub(crate) ctx: Rc<Context>,
     pub(crate) cost_accum: CostAccumulator,
+    pub(crate) env: Env,
+    /// Segregated constants of the tree, referred by ConstantPlaceholder nodes
+    pub(crate) constants: Vec<Constant>,
+    /// Tracer of the evaluated nodes (if set)
+    pub(crate) tracer: Option<TraceState<'a>>,
 }
 
-impl EvalContext {
-    pub fn new(cost_accum: CostAccumulator) -> Self {
-        EvalContext { cost_accum }
+impl<'a> EvalContext<'a> {
+    pub fn new(ctx: Rc<Context>, cost_accum: CostAccumulator) -> Self {
+        EvalContext {
+            ctx,
+            cost_accum,
+            env: Env::empty(),
+            constants: Vec::new(),
+            tracer: None,
+        }
+    }
+
+    /// Set the tree constants to resolve ConstantPlaceholder nodes from
+    pub fn with_constants(self, constants: Vec<Constant>) -> Self {
+        EvalContext { constants, ..self }
+    }
+
+    /// Report every evaluated node to the given tracer
+    pub fn with_tracer(self, tracer: &'a mut dyn EvalTracer) -> Self {
+        EvalContext {
+            tracer: Some(TraceState::new(tracer)),
+            ..self
+        }
     }
 }
 
+/// Evaluation function of a method, called with the object and the arguments
+pub(crate) type EvalFn = fn(&mut EvalContext, Value, Vec<Value>) -> Result<Value, EvalError>;
+
+/// Look up the evaluation function of the given method
+fn smethod_eval_fn(method: &SMethod) -> Result<EvalFn, EvalError> {
+    use ergotree_ir::types::savltree;
+    use ergotree_ir::types::scoll;
+    use ergotree_ir::types::scontext;
+    use ergotree_ir::types::sgroup_elem;
+    use ergotree_ir::types::sheader;
+    use ergotree_ir::types::spreheader;
+    Ok(match method.type_id() {
+        scontext::TYPE_ID => match method.method_id() {
+            scontext::DATA_INPUTS_PROPERTY_METHOD_ID => self::scontext::DATA_INPUTS_EVAL_FN,
+            scontext::HEADERS_PROPERTY_METHOD_ID => self::scontext::HEADERS_EVAL_FN,
+            scontext::PRE_HEADER_PROPERTY_METHOD_ID => self::scontext::PRE_HEADER_EVAL_FN,
+            scontext::INPUTS_PROPERTY_METHOD_ID => self::scontext::INPUTS_EVAL_FN,
+            scontext::OUTPUTS_PROPERTY_METHOD_ID => self::scontext::OUTPUTS_EVAL_FN,
+            scontext::HEIGHT_PROPERTY_METHOD_ID => self::scontext::HEIGHT_EVAL_FN,
+            scontext::SELF_PROPERTY_METHOD_ID => self::scontext::SELF_EVAL_FN,
+            scontext::SELF_BOX_INDEX_PROPERTY_METHOD_ID => self::scontext::SELF_BOX_INDEX_EVAL_FN,
+            scontext::MINER_PUBKEY_PROPERTY_METHOD_ID => self::scontext::MINER_PUBKEY_EVAL_FN,
+            method_id => return Err(unknown_method_err(method, method_id)),
+        },
+        scoll::TYPE_ID => match method.method_id() {
+            scoll::GET_OR_ELSE_METHOD_ID => self::scoll::GET_OR_ELSE_EVAL_FN,
+            scoll::INDICES_METHOD_ID => self::scoll::INDICES_EVAL_FN,
+            scoll::FLATMAP_METHOD_ID => self::scoll::FLATMAP_EVAL_FN,
+            scoll::PATCH_METHOD_ID => self::scoll::PATCH_EVAL_FN,
+            scoll::UPDATED_METHOD_ID => self::scoll::UPDATED_EVAL_FN,
+            scoll::UPDATE_MANY_METHOD_ID => self::scoll::UPDATE_MANY_EVAL_FN,
+            scoll::INDEX_OF_METHOD_ID => self::scoll::INDEX_OF_EVAL_FN,
+            scoll::ZIP_METHOD_ID => self::scoll::ZIP_EVAL_FN,
+            method_id => return Err(unknown_method_err(method, method_id)),
+        },
+        sgroup_elem::TYPE_ID => match method.method_id() {
+            sgroup_elem::GET_ENCODED_METHOD_ID => self::sgroup_elem::GET_ENCODED_EVAL_FN,
+            sgroup_elem::NEGATE_METHOD_ID => self::sgroup_elem::NEGATE_EVAL_FN,
+            method_id => return Err(unknown_method_err(method, method_id)),
+        },
+        sheader::TYPE_ID => match method.method_id() {
+            sheader::ID_PROPERTY_METHOD_ID => self::sheader::ID_EVAL_FN,
+            sheader::VERSION_PROPERTY_METHOD_ID => self::sheader::VERSION_EVAL_FN,
+            sheader::PARENT_ID_PROPERTY_METHOD_ID => self::sheader::PARENT_ID_EVAL_FN,
+            sheader::AD_PROOFS_ROOT_PROPERTY_METHOD_ID => self::sheader::AD_PROOFS_ROOT_EVAL_FN,
+            sheader::STATE_ROOT_PROPERTY_METHOD_ID => self::sheader::STATE_ROOT_EVAL_FN,
+            sheader::TRANSACTIONS_ROOT_PROPERTY_METHOD_ID => {
+                self::sheader::TRANSACTIONS_ROOT_EVAL_FN
+            }
+            sheader::TIMESTAMP_PROPERTY_METHOD_ID => self::sheader::TIMESTAMP_EVAL_FN,
+            sheader::N_BITS_PROPERTY_METHOD_ID => self::sheader::N_BITS_EVAL_FN,
+            sheader::HEIGHT_PROPERTY_METHOD_ID => self::sheader::HEIGHT_EVAL_FN,
+            sheader::EXTENSION_ROOT_PROPERTY_METHOD_ID => self::sheader::EXTENSION_ROOT_EVAL_FN,
+            sheader::MINER_PK_PROPERTY_METHOD_ID => self::sheader::MINER_PK_EVAL_FN,
+            sheader::POW_ONETIME_PK_PROPERTY_METHOD_ID => self::sheader::POW_ONETIME_PK_EVAL_FN,
+            sheader::POW_NONCE_PROPERTY_METHOD_ID => self::sheader::POW_NONCE_EVAL_FN,
+            sheader::POW_DISTANCE_PROPERTY_METHOD_ID => self::sheader::POW_DISTANCE_EVAL_FN,
+            sheader::VOTES_PROPERTY_METHOD_ID => self::sheader::VOTES_EVAL_FN,
+            method_id => return Err(unknown_method_err(method, method_id)),
+        },
+        spreheader::TYPE_ID => match method.method_id() {
+            spreheader::VERSION_PROPERTY_METHOD_ID => self::spreheader::VERSION_EVAL_FN,
+            spreheader::PARENT_ID_PROPERTY_METHOD_ID => self::spreheader::PARENT_ID_EVAL_FN,
+            spreheader::TIMESTAMP_PROPERTY_METHOD_ID => self::spreheader::TIMESTAMP_EVAL_FN,
+            spreheader::N_BITS_PROPERTY_METHOD_ID => self::spreheader::N_BITS_EVAL_FN,
+            spreheader::HEIGHT_PROPERTY_METHOD_ID => self::spreheader::HEIGHT_EVAL_FN,
+            spreheader::MINER_PK_PROPERTY_METHOD_ID => self::spreheader::MINER_PK_EVAL_FN,
+            spreheader::VOTES_PROPERTY_METHOD_ID => self::spreheader::VOTES_EVAL_FN,
+            method_id => return Err(unknown_method_err(method, method_id)),
+        },
+        savltree::TYPE_ID => match method.method_id() {
+            savltree::DIGEST_METHOD_ID => self::savltree::DIGEST_EVAL_FN,
+            savltree::ENABLED_OPERATIONS_METHOD_ID => self::savltree::ENABLED_OPERATIONS_EVAL_FN,
+            savltree::KEY_LENGTH_METHOD_ID => self::savltree::KEY_LENGTH_EVAL_FN,
+            savltree::VALUE_LENGTH_OPT_METHOD_ID => self::savltree::VALUE_LENGTH_OPT_EVAL_FN,
+            savltree::IS_INSERT_ALLOWED_METHOD_ID => self::savltree::IS_INSERT_ALLOWED_EVAL_FN,
+            savltree::IS_UPDATE_ALLOWED_METHOD_ID => self::savltree::IS_UPDATE_ALLOWED_EVAL_FN,
+            savltree::IS_REMOVE_ALLOWED_METHOD_ID => self::savltree::IS_REMOVE_ALLOWED_EVAL_FN,
+            savltree::UPDATE_OPERATIONS_METHOD_ID => self::savltree::UPDATE_OPERATIONS_EVAL_FN,
+            savltree::CONTAINS_METHOD_ID => self::savltree::CONTAINS_EVAL_FN,
+            savltree::GET_METHOD_ID => self::savltree::GET_EVAL_FN,
+            savltree::GET_MANY_METHOD_ID => self::savltree::GET_MANY_EVAL_FN,
+            savltree::INSERT_METHOD_ID => self::savltree::INSERT_EVAL_FN,
+            savltree::UPDATE_METHOD_ID => self::savltree::UPDATE_EVAL_FN,
+            savltree::REMOVE_METHOD_ID => self::savltree::REMOVE_EVAL_FN,
+            savltree::UPDATE_DIGEST_METHOD_ID => self::savltree::UPDATE_DIGEST_EVAL_FN,
+            method_id => return Err(unknown_method_err(method, method_id)),
+        },
+        type_id => {
+            return Err(EvalError::NotFound(format!(
+                "eval fn: unknown type id {0:?}",
+                type_id
+            )))
+        }
+    })
+}
+
+/// Get the method argument at the given position
+pub(crate) fn method_arg(
+    args: &[Value],
+    idx: usize,
+    method_name: &str,
+) -> Result<Value, EvalError> {
+    args.get(idx).cloned().ok_or_else(|| {
+        EvalError::NotFound(format!(
+            "{0}: missing argument at position {1}",
+            method_name, idx
+        ))
+    })
+}
+
+fn unknown_method_err(method: &SMethod, method_id: MethodId) -> EvalError {
+    EvalError::NotFound(format!(
+        "eval fn: unknown method id {0:?} in type {1}",
+        method_id,
+        method.obj_type().type_name()
+    ))
+}
+
 /// Expression evaluation.
 /// Should be implemented by every node that can be evaluated.
 pub(crate) trait Evaluable {
@@ -144,9 +433,9 @@ pub(crate) mod tests {
         eval_out(expr, ctx)
     }
 
-    pub fn eval_out<T: TryExtractFrom<Value>>(expr: &Expr, _ctx: Rc<Context>) -> T {
+    pub fn eval_out<T: TryExtractFrom<Value>>(expr: &Expr, ctx: Rc<Context>) -> T {
         let cost_accum = CostAccumulator::new(0, None);
-        let mut ectx = EvalContext::new(cost_accum);
+        let mut ectx = EvalContext::new(ctx, cost_accum);
         expr.eval(&mut ectx)
             .unwrap()
             .try_extract_into::<T>()
@@ -155,10 +444,10 @@ pub(crate) mod tests {
 
     pub fn try_eval_out<T: TryExtractFrom<Value>>(
         expr: &Expr,
-        _ctx: Rc<Context>,
+        ctx: Rc<Context>,
     ) -> Result<T, EvalError> {
         let cost_accum = CostAccumulator::new(0, None);
-        let mut ectx = EvalContext::new(cost_accum);
+        let mut ectx = EvalContext::new(ctx, cost_accum);
         expr.eval(&mut ectx)
             .and_then(|v| v.try_extract_into::<T>().map_err(EvalError::TryExtractFrom))
     }
@@ -168,5 +457,36 @@ pub(crate) mod tests {
         try_eval_out(expr, ctx)
     }
 
+    #[test]
+    fn reduction_cost_is_reported_and_limited() {
+        use ergotree_ir::mir::bin_op::BinOp;
+        use ergotree_ir::mir::bin_op::RelationOp;
+        use ergotree_ir::mir::bool_to_sigma::BoolToSigmaProp;
+        use ergotree_ir::mir::global_vars::GlobalVars;
+
+        let expr: Expr = BoolToSigmaProp {
+            input: Box::new(
+                BinOp {
+                    kind: RelationOp::Ge.into(),
+                    left: Box::new(GlobalVars::Height.into()),
+                    right: Box::new(0i32.into()),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let res = reduce_to_crypto(&expr, ctx.clone(), ReductionOptions::default()).unwrap();
+        assert_eq!(res.sigma_prop, SigmaBoolean::TrivialProp(true));
+        // BoolToSigmaProp + GE on Int + HEIGHT + Constant
+        assert_eq!(res.cost, 15 + 20 + 26 + 5);
+        let opts = |cost_limit| ReductionOptions {
+            cost_limit,
+            ..Default::default()
+        };
+        assert!(reduce_to_crypto(&expr, ctx.clone(), opts(Some(res.cost))).is_ok());
+        assert!(reduce_to_crypto(&expr, ctx, opts(Some(res.cost - 1))).is_err());
+    }
+
     // TODO mini: restore tests that was here before minification (see git history of this file)
 }
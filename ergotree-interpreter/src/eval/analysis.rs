@@ -0,0 +1,337 @@
+//! Analysis of the conditions which made the script reduce to `false`
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::rc::Rc;
+
+use ergotree_ir::mir::bin_op::BinOpKind;
+use ergotree_ir::mir::bin_op::LogicalOp;
+use ergotree_ir::mir::constant::Constant;
+use ergotree_ir::mir::expr::Expr;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use ergotree_ir::source_span::SourceSpan;
+
+use super::context::Context;
+use super::reduce;
+use super::tracer::EvalTracer;
+use super::EvalError;
+
+/// Leaf condition (not a boolean connective) which evaluated to `false`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FailedCondition {
+    /// Pretty printed condition
+    pub source: String,
+    /// Source position of the condition in the pretty printed script
+    /// (empty for nodes without source position)
+    pub span: SourceSpan,
+    /// Evaluated operands of the condition
+    pub operands: Vec<Value>,
+}
+
+impl Display for FailedCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)?;
+        if !self.operands.is_empty() {
+            let operands: Vec<String> = self.operands.iter().map(|v| v.to_string()).collect();
+            write!(f, " with operands ({})", operands.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Evaluate the given expression (which is expected to reduce to `false`) and find the
+/// minimal set of leaf conditions which have to change for the script to reduce to `true`.
+/// Boolean connectives (`&&`, `||` on Boolean and SigmaProp values) are walked from the root:
+/// all failed operands of a conjunction are reported, and for a disjunction only the operand
+/// with the fewest failed conditions is reported.
+/// Operands of `&&` which were not evaluated (short-circuited) are not reported.
+pub fn analyze_false_reduction(
+    expr: &Expr,
+    constants: &[Constant],
+    ctx: Rc<Context>,
+    cost_limit: Option<u64>,
+) -> Result<Vec<FailedCondition>, EvalError> {
+    let (spanned_expr, _) = expr
+        .pretty_print()
+        .map_err(|e| EvalError::Misc(e.to_string()))?;
+    let mut tracer = ResultTracer::default();
+    reduce(&spanned_expr, constants, ctx, cost_limit, Some(&mut tracer))?;
+    failed_conditions(&spanned_expr, &tracer)
+}
+
+/// Evaluation result of a node with the values of its subexpressions
+struct EvalRecord {
+    inputs: Vec<Value>,
+    result: Value,
+}
+
+/// Records the last evaluation result of every node (identified by its address in the tree)
+#[derive(Default)]
+struct ResultTracer {
+    results: HashMap<*const Expr, EvalRecord>,
+}
+
+impl ResultTracer {
+    fn get(&self, expr: &Expr) -> Option<&EvalRecord> {
+        self.results.get(&(expr as *const Expr))
+    }
+}
+
+impl EvalTracer for ResultTracer {
+    fn on_eval(&mut self, expr: &Expr, _span: SourceSpan, inputs: &[Value], result: &Value) {
+        self.results.insert(
+            expr as *const Expr,
+            EvalRecord {
+                inputs: inputs.to_vec(),
+                result: result.clone(),
+            },
+        );
+    }
+}
+
+fn failed_conditions(
+    expr: &Expr,
+    results: &ResultTracer,
+) -> Result<Vec<FailedCondition>, EvalError> {
+    let record = match results.get(expr) {
+        Some(record) if is_false(&record.result) => record,
+        // not evaluated or not failed
+        _ => return Ok(Vec::new()),
+    };
+    Ok(match expr {
+        Expr::BoolToSigmaProp(op) => failed_conditions(&op.input, results)?,
+        Expr::BlockValue(op) => failed_conditions(&op.expr().result, results)?,
+        Expr::BinOp(op) if op.expr().kind == BinOpKind::Logical(LogicalOp::And) => conjunction(
+            &[op.expr().left.as_ref(), op.expr().right.as_ref()],
+            results,
+        )?,
+        Expr::BinOp(op) if op.expr().kind == BinOpKind::Logical(LogicalOp::Or) => disjunction(
+            &[op.expr().left.as_ref(), op.expr().right.as_ref()],
+            results,
+        )?,
+        Expr::SigmaAnd(op) => conjunction(&op.items.iter().collect::<Vec<_>>(), results)?,
+        Expr::SigmaOr(op) => disjunction(&op.items.iter().collect::<Vec<_>>(), results)?,
+        // other nodes (including collection operations with lambdas) are reported as a whole,
+        // lambda bodies are not walked since they are evaluated for every element
+        _ => {
+            let (_, source) = expr
+                .pretty_print()
+                .map_err(|e| EvalError::Misc(e.to_string()))?;
+            vec![FailedCondition {
+                source,
+                span: expr.span(),
+                operands: record.inputs.clone(),
+            }]
+        }
+    })
+}
+
+/// All failed conditions of the operands
+fn conjunction(
+    operands: &[&Expr],
+    results: &ResultTracer,
+) -> Result<Vec<FailedCondition>, EvalError> {
+    let mut res = Vec::new();
+    for operand in operands {
+        res.append(&mut failed_conditions(operand, results)?);
+    }
+    Ok(res)
+}
+
+/// Failed conditions of the operand which is the closest to pass
+fn disjunction(
+    operands: &[&Expr],
+    results: &ResultTracer,
+) -> Result<Vec<FailedCondition>, EvalError> {
+    let mut res: Option<Vec<FailedCondition>> = None;
+    for operand in operands {
+        let conds = failed_conditions(operand, results)?;
+        if !conds.is_empty() && res.as_ref().map(|r| conds.len() < r.len()).unwrap_or(true) {
+            res = Some(conds);
+        }
+    }
+    Ok(res.unwrap_or_default())
+}
+
+fn is_false(v: &Value) -> bool {
+    match v {
+        Value::Boolean(b) => !b,
+        Value::SigmaProp(sp) => sp.value() == &SigmaBoolean::TrivialProp(false),
+        _ => false,
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::bool_to_sigma::BoolToSigmaProp;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use ergotree_ir::mir::sigma_and::SigmaAnd;
+    use ergotree_ir::mir::sigma_or::SigmaOr;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use ergotree_ir::mir::coll_forall::ForAll;
+    use ergotree_ir::mir::func_value::FuncArg;
+    use ergotree_ir::mir::func_value::FuncValue;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::types::stype::SType;
+
+    use crate::eval::reduce_to_crypto;
+    use crate::eval::ReductionOptions;
+
+    fn height_cond(op: RelationOp, v: i32) -> Expr {
+        BoolToSigmaProp {
+            input: Box::new(
+                BinOp {
+                    kind: op.into(),
+                    left: Box::new(GlobalVars::Height.into()),
+                    right: Box::new(v.into()),
+                }
+                .into(),
+            ),
+        }
+        .into()
+    }
+
+    #[test]
+    fn reports_minimal_failed_conditions() {
+        // HEIGHT > 100 && ((HEIGHT == 1 && HEIGHT == 2) || HEIGHT < 10) && HEIGHT > 40
+        // with HEIGHT = 42, the disjunction is the closest to pass on `HEIGHT < 10`
+        let expr: Expr = SigmaAnd::new(vec![
+            height_cond(RelationOp::Gt, 100),
+            SigmaOr::new(vec![
+                SigmaAnd::new(vec![
+                    height_cond(RelationOp::Eq, 1),
+                    height_cond(RelationOp::Eq, 2),
+                ])
+                .unwrap()
+                .into(),
+                height_cond(RelationOp::Lt, 10),
+            ])
+            .unwrap()
+            .into(),
+            height_cond(RelationOp::Gt, 40),
+        ])
+        .unwrap()
+        .into();
+        let ctx = Rc::new(Context {
+            height: 42,
+            ..force_any_val::<Context>()
+        });
+        // the analysis is not run by default
+        let res = reduce_to_crypto(&expr, ctx.clone(), ReductionOptions::default()).unwrap();
+        assert_eq!(res.sigma_prop, SigmaBoolean::TrivialProp(false));
+        assert!(res.diag.pretty_printed_expr.is_some());
+        assert!(res.diag.failed_conditions.is_empty());
+        let opts = ReductionOptions {
+            analyze: true,
+            ..Default::default()
+        };
+        let res = reduce_to_crypto(&expr, ctx, opts).unwrap();
+        assert_eq!(res.sigma_prop, SigmaBoolean::TrivialProp(false));
+        let failed = res.diag.failed_conditions;
+        assert_eq!(
+            failed.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
+            vec![
+                "HEIGHT > 100 with operands (42, 100)",
+                "HEIGHT < 10 with operands (42, 10)"
+            ]
+        );
+        let printed = res.diag.pretty_printed_expr.unwrap();
+        let span = failed[0].span;
+        assert_eq!(
+            &printed[span.offset..span.offset + span.length],
+            "HEIGHT > 100"
+        );
+    }
+
+    #[test]
+    fn short_circuited_operands_are_not_reported() {
+        let expr: Expr = BoolToSigmaProp {
+            input: Box::new(
+                BinOp {
+                    kind: LogicalOp::And.into(),
+                    left: Box::new(false.into()),
+                    right: Box::new(
+                        BinOp {
+                            kind: RelationOp::Gt.into(),
+                            left: Box::new(GlobalVars::Height.into()),
+                            right: Box::new(100i32.into()),
+                        }
+                        .into(),
+                    ),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let failed = analyze_false_reduction(&expr, &[], ctx, None).unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].source, "false");
+        assert!(failed[0].operands.is_empty());
+    }
+
+    #[test]
+    fn collection_op_is_reported_as_a_whole() {
+        // HEIGHT > 1 && Coll(5, 0, 7).forall({ (v1: Int) => v1 > 1 })
+        // the element before the last one fails the condition
+        let gt_one: Expr = FuncValue::new(
+            vec![FuncArg {
+                idx: 1.into(),
+                tpe: SType::SInt,
+            }],
+            BinOp {
+                kind: RelationOp::Gt.into(),
+                left: Box::new(
+                    ValUse {
+                        val_id: 1.into(),
+                        tpe: SType::SInt,
+                    }
+                    .into(),
+                ),
+                right: Box::new(1i32.into()),
+            }
+            .into(),
+        )
+        .into();
+        let expr: Expr = BoolToSigmaProp {
+            input: Box::new(
+                BinOp {
+                    kind: LogicalOp::And.into(),
+                    left: Box::new(
+                        BinOp {
+                            kind: RelationOp::Gt.into(),
+                            left: Box::new(GlobalVars::Height.into()),
+                            right: Box::new(1i32.into()),
+                        }
+                        .into(),
+                    ),
+                    right: Box::new(
+                        ForAll::new(vec![5i32, 0i32, 7i32].into(), gt_one)
+                            .unwrap()
+                            .into(),
+                    ),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let ctx = Rc::new(Context {
+            height: 42,
+            ..force_any_val::<Context>()
+        });
+        let failed = analyze_false_reduction(&expr, &[], ctx, None).unwrap();
+        assert_eq!(
+            failed.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
+            vec![
+                "[5, 0, 7].forall({(v1: Int) =>\n  v1 > 1\n}) with operands (Coll[Int](5, 0, 7), lambda(v1: Int))"
+            ]
+        );
+    }
+}
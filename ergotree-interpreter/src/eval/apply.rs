@@ -0,0 +1,179 @@
+use ergotree_ir::mir::apply::Apply;
+use ergotree_ir::mir::val_def::ValId;
+use ergotree_ir::mir::value::Lambda;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Apply {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let func_v = self.func.eval(ctx)?;
+        let args_v = self
+            .args
+            .iter()
+            .map(|arg| arg.eval(ctx))
+            .collect::<Result<Vec<Value>, EvalError>>()?;
+        match func_v {
+            Value::Lambda(lambda) => eval_lambda(&lambda, args_v, ctx),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "expected func_v to be Value::Lambda, got: {0:?}",
+                func_v
+            ))),
+        }
+    }
+}
+
+/// Evaluate the lambda body with the captured environment and the given arguments bound to
+/// the lambda's arguments
+pub(crate) fn eval_lambda(
+    lambda: &Lambda,
+    args: Vec<Value>,
+    ctx: &mut EvalContext,
+) -> Result<Value, EvalError> {
+    if lambda.args.len() != args.len() {
+        return Err(EvalError::UnexpectedValue(format!(
+            "lambda expects {0} argument(s), got {1}",
+            lambda.args.len(),
+            args.len()
+        )));
+    }
+    let bindings = lambda
+        .captured_env
+        .iter()
+        .cloned()
+        .chain(lambda.args.iter().map(|a| a.idx).zip(args));
+    // bindings shadowed by the lambda, restored after the body is evaluated
+    let mut shadowed: Vec<(ValId, Option<Value>)> = Vec::new();
+    for (id, v) in bindings {
+        let prev = ctx.env.insert(id, v);
+        shadowed.push((id, prev));
+    }
+    if let Some(tracer) = ctx.tracer.as_mut() {
+        tracer.enter_lambda_body();
+    }
+    let res = lambda.body.eval(ctx);
+    if let Some(tracer) = ctx.tracer.as_mut() {
+        tracer.exit_lambda_body();
+    }
+    for (id, prev) in shadowed.into_iter().rev() {
+        match prev {
+            Some(v) => ctx.env.insert(id, v),
+            None => ctx.env.remove(&id),
+        };
+    }
+    res
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::block::BlockValue;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::func_value::FuncArg;
+    use ergotree_ir::mir::func_value::FuncValue;
+    use ergotree_ir::mir::val_def::ValDef;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::types::stype::SType;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    fn val_use(id: u32) -> Expr {
+        ValUse {
+            val_id: ValId(id),
+            tpe: SType::SInt,
+        }
+        .into()
+    }
+
+    fn plus(left: Expr, right: Expr) -> Expr {
+        BinOp {
+            kind: ArithOp::Plus.into(),
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_user_defined_func_call() {
+        // { (v1: Int, v2: Int) => v1 + v2 }(1, 2)
+        let func: Expr = FuncValue::new(
+            vec![
+                FuncArg {
+                    idx: ValId(1),
+                    tpe: SType::SInt,
+                },
+                FuncArg {
+                    idx: ValId(2),
+                    tpe: SType::SInt,
+                },
+            ],
+            plus(val_use(1), val_use(2)),
+        )
+        .into();
+        let expr: Expr = Apply::new(func, vec![1i32.into(), 2i32.into()])
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<i32>(&expr), 3);
+    }
+
+    #[test]
+    fn eval_closure_captures_env() {
+        // {
+        //   val v1 = 10
+        //   val v2 = { (v3: Int) => v3 + v1 }
+        //   { val v1 = 100; v2(1) }
+        // }
+        let func: Expr = FuncValue::new(
+            vec![FuncArg {
+                idx: ValId(3),
+                tpe: SType::SInt,
+            }],
+            plus(val_use(3), val_use(1)),
+        )
+        .into();
+        let func_tpe = func.tpe();
+        let call: Expr = Apply::new(
+            ValUse {
+                val_id: ValId(2),
+                tpe: func_tpe,
+            }
+            .into(),
+            vec![1i32.into()],
+        )
+        .unwrap()
+        .into();
+        let inner: Expr = BlockValue {
+            items: vec![ValDef {
+                id: ValId(1),
+                rhs: Box::new(100i32.into()),
+            }
+            .into()],
+            result: Box::new(call),
+        }
+        .into();
+        let expr: Expr = BlockValue {
+            items: vec![
+                ValDef {
+                    id: ValId(1),
+                    rhs: Box::new(10i32.into()),
+                }
+                .into(),
+                ValDef {
+                    id: ValId(2),
+                    rhs: Box::new(func),
+                }
+                .into(),
+            ],
+            result: Box::new(inner),
+        }
+        .into();
+        assert_eq!(eval_out_wo_ctx::<i32>(&expr), 11);
+    }
+}
@@ -0,0 +1,132 @@
+use std::convert::TryInto;
+
+use ergotree_ir::mir::atleast::Atleast;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::sigma_boolean::cthreshold::Cthreshold;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Atleast {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let bound_v = self.bound.eval(ctx)?.try_extract_into::<i32>()?;
+        let input_v = self.input.eval(ctx)?.try_extract_into::<Vec<SigmaProp>>()?;
+        if input_v.len() > Atleast::MAX_CHILDREN_COUNT {
+            return Err(EvalError::Misc(format!(
+                "Atleast: expected at most {} children, got {}",
+                Atleast::MAX_CHILDREN_COUNT,
+                input_v.len()
+            )));
+        }
+        ctx.cost_accum
+            .add(Costs::DEFAULT.atleast.cost(input_v.len()))?;
+        let children: Vec<SigmaBoolean> =
+            input_v.into_iter().map(|sp| sp.value().clone()).collect();
+        let sb = if bound_v <= 0 {
+            SigmaBoolean::TrivialProp(true)
+        } else if bound_v as usize > children.len() {
+            SigmaBoolean::TrivialProp(false)
+        } else if let [child] = children.as_slice() {
+            // bound is 1, the only child has to be proven
+            child.clone()
+        } else {
+            Cthreshold::reduce(bound_v as u8, children.try_into()?)
+        };
+        Ok(SigmaProp::new(sb).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::panic)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjecture;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    fn pks(n: usize) -> Vec<SigmaProp> {
+        (0..n)
+            .map(|_| force_any_val::<ProveDlog>().into())
+            .collect()
+    }
+
+    #[test]
+    fn eval_2_of_3() {
+        let props = pks(3);
+        let expr: Expr = Atleast::new(2i32.into(), props.clone().into())
+            .unwrap()
+            .into();
+        let res = eval_out_wo_ctx::<SigmaProp>(&expr);
+        match res.value() {
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(ct)) => {
+                assert_eq!(ct.k, 2);
+                assert_eq!(
+                    ct.children.as_vec(),
+                    &props
+                        .into_iter()
+                        .map(|sp| sp.value().clone())
+                        .collect::<Vec<_>>()
+                );
+            }
+            sb => panic!("expected Cthreshold, got {:?}", sb),
+        }
+    }
+
+    #[test]
+    fn eval_trivial_bounds() {
+        let props = pks(3);
+        let expr: Expr = Atleast::new(0i32.into(), props.clone().into())
+            .unwrap()
+            .into();
+        assert_eq!(
+            eval_out_wo_ctx::<SigmaProp>(&expr),
+            SigmaProp::new(true.into())
+        );
+        let expr: Expr = Atleast::new(4i32.into(), props.clone().into())
+            .unwrap()
+            .into();
+        assert_eq!(
+            eval_out_wo_ctx::<SigmaProp>(&expr),
+            SigmaProp::new(false.into())
+        );
+        let expr: Expr = Atleast::new(3i32.into(), props.clone().into())
+            .unwrap()
+            .into();
+        assert!(matches!(
+            eval_out_wo_ctx::<SigmaProp>(&expr).value(),
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cand(_))
+        ));
+        let expr: Expr = Atleast::new(1i32.into(), props.into()).unwrap().into();
+        assert!(matches!(
+            eval_out_wo_ctx::<SigmaProp>(&expr).value(),
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cor(_))
+        ));
+    }
+
+    #[test]
+    fn eval_single_child() {
+        let props = pks(1);
+        let expr: Expr = Atleast::new(1i32.into(), props.clone().into())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<SigmaProp>(&expr), props[0]);
+    }
+
+    #[test]
+    fn eval_too_many_children() {
+        let props = vec![SigmaProp::new(true.into()); 256];
+        let expr: Expr = Atleast::new(1i32.into(), props.into()).unwrap().into();
+        assert!(try_eval_out_wo_ctx::<SigmaProp>(&expr).is_err());
+    }
+}
@@ -0,0 +1,524 @@
+//! Operators in ErgoTree
+
+use std::cmp::max;
+use std::cmp::min;
+use std::cmp::Ordering;
+
+use ergotree_ir::bigint256::BigInt256;
+use ergotree_ir::mir::bin_op::{ArithOp, BinOp, BinOpKind, LogicalOp, RelationOp};
+use ergotree_ir::mir::constant::TryExtractFrom;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+use num_traits::CheckedAdd;
+use num_traits::CheckedDiv;
+use num_traits::CheckedMul;
+use num_traits::CheckedRem;
+use num_traits::CheckedSub;
+use num_traits::Num;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+fn arithmetic_err<T: std::fmt::Display>(
+    op: &str,
+    lv_raw: T,
+    rv_raw: T,
+    err_str: &str,
+) -> EvalError {
+    EvalError::ArithmeticException(format!(
+        "({0}) {1} ({2}) resulted in {3}",
+        lv_raw, op, rv_raw, err_str
+    ))
+}
+
+fn eval_plus<T>(lv_raw: T, rv: Value) -> Result<Value, EvalError>
+where
+    T: Num + CheckedAdd + TryExtractFrom<Value> + Into<Value> + std::fmt::Display,
+{
+    let rv_raw = rv.try_extract_into::<T>()?;
+    lv_raw
+        .checked_add(&rv_raw)
+        .ok_or_else(|| arithmetic_err("+", lv_raw, rv_raw, "overflow"))
+        .map(|t| t.into()) // convert T to Value
+}
+
+fn eval_minus<T>(lv_raw: T, rv: Value) -> Result<Value, EvalError>
+where
+    T: Num + CheckedSub + TryExtractFrom<Value> + Into<Value> + std::fmt::Display,
+{
+    let rv_raw = rv.try_extract_into::<T>()?;
+    lv_raw
+        .checked_sub(&rv_raw)
+        .ok_or_else(|| arithmetic_err("-", lv_raw, rv_raw, "overflow"))
+        .map(|t| t.into()) // convert T to Value
+}
+
+fn eval_mul<T>(lv_raw: T, rv: Value) -> Result<Value, EvalError>
+where
+    T: Num + CheckedMul + TryExtractFrom<Value> + Into<Value> + std::fmt::Display,
+{
+    let rv_raw = rv.try_extract_into::<T>()?;
+    lv_raw
+        .checked_mul(&rv_raw)
+        .ok_or_else(|| arithmetic_err("*", lv_raw, rv_raw, "overflow"))
+        .map(|t| t.into()) // convert T to Value
+}
+
+fn eval_div<T>(lv_raw: T, rv: Value) -> Result<Value, EvalError>
+where
+    T: Num + CheckedDiv + TryExtractFrom<Value> + Into<Value> + std::fmt::Display,
+{
+    let rv_raw = rv.try_extract_into::<T>()?;
+    lv_raw
+        .checked_div(&rv_raw)
+        .ok_or_else(|| arithmetic_err("/", lv_raw, rv_raw, "exception"))
+        .map(|t| t.into()) // convert T to Value
+}
+
+fn eval_mod<T>(lv_raw: T, rv: Value) -> Result<Value, EvalError>
+where
+    T: Num + CheckedRem + TryExtractFrom<Value> + Into<Value> + std::fmt::Display,
+{
+    let rv_raw = rv.try_extract_into::<T>()?;
+    lv_raw
+        .checked_rem(&rv_raw)
+        .ok_or_else(|| arithmetic_err("%", lv_raw, rv_raw, "exception"))
+        .map(|t| t.into()) // convert T to Value
+}
+
+fn eval_max<T>(lv_raw: T, rv: Value) -> Result<Value, EvalError>
+where
+    T: Num + Ord + TryExtractFrom<Value> + Into<Value> + std::fmt::Display,
+{
+    let rv_raw = rv.try_extract_into::<T>()?;
+    Ok((max(lv_raw, rv_raw)).into())
+}
+
+fn eval_min<T>(lv_raw: T, rv: Value) -> Result<Value, EvalError>
+where
+    T: Num + Ord + TryExtractFrom<Value> + Into<Value> + std::fmt::Display,
+{
+    let rv_raw = rv.try_extract_into::<T>()?;
+    Ok((min(lv_raw, rv_raw)).into())
+}
+
+fn expected_numeric_err(lv: Value) -> EvalError {
+    EvalError::UnexpectedValue(format!(
+        "expected BinOp::left to be numeric value, got {0:?}",
+        lv
+    ))
+}
+
+fn numeric_cmp(lv: Value, rv: Value) -> Result<Ordering, EvalError> {
+    match lv {
+        Value::Byte(lv_raw) => Ok(lv_raw.cmp(&rv.try_extract_into::<i8>()?)),
+        Value::Short(lv_raw) => Ok(lv_raw.cmp(&rv.try_extract_into::<i16>()?)),
+        Value::Int(lv_raw) => Ok(lv_raw.cmp(&rv.try_extract_into::<i32>()?)),
+        Value::Long(lv_raw) => Ok(lv_raw.cmp(&rv.try_extract_into::<i64>()?)),
+        Value::BigInt(lv_raw) => Ok(lv_raw.cmp(&rv.try_extract_into::<BigInt256>()?)),
+        _ => Err(expected_numeric_err(lv)),
+    }
+}
+
+fn eval_relation(op: RelationOp, lv: Value, rv: Value) -> Result<Value, EvalError> {
+    let res = match op {
+        RelationOp::Eq => lv == rv,
+        RelationOp::NEq => lv != rv,
+        RelationOp::Gt => numeric_cmp(lv, rv)?.is_gt(),
+        RelationOp::Ge => numeric_cmp(lv, rv)?.is_ge(),
+        RelationOp::Lt => numeric_cmp(lv, rv)?.is_lt(),
+        RelationOp::Le => numeric_cmp(lv, rv)?.is_le(),
+    };
+    Ok(res.into())
+}
+
+impl Evaluable for BinOp {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let lv = self.left.eval(ctx)?;
+        if let (BinOpKind::Relation(RelationOp::Eq | RelationOp::NEq), Value::Coll(coll)) =
+            (self.kind, &lv)
+        {
+            ctx.cost_accum
+                .add(Costs::DEFAULT.eq_coll.cost(coll.len()))?;
+        }
+        // using closure to keep right value from evaluation (for lazy AND, OR)
+        let mut rv = || self.right.eval(ctx);
+        match self.kind {
+            BinOpKind::Relation(op) => eval_relation(op, lv, rv()?),
+            BinOpKind::Logical(op) => match op {
+                LogicalOp::And => {
+                    if lv.try_extract_into::<bool>()? {
+                        Ok(rv()?.try_extract_into::<bool>()?.into())
+                    } else {
+                        Ok(false.into())
+                    }
+                }
+                LogicalOp::Or => {
+                    if lv.try_extract_into::<bool>()? {
+                        Ok(true.into())
+                    } else {
+                        Ok(rv()?.try_extract_into::<bool>()?.into())
+                    }
+                }
+                LogicalOp::Xor => {
+                    let l = lv.try_extract_into::<bool>()?;
+                    let r = rv()?.try_extract_into::<bool>()?;
+                    Ok((l ^ r).into())
+                }
+            },
+            BinOpKind::Arith(op) => match op {
+                ArithOp::Plus => match lv {
+                    Value::Byte(lv_raw) => eval_plus(lv_raw, rv()?),
+                    Value::Short(lv_raw) => eval_plus(lv_raw, rv()?),
+                    Value::Int(lv_raw) => eval_plus(lv_raw, rv()?),
+                    Value::Long(lv_raw) => eval_plus(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => eval_plus(lv_raw, rv()?),
+                    _ => Err(expected_numeric_err(lv)),
+                },
+                ArithOp::Minus => match lv {
+                    Value::Byte(lv_raw) => eval_minus(lv_raw, rv()?),
+                    Value::Short(lv_raw) => eval_minus(lv_raw, rv()?),
+                    Value::Int(lv_raw) => eval_minus(lv_raw, rv()?),
+                    Value::Long(lv_raw) => eval_minus(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => eval_minus(lv_raw, rv()?),
+                    _ => Err(expected_numeric_err(lv)),
+                },
+                ArithOp::Multiply => match lv {
+                    Value::Byte(lv_raw) => eval_mul(lv_raw, rv()?),
+                    Value::Short(lv_raw) => eval_mul(lv_raw, rv()?),
+                    Value::Int(lv_raw) => eval_mul(lv_raw, rv()?),
+                    Value::Long(lv_raw) => eval_mul(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => eval_mul(lv_raw, rv()?),
+                    _ => Err(expected_numeric_err(lv)),
+                },
+                ArithOp::Divide => match lv {
+                    Value::Byte(lv_raw) => eval_div(lv_raw, rv()?),
+                    Value::Short(lv_raw) => eval_div(lv_raw, rv()?),
+                    Value::Int(lv_raw) => eval_div(lv_raw, rv()?),
+                    Value::Long(lv_raw) => eval_div(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => eval_div(lv_raw, rv()?),
+                    _ => Err(expected_numeric_err(lv)),
+                },
+                ArithOp::Modulo => match lv {
+                    Value::Byte(lv_raw) => eval_mod(lv_raw, rv()?),
+                    Value::Short(lv_raw) => eval_mod(lv_raw, rv()?),
+                    Value::Int(lv_raw) => eval_mod(lv_raw, rv()?),
+                    Value::Long(lv_raw) => eval_mod(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => eval_mod(lv_raw, rv()?),
+                    _ => Err(expected_numeric_err(lv)),
+                },
+                ArithOp::Max => match lv {
+                    Value::Byte(lv_raw) => eval_max(lv_raw, rv()?),
+                    Value::Short(lv_raw) => eval_max(lv_raw, rv()?),
+                    Value::Int(lv_raw) => eval_max(lv_raw, rv()?),
+                    Value::Long(lv_raw) => eval_max(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => eval_max(lv_raw, rv()?),
+                    _ => Err(expected_numeric_err(lv)),
+                },
+                ArithOp::Min => match lv {
+                    Value::Byte(lv_raw) => eval_min(lv_raw, rv()?),
+                    Value::Short(lv_raw) => eval_min(lv_raw, rv()?),
+                    Value::Int(lv_raw) => eval_min(lv_raw, rv()?),
+                    Value::Long(lv_raw) => eval_min(lv_raw, rv()?),
+                    Value::BigInt(lv_raw) => eval_min(lv_raw, rv()?),
+                    _ => Err(expected_numeric_err(lv)),
+                },
+            },
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+    use ergo_chain_types::ec_point::generator;
+    use ergo_chain_types::ec_point::inverse;
+    use ergo_chain_types::EcPoint;
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+    use num_traits::Bounded;
+    use num_traits::Zero;
+    use proptest::prelude::*;
+    use sigma_test_util::force_any_val;
+    use std::rc::Rc;
+
+    fn eval_num_op<T: TryExtractFrom<Value> + Into<Constant>>(
+        op: ArithOp,
+        left: T,
+        right: T,
+    ) -> Result<T, EvalError> {
+        let expr: Expr = BinOp {
+            kind: BinOpKind::Arith(op),
+            left: Box::new(Expr::Const(left.into())),
+            right: Box::new(Expr::Const(right.into())),
+        }
+        .into();
+        try_eval_out_wo_ctx::<T>(&expr)
+    }
+
+    #[test]
+    fn test_bigint_extremes() {
+        let max = BigInt256::max_value;
+        let min = BigInt256::min_value;
+        let one = || BigInt256::from(1i64);
+        assert!(eval_num_op(ArithOp::Plus, max(), one()).is_err());
+        assert!(eval_num_op(ArithOp::Minus, min(), one()).is_err());
+        assert!(eval_num_op(ArithOp::Multiply, max(), BigInt256::from(2i64)).is_err());
+        assert!(eval_num_op(ArithOp::Divide, one(), BigInt256::zero()).is_err());
+        assert!(eval_num_op(ArithOp::Modulo, one(), BigInt256::zero()).is_err());
+        assert_eq!(
+            eval_num_op(ArithOp::Plus, max() - one(), one()).unwrap(),
+            max()
+        );
+        assert_eq!(eval_num_op(ArithOp::Max, min(), max()).unwrap(), max());
+        assert_eq!(eval_num_op(ArithOp::Min, min(), max()).unwrap(), min());
+    }
+
+    #[test]
+    fn test_left_non_numeric() {
+        let expr: Expr = BinOp {
+            kind: ArithOp::Plus.into(),
+            left: Box::new(true.into()),
+            right: Box::new(1i32.into()),
+        }
+        .into();
+        assert!(try_eval_out_wo_ctx::<i32>(&expr).is_err());
+    }
+
+    fn check_eq_neq(left: Constant, right: Constant) -> bool {
+        let eq_op: Expr = BinOp {
+            kind: BinOpKind::Relation(RelationOp::Eq),
+            left: Box::new(left.clone().into()),
+            right: Box::new(right.clone().into()),
+        }
+        .into();
+        let neq_op: Expr = BinOp {
+            kind: BinOpKind::Relation(RelationOp::NEq),
+            left: Box::new(left.into()),
+            right: Box::new(right.into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let res = eval_out::<bool>(&eq_op, ctx.clone());
+        res && !eval_out::<bool>(&neq_op, ctx)
+    }
+
+    #[test]
+    fn num_eq() {
+        assert!(check_eq_neq(1i64.into(), 1i64.into()));
+        assert!(check_eq_neq(
+            BigInt256::from(1i64).into(),
+            BigInt256::from(1i64).into()
+        ));
+    }
+
+    #[test]
+    fn num_neq() {
+        assert!(!check_eq_neq(2i64.into(), 1i64.into()));
+        assert!(!check_eq_neq(
+            BigInt256::from(2i64).into(),
+            BigInt256::from(1i64).into()
+        ));
+    }
+
+    #[test]
+    fn coll_eq() {
+        assert!(check_eq_neq(vec![1i64, 2].into(), vec![1i64, 2].into()));
+        assert!(!check_eq_neq(vec![1i64, 2].into(), vec![2i64, 1].into()));
+        assert!(check_eq_neq(vec![1u8, 2].into(), vec![1i8, 2].into()));
+    }
+
+    #[test]
+    fn option_eq() {
+        assert!(check_eq_neq(Some(1i64).into(), Some(1i64).into()));
+        let none: Option<i64> = None;
+        assert!(check_eq_neq(none.into(), none.into()));
+        assert!(!check_eq_neq(Some(1i64).into(), none.into()));
+    }
+
+    #[test]
+    fn sigma_prop_eq() {
+        let prop: SigmaProp = force_any_val::<SigmaProp>();
+        assert!(check_eq_neq(prop.clone().into(), prop.into()));
+    }
+
+    #[test]
+    fn group_element_eq() {
+        let ge = force_any_val::<EcPoint>();
+        assert!(check_eq_neq(ge.clone().into(), ge.into()));
+        assert!(!check_eq_neq(
+            generator().into(),
+            inverse(&generator()).into()
+        ));
+    }
+
+    fn eval_relation_op<T: Into<Constant>>(op: RelationOp, left: T, right: T) -> bool {
+        let expr: Expr = BinOp {
+            kind: BinOpKind::Relation(op),
+            left: Box::new(left.into().into()),
+            right: Box::new(right.into().into()),
+        }
+        .into();
+        eval_out_wo_ctx::<bool>(&expr)
+    }
+
+    fn eval_logical_op(op: LogicalOp, left: Expr, right: Expr) -> Result<bool, EvalError> {
+        let expr: Expr = BinOp {
+            kind: BinOpKind::Logical(op),
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+        .into();
+        try_eval_out_wo_ctx::<bool>(&expr)
+    }
+
+    #[test]
+    fn bin_and_or_lazy() {
+        // right operand fails on evaluation
+        let failing: Expr = BinOp {
+            kind: RelationOp::Eq.into(),
+            left: Box::new(
+                BinOp {
+                    kind: ArithOp::Divide.into(),
+                    left: Box::new(1i32.into()),
+                    right: Box::new(0i32.into()),
+                }
+                .into(),
+            ),
+            right: Box::new(1i32.into()),
+        }
+        .into();
+        assert!(!eval_logical_op(LogicalOp::And, false.into(), failing.clone()).unwrap());
+        assert!(eval_logical_op(LogicalOp::Or, true.into(), failing.clone()).unwrap());
+        assert!(eval_logical_op(LogicalOp::And, true.into(), failing.clone()).is_err());
+        assert!(eval_logical_op(LogicalOp::Or, false.into(), failing.clone()).is_err());
+        assert!(eval_logical_op(LogicalOp::Xor, true.into(), failing).is_err());
+    }
+
+    #[test]
+    fn test_relation_non_numeric() {
+        let expr: Expr = BinOp {
+            kind: RelationOp::Gt.into(),
+            left: Box::new(true.into()),
+            right: Box::new(false.into()),
+        }
+        .into();
+        assert!(try_eval_out_wo_ctx::<bool>(&expr).is_err());
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn eq_identity(v in any::<Constant>()) {
+            prop_assert!(check_eq_neq(v.clone(), v));
+        }
+
+        #[test]
+        fn test_relation_int(l in any::<i32>(), r in any::<i32>()) {
+            prop_assert_eq!(eval_relation_op(RelationOp::Gt, l, r), l > r);
+            prop_assert_eq!(eval_relation_op(RelationOp::Ge, l, r), l >= r);
+            prop_assert_eq!(eval_relation_op(RelationOp::Lt, l, r), l < r);
+            prop_assert_eq!(eval_relation_op(RelationOp::Le, l, r), l <= r);
+            prop_assert_eq!(eval_relation_op(RelationOp::Eq, l, r), l == r);
+            prop_assert_eq!(eval_relation_op(RelationOp::NEq, l, r), l != r);
+        }
+
+        #[test]
+        fn test_relation_bigint(l in any::<i64>(), r in any::<i64>()) {
+            let l_bi = BigInt256::from(l);
+            let r_bi = BigInt256::from(r);
+            prop_assert_eq!(eval_relation_op(RelationOp::Gt, l_bi.clone(), r_bi.clone()), l > r);
+            prop_assert_eq!(eval_relation_op(RelationOp::Ge, l_bi.clone(), r_bi.clone()), l >= r);
+            prop_assert_eq!(eval_relation_op(RelationOp::Lt, l_bi.clone(), r_bi.clone()), l < r);
+            prop_assert_eq!(eval_relation_op(RelationOp::Le, l_bi, r_bi), l <= r);
+        }
+
+        #[test]
+        fn test_logical(l in any::<bool>(), r in any::<bool>()) {
+            prop_assert_eq!(eval_logical_op(LogicalOp::And, l.into(), r.into()).unwrap(), l && r);
+            prop_assert_eq!(eval_logical_op(LogicalOp::Or, l.into(), r.into()).unwrap(), l || r);
+            prop_assert_eq!(eval_logical_op(LogicalOp::Xor, l.into(), r.into()).unwrap(), l ^ r);
+        }
+
+        #[test]
+        fn test_num_byte(l in any::<i8>(), r in any::<i8>()) {
+            prop_assert_eq!(eval_num_op(ArithOp::Plus, l, r).ok(), l.checked_add(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Minus, l, r).ok(), l.checked_sub(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Multiply, l, r).ok(), l.checked_mul(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Divide, l, r).ok(), l.checked_div(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Modulo, l, r).ok(), l.checked_rem(r));
+            prop_assert_eq!(eval_num_op::<i8>(ArithOp::Max, l, r).unwrap(), l.max(r));
+            prop_assert_eq!(eval_num_op::<i8>(ArithOp::Min, l, r).unwrap(), l.min(r));
+        }
+
+        #[test]
+        fn test_num_short(l in any::<i16>(), r in any::<i16>()) {
+            prop_assert_eq!(eval_num_op(ArithOp::Plus, l, r).ok(), l.checked_add(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Minus, l, r).ok(), l.checked_sub(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Multiply, l, r).ok(), l.checked_mul(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Divide, l, r).ok(), l.checked_div(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Modulo, l, r).ok(), l.checked_rem(r));
+            prop_assert_eq!(eval_num_op::<i16>(ArithOp::Max, l, r).unwrap(), l.max(r));
+            prop_assert_eq!(eval_num_op::<i16>(ArithOp::Min, l, r).unwrap(), l.min(r));
+        }
+
+        #[test]
+        fn test_num_int(l in any::<i32>(), r in any::<i32>()) {
+            prop_assert_eq!(eval_num_op(ArithOp::Plus, l, r).ok(), l.checked_add(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Minus, l, r).ok(), l.checked_sub(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Multiply, l, r).ok(), l.checked_mul(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Divide, l, r).ok(), l.checked_div(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Modulo, l, r).ok(), l.checked_rem(r));
+            prop_assert_eq!(eval_num_op::<i32>(ArithOp::Max, l, r).unwrap(), l.max(r));
+            prop_assert_eq!(eval_num_op::<i32>(ArithOp::Min, l, r).unwrap(), l.min(r));
+        }
+
+        #[test]
+        fn test_num_long(l in any::<i64>(), r in any::<i64>()) {
+            prop_assert_eq!(eval_num_op(ArithOp::Plus, l, r).ok(), l.checked_add(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Minus, l, r).ok(), l.checked_sub(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Multiply, l, r).ok(), l.checked_mul(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Divide, l, r).ok(), l.checked_div(r));
+            prop_assert_eq!(eval_num_op(ArithOp::Modulo, l, r).ok(), l.checked_rem(r));
+            prop_assert_eq!(eval_num_op::<i64>(ArithOp::Max, l, r).unwrap(), l.max(r));
+            prop_assert_eq!(eval_num_op::<i64>(ArithOp::Min, l, r).unwrap(), l.min(r));
+        }
+
+        #[test]
+        fn test_num_bigint(l in any::<i32>(), r in any::<i32>()) {
+            let l_bi = BigInt256::from(l);
+            let r_bi = BigInt256::from(r);
+            prop_assert_eq!(
+                eval_num_op(ArithOp::Plus, l_bi.clone(), r_bi.clone()).unwrap(),
+                BigInt256::from(l as i64 + r as i64)
+            );
+            prop_assert_eq!(
+                eval_num_op(ArithOp::Minus, l_bi.clone(), r_bi.clone()).unwrap(),
+                BigInt256::from(l as i64 - r as i64)
+            );
+            prop_assert_eq!(
+                eval_num_op(ArithOp::Multiply, l_bi.clone(), r_bi.clone()).unwrap(),
+                BigInt256::from(l as i64 * r as i64)
+            );
+            prop_assert_eq!(
+                eval_num_op::<BigInt256>(ArithOp::Max, l_bi.clone(), r_bi.clone()).unwrap(),
+                BigInt256::from(l.max(r))
+            );
+            prop_assert_eq!(
+                eval_num_op::<BigInt256>(ArithOp::Min, l_bi, r_bi).unwrap(),
+                BigInt256::from(l.min(r))
+            );
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use ergotree_ir::mir::block::BlockValue;
+use ergotree_ir::mir::expr::Expr;
+use ergotree_ir::mir::val_def::ValId;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for BlockValue {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        // bindings shadowed by this block, restored when leaving it
+        ctx.cost_accum
+            .add(Costs::DEFAULT.block_value.cost(self.items.len()))?;
+        let mut shadowed: Vec<(ValId, Option<Value>)> = Vec::with_capacity(self.items.len());
+        let res = eval_block(self, ctx, &mut shadowed);
+        for (id, prev) in shadowed.into_iter().rev() {
+            match prev {
+                Some(v) => ctx.env.insert(id, v),
+                None => ctx.env.remove(&id),
+            };
+        }
+        res
+    }
+}
+
+fn eval_block(
+    block: &BlockValue,
+    ctx: &mut EvalContext,
+    shadowed: &mut Vec<(ValId, Option<Value>)>,
+) -> Result<Value, EvalError> {
+    for item in &block.items {
+        let val_def = match item {
+            Expr::ValDef(val_def) => val_def.expr(),
+            _ => {
+                return Err(EvalError::UnexpectedExpr(format!(
+                    "BlockValue: expected ValDef, got {0:?}",
+                    item
+                )))
+            }
+        };
+        let v = val_def.rhs.eval(ctx)?;
+        let prev = ctx.env.insert(val_def.id, v);
+        shadowed.push((val_def.id, prev));
+    }
+    block.result.eval(ctx)
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::val_def::ValDef;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::types::stype::SType;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    fn val_def(id: u32, rhs: Expr) -> Expr {
+        ValDef {
+            id: ValId(id),
+            rhs: Box::new(rhs),
+        }
+        .into()
+    }
+
+    fn val_use(id: u32) -> Expr {
+        ValUse {
+            val_id: ValId(id),
+            tpe: SType::SInt,
+        }
+        .into()
+    }
+
+    fn plus(left: Expr, right: Expr) -> Expr {
+        BinOp {
+            kind: ArithOp::Plus.into(),
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_val_use() {
+        // { val v1 = 1; val v2 = v1 + 2; v2 + v1 }
+        let expr: Expr = BlockValue {
+            items: vec![
+                val_def(1, 1i32.into()),
+                val_def(2, plus(val_use(1), 2i32.into())),
+            ],
+            result: Box::new(plus(val_use(2), val_use(1))),
+        }
+        .into();
+        assert_eq!(eval_out_wo_ctx::<i32>(&expr), 4);
+    }
+
+    #[test]
+    fn eval_shadowing() {
+        // { val v1 = 1; { val v1 = 10; v1 } + v1 }
+        let inner: Expr = BlockValue {
+            items: vec![val_def(1, 10i32.into())],
+            result: Box::new(val_use(1)),
+        }
+        .into();
+        let expr: Expr = BlockValue {
+            items: vec![val_def(1, 1i32.into())],
+            result: Box::new(plus(inner, val_use(1))),
+        }
+        .into();
+        assert_eq!(eval_out_wo_ctx::<i32>(&expr), 11);
+    }
+
+    #[test]
+    fn eval_val_use_out_of_scope() {
+        // { { val v1 = 1; v1 } + v1 }
+        let inner: Expr = BlockValue {
+            items: vec![val_def(1, 1i32.into())],
+            result: Box::new(val_use(1)),
+        }
+        .into();
+        let expr: Expr = BlockValue {
+            items: vec![],
+            result: Box::new(plus(inner, val_use(1))),
+        }
+        .into();
+        assert!(crate::eval::tests::try_eval_out_wo_ctx::<i32>(&expr).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+use ergotree_ir::mir::bool_to_sigma::BoolToSigmaProp;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for BoolToSigmaProp {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let input_v_bool = input_v.try_extract_into::<bool>()?;
+        Ok(SigmaProp::new(SigmaBoolean::TrivialProp(input_v_bool)).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_bool_to_sigma_prop() {
+        let expr: Expr = BoolToSigmaProp::new(true.into()).unwrap().into();
+        assert_eq!(
+            eval_out_wo_ctx::<SigmaProp>(&expr),
+            SigmaProp::new(SigmaBoolean::TrivialProp(true))
+        );
+        let expr: Expr = BoolToSigmaProp::new(false.into()).unwrap().into();
+        assert_eq!(
+            eval_out_wo_ctx::<SigmaProp>(&expr),
+            SigmaProp::new(SigmaBoolean::TrivialProp(false))
+        );
+    }
+}
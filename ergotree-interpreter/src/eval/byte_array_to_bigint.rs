@@ -0,0 +1,92 @@
+use std::convert::TryFrom;
+
+use ergotree_ir::bigint256::BigInt256;
+use ergotree_ir::mir::byte_array_to_bigint::ByteArrayToBigInt;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ByteArrayToBigInt {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let bytes = self.input.eval(ctx)?.try_extract_into::<Vec<u8>>()?;
+        if bytes.is_empty() {
+            return Err(EvalError::UnexpectedValue(
+                "ByteArrayToBigInt: byte array is empty".to_string(),
+            ));
+        }
+        // the value (not the byte array length) is checked to fit into 256 bits,
+        // so redundant leading sign bytes are allowed
+        Ok(BigInt256::try_from(bytes.as_slice())
+            .map_err(|e| {
+                EvalError::ArithmeticException(format!(
+                    "ByteArrayToBigInt: BigInt value doesn't fit into 256 bits: {0}",
+                    e
+                ))
+            })?
+            .into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use num_traits::Bounded;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    #[test]
+    fn eval_negative() {
+        let expr: Expr = ByteArrayToBigInt::new(vec![0xffu8, 0xfe].into())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<BigInt256>(&expr), BigInt256::from(-2i64));
+    }
+
+    #[test]
+    fn eval_empty() {
+        let expr: Expr = ByteArrayToBigInt::new(Vec::<u8>::new().into())
+            .unwrap()
+            .into();
+        assert!(try_eval_out_wo_ctx::<BigInt256>(&expr).is_err());
+    }
+
+    fn eval_bytes(bytes: Vec<u8>) -> Result<BigInt256, EvalError> {
+        let expr: Expr = ByteArrayToBigInt::new(bytes.into()).unwrap().into();
+        try_eval_out_wo_ctx::<BigInt256>(&expr)
+    }
+
+    #[test]
+    fn eval_32_bytes() {
+        let mut max_bytes = vec![0xffu8; 32];
+        max_bytes[0] = 0x7f;
+        assert_eq!(eval_bytes(max_bytes).unwrap(), BigInt256::max_value());
+        let mut min_bytes = vec![0u8; 32];
+        min_bytes[0] = 0x80;
+        assert_eq!(eval_bytes(min_bytes).unwrap(), BigInt256::min_value());
+    }
+
+    #[test]
+    fn eval_33_bytes() {
+        // 2^255 - 1 with a leading sign byte
+        let mut max_bytes = vec![0xffu8; 33];
+        max_bytes[0] = 0;
+        max_bytes[1] = 0x7f;
+        assert_eq!(eval_bytes(max_bytes).unwrap(), BigInt256::max_value());
+        // 2^255
+        let mut overflow_bytes = vec![0u8; 33];
+        overflow_bytes[1] = 0x80;
+        assert!(eval_bytes(overflow_bytes).is_err());
+        // -2^255 - 1
+        let mut underflow_bytes = vec![0xffu8; 33];
+        underflow_bytes[1] = 0x7f;
+        assert!(eval_bytes(underflow_bytes).is_err());
+        assert!(eval_bytes(vec![1u8; 33]).is_err());
+    }
+}
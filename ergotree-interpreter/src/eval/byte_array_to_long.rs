@@ -0,0 +1,50 @@
+use std::convert::TryInto;
+
+use ergotree_ir::mir::byte_array_to_long::ByteArrayToLong;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ByteArrayToLong {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let bytes = self.input.eval(ctx)?.try_extract_into::<Vec<u8>>()?;
+        let first_8: [u8; 8] = bytes
+            .get(..8)
+            .and_then(|b| b.try_into().ok())
+            .ok_or_else(|| {
+                EvalError::UnexpectedValue(format!(
+                    "ByteArrayToLong: expected at least 8 bytes, got {0}",
+                    bytes.len()
+                ))
+            })?;
+        Ok(i64::from_be_bytes(first_8).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    #[test]
+    fn eval_uses_first_8_bytes() {
+        let expr: Expr = ByteArrayToLong::new(vec![0u8, 0, 0, 0, 0, 0, 1, 0, 0xff].into())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<i64>(&expr), 256);
+    }
+
+    #[test]
+    fn eval_too_short() {
+        let expr: Expr = ByteArrayToLong::new(vec![1u8; 7].into()).unwrap().into();
+        assert!(try_eval_out_wo_ctx::<i64>(&expr).is_err());
+    }
+}
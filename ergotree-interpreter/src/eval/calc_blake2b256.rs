@@ -0,0 +1,40 @@
+use ergotree_ir::mir::calc_blake2b256::CalcBlake2b256;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+use sigma_util::hash::blake2b256_hash;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for CalcBlake2b256 {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let bytes = input_v.try_extract_into::<Vec<u8>>()?;
+        ctx.cost_accum
+            .add(Costs::DEFAULT.calc_blake2b256.cost(bytes.len()))?;
+        let hash = blake2b256_hash(&bytes);
+        Ok(hash.to_vec().into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_known_hash() {
+        let expr: Expr = CalcBlake2b256::new(b"abc".to_vec().into()).unwrap().into();
+        let res = eval_out_wo_ctx::<Vec<u8>>(&expr);
+        assert_eq!(
+            base16::encode_lower(&res),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+    }
+}
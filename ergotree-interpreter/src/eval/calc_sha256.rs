@@ -0,0 +1,40 @@
+use ergotree_ir::mir::calc_sha256::CalcSha256;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+use sigma_util::hash::sha256_hash;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for CalcSha256 {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let bytes = input_v.try_extract_into::<Vec<u8>>()?;
+        ctx.cost_accum
+            .add(Costs::DEFAULT.calc_sha256.cost(bytes.len()))?;
+        let hash = sha256_hash(&bytes);
+        Ok(hash.to_vec().into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_known_hash() {
+        let expr: Expr = CalcSha256::new(b"abc".to_vec().into()).unwrap().into();
+        let res = eval_out_wo_ctx::<Vec<u8>>(&expr);
+        assert_eq!(
+            base16::encode_lower(&res),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
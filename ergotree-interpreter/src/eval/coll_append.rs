@@ -0,0 +1,67 @@
+use ergotree_ir::mir::coll_append::Append;
+use ergotree_ir::mir::value::CollKind;
+use ergotree_ir::mir::value::NativeColl;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Append {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let col_2_v = self.col_2.eval(ctx)?;
+        if let (Value::Coll(coll), Value::Coll(coll_2)) = (&input_v, &col_2_v) {
+            ctx.cost_accum
+                .add(Costs::DEFAULT.append.cost(coll.len() + coll_2.len()))?;
+        }
+        match (input_v, col_2_v) {
+            (
+                Value::Coll(CollKind::NativeColl(NativeColl::CollByte(mut bytes))),
+                Value::Coll(CollKind::NativeColl(NativeColl::CollByte(bytes_2))),
+            ) => {
+                bytes.extend(bytes_2);
+                Ok(bytes.into())
+            }
+            (Value::Coll(coll), Value::Coll(coll_2)) => {
+                let mut items = coll.as_vec();
+                items.extend(coll_2.as_vec());
+                Ok(Value::Coll(CollKind::from_vec(
+                    coll.elem_tpe().clone(),
+                    items,
+                )?))
+            }
+            (input_v, col_2_v) => Err(EvalError::UnexpectedValue(format!(
+                "Append: expected both operands to be Value::Coll, got: {0:?} and {1:?}",
+                input_v, col_2_v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_append() {
+        let expr: Expr = Append::new(vec![1i64].into(), vec![2i64, 3i64].into())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<Vec<i64>>(&expr), vec![1i64, 2i64, 3i64]);
+    }
+
+    #[test]
+    fn eval_append_bytes() {
+        let expr: Expr = Append::new(vec![1i8].into(), vec![2i8].into())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<Vec<i8>>(&expr), vec![1i8, 2i8]);
+    }
+}
@@ -0,0 +1,89 @@
+use ergotree_ir::mir::coll_by_index::ByIndex;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::CollKind;
+use ergotree_ir::mir::value::NativeColl;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ByIndex {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let index_v = self.index.eval(ctx)?;
+        let index = index_v.try_extract_into::<i32>()?;
+        let default_v = self
+            .default
+            .as_ref()
+            .map(|default| default.eval(ctx))
+            .transpose()?;
+        let item = match input_v {
+            Value::Coll(CollKind::NativeColl(NativeColl::CollByte(bytes))) => {
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|i| bytes.get(i))
+                    .map(|b| Value::Byte(*b))
+            }
+            Value::Coll(CollKind::WrappedColl { items, .. }) => usize::try_from(index)
+                .ok()
+                .and_then(|i| items.get(i))
+                .cloned(),
+            _ => {
+                return Err(EvalError::UnexpectedValue(format!(
+                    "ByIndex: expected input to be Value::Coll, got: {0:?}",
+                    input_v
+                )))
+            }
+        };
+        item.or(default_v)
+            .ok_or_else(|| EvalError::Misc(format!("ByIndex: index {0:?} out of bounds", index)))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    #[test]
+    fn eval_by_index() {
+        let expr: Expr = ByIndex::new(vec![1i64, 2i64].into(), 1i32.into(), None)
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<i64>(&expr), 2i64);
+    }
+
+    #[test]
+    fn eval_by_index_bytes() {
+        let expr: Expr = ByIndex::new(vec![1i8, 2i8].into(), 0i32.into(), None)
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<i8>(&expr), 1i8);
+    }
+
+    #[test]
+    fn eval_by_index_out_of_bounds() {
+        let expr: Expr = ByIndex::new(vec![1i64].into(), 1i32.into(), None)
+            .unwrap()
+            .into();
+        assert!(try_eval_out_wo_ctx::<i64>(&expr).is_err());
+        let expr: Expr = ByIndex::new(vec![1i64].into(), (-1i32).into(), None)
+            .unwrap()
+            .into();
+        assert!(try_eval_out_wo_ctx::<i64>(&expr).is_err());
+    }
+
+    #[test]
+    fn eval_get_or_else() {
+        let expr: Expr = ByIndex::new(vec![1i64].into(), 5i32.into(), Some(Box::new(42i64.into())))
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<i64>(&expr), 42i64);
+    }
+}
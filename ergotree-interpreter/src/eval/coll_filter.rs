@@ -0,0 +1,93 @@
+use ergotree_ir::mir::coll_filter::Filter;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::CollKind;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::apply::eval_lambda;
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Filter {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let condition_v = self.condition.eval(ctx)?;
+        let condition = match condition_v {
+            Value::Lambda(lambda) => Ok(lambda),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Filter: expected condition_v to be Value::Lambda, got: {0:?}",
+                condition_v
+            ))),
+        }?;
+        let normalized_input_vals: Vec<Value> = match input_v {
+            Value::Coll(coll) => Ok(coll.as_vec()),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Filter: expected Coll, found: {0:?}",
+                input_v
+            ))),
+        }?;
+        ctx.cost_accum
+            .add(Costs::DEFAULT.filter.cost(normalized_input_vals.len()))?;
+        let mut items = Vec::new();
+        for item in normalized_input_vals {
+            let cond =
+                eval_lambda(&condition, vec![item.clone()], ctx)?.try_extract_into::<bool>()?;
+            if cond {
+                items.push(item);
+            }
+        }
+        Ok(Value::Coll(CollKind::from_vec(
+            self.elem_tpe.clone(),
+            items,
+        )?))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::func_value::FuncArg;
+    use ergotree_ir::mir::func_value::FuncValue;
+    use ergotree_ir::mir::val_def::ValId;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::types::stype::SType;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    // { (v1: Int) => v1 > 1 }
+    fn gt_one() -> Expr {
+        FuncValue::new(
+            vec![FuncArg {
+                idx: ValId(1),
+                tpe: SType::SInt,
+            }],
+            BinOp {
+                kind: RelationOp::Gt.into(),
+                left: Box::new(
+                    ValUse {
+                        val_id: ValId(1),
+                        tpe: SType::SInt,
+                    }
+                    .into(),
+                ),
+                right: Box::new(1i32.into()),
+            }
+            .into(),
+        )
+        .into()
+    }
+
+    #[test]
+    fn eval_filter() {
+        let expr: Expr = Filter::new(vec![1i32, 2i32, 0i32, 3i32].into(), gt_one())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<Vec<i32>>(&expr), vec![2i32, 3i32]);
+    }
+}
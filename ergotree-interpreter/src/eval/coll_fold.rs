@@ -0,0 +1,119 @@
+use ergotree_ir::mir::coll_fold::Fold;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::apply::eval_lambda;
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Fold {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let zero_v = self.zero.eval(ctx)?;
+        let fold_op_v = self.fold_op.eval(ctx)?;
+        let fold_op = match fold_op_v {
+            Value::Lambda(lambda) => Ok(lambda),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Fold: expected fold_op to be Value::Lambda, got: {0:?}",
+                fold_op_v
+            ))),
+        }?;
+        let normalized_input_vals: Vec<Value> = match input_v {
+            Value::Coll(coll) => Ok(coll.as_vec()),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Fold: expected Coll, found: {0:?}",
+                input_v
+            ))),
+        }?;
+        ctx.cost_accum
+            .add(Costs::DEFAULT.fold.cost(normalized_input_vals.len()))?;
+        normalized_input_vals
+            .into_iter()
+            .try_fold(zero_v, |acc, item| {
+                eval_lambda(&fold_op, vec![Value::Tup([acc, item].into())], ctx)
+            })
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::convert::TryInto;
+
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::func_value::FuncArg;
+    use ergotree_ir::mir::func_value::FuncValue;
+    use ergotree_ir::mir::select_field::SelectField;
+    use ergotree_ir::mir::val_def::ValId;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::types::stuple::STuple;
+    use ergotree_ir::types::stype::SType;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    // { (v1: (Long, Long)) => body }
+    fn fold_op(body: Expr) -> Expr {
+        FuncValue::new(
+            vec![FuncArg {
+                idx: ValId(1),
+                tpe: STuple::pair(SType::SLong, SType::SLong).into(),
+            }],
+            body,
+        )
+        .into()
+    }
+
+    #[test]
+    fn eval_fold_empty() {
+        let expr: Expr = Fold::new(Vec::<i64>::new().into(), 7i64.into(), fold_op(1i64.into()))
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<i64>(&expr), 7i64);
+    }
+
+    #[test]
+    fn eval_fold() {
+        let expr: Expr = Fold::new(vec![1i64, 2i64].into(), 0i64.into(), fold_op(5i64.into()))
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<i64>(&expr), 5i64);
+    }
+
+    #[test]
+    fn eval_fold_sum() {
+        // { (v1: (Long, Long)) => v1._1 + v1._2 }
+        let field = |idx: u8| -> Box<Expr> {
+            let v1: Expr = ValUse {
+                val_id: ValId(1),
+                tpe: STuple::pair(SType::SLong, SType::SLong).into(),
+            }
+            .into();
+            Box::new(
+                SelectField::new(v1, idx.try_into().unwrap())
+                    .unwrap()
+                    .into(),
+            )
+        };
+        let body: Expr = BinOp {
+            kind: ArithOp::Plus.into(),
+            left: field(1),
+            right: field(2),
+        }
+        .into();
+        let expr: Expr = Fold::new(vec![1i64, 2i64, 3i64].into(), 10i64.into(), fold_op(body))
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<i64>(&expr), 16i64);
+    }
+
+    #[test]
+    fn fold_op_arg_type_mismatch() {
+        let expr = Fold::new(vec![1i32].into(), 0i64.into(), fold_op(5i64.into()));
+        assert!(expr.is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use ergotree_ir::mir::coll_forall::ForAll;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::apply::eval_lambda;
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ForAll {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let condition_v = self.condition.eval(ctx)?;
+        let condition = match condition_v {
+            Value::Lambda(lambda) => Ok(lambda),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "ForAll: expected condition_v to be Value::Lambda, got: {0:?}",
+                condition_v
+            ))),
+        }?;
+        let normalized_input_vals: Vec<Value> = match input_v {
+            Value::Coll(coll) => Ok(coll.as_vec()),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "ForAll: expected Coll, found: {0:?}",
+                input_v
+            ))),
+        }?;
+        ctx.cost_accum
+            .add(Costs::DEFAULT.for_all.cost(normalized_input_vals.len()))?;
+        for item in normalized_input_vals {
+            if !eval_lambda(&condition, vec![item], ctx)?.try_extract_into::<bool>()? {
+                return Ok(false.into());
+            }
+        }
+        Ok(true.into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::func_value::FuncArg;
+    use ergotree_ir::mir::func_value::FuncValue;
+    use ergotree_ir::mir::val_def::ValId;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::types::stype::SType;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    // { (v1: Int) => v1 > 1 }
+    fn gt_one() -> Expr {
+        FuncValue::new(
+            vec![FuncArg {
+                idx: ValId(1),
+                tpe: SType::SInt,
+            }],
+            BinOp {
+                kind: RelationOp::Gt.into(),
+                left: Box::new(
+                    ValUse {
+                        val_id: ValId(1),
+                        tpe: SType::SInt,
+                    }
+                    .into(),
+                ),
+                right: Box::new(1i32.into()),
+            }
+            .into(),
+        )
+        .into()
+    }
+
+    #[test]
+    fn eval_forall() {
+        let expr: Expr = ForAll::new(vec![2i32, 3i32].into(), gt_one())
+            .unwrap()
+            .into();
+        assert!(eval_out_wo_ctx::<bool>(&expr));
+        let expr: Expr = ForAll::new(vec![1i32, 2i32].into(), gt_one())
+            .unwrap()
+            .into();
+        assert!(!eval_out_wo_ctx::<bool>(&expr));
+        let expr: Expr = ForAll::new(Vec::<i32>::new().into(), gt_one())
+            .unwrap()
+            .into();
+        assert!(eval_out_wo_ctx::<bool>(&expr));
+    }
+}
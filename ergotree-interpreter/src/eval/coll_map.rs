@@ -0,0 +1,95 @@
+use ergotree_ir::mir::coll_map::Map;
+use ergotree_ir::mir::value::CollKind;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::apply::eval_lambda;
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Map {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let mapper_v = self.mapper.eval(ctx)?;
+        let mapper = match mapper_v {
+            Value::Lambda(lambda) => Ok(lambda),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Map: expected mapper_v to be Value::Lambda, got: {0:?}",
+                mapper_v
+            ))),
+        }?;
+        let normalized_input_vals: Vec<Value> = match input_v {
+            Value::Coll(coll) => Ok(coll.as_vec()),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Map: expected Coll, found: {0:?}",
+                input_v
+            ))),
+        }?;
+        ctx.cost_accum
+            .add(Costs::DEFAULT.map.cost(normalized_input_vals.len()))?;
+        let items = normalized_input_vals
+            .into_iter()
+            .map(|item| eval_lambda(&mapper, vec![item], ctx))
+            .collect::<Result<Vec<Value>, EvalError>>()?;
+        Ok(Value::Coll(CollKind::from_vec(self.out_elem_tpe(), items)?))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::func_value::FuncArg;
+    use ergotree_ir::mir::func_value::FuncValue;
+    use ergotree_ir::mir::val_def::ValId;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::types::stype::SType;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    fn inc_mapper(tpe: SType, one: Expr) -> Expr {
+        FuncValue::new(
+            vec![FuncArg {
+                idx: ValId(1),
+                tpe: tpe.clone(),
+            }],
+            BinOp {
+                kind: ArithOp::Plus.into(),
+                left: Box::new(
+                    ValUse {
+                        val_id: ValId(1),
+                        tpe,
+                    }
+                    .into(),
+                ),
+                right: Box::new(one),
+            }
+            .into(),
+        )
+        .into()
+    }
+
+    #[test]
+    fn eval_map() {
+        let expr: Expr = Map::new(
+            vec![1i64, 2i64, 3i64].into(),
+            inc_mapper(SType::SLong, 1i64.into()),
+        )
+        .unwrap()
+        .into();
+        assert_eq!(eval_out_wo_ctx::<Vec<i64>>(&expr), vec![2i64, 3i64, 4i64]);
+    }
+
+    #[test]
+    fn eval_map_bytes() {
+        let expr: Expr = Map::new(vec![1i8, 2i8].into(), inc_mapper(SType::SByte, 1i8.into()))
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<Vec<i8>>(&expr), vec![2i8, 3i8]);
+    }
+}
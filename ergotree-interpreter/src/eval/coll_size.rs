@@ -0,0 +1,37 @@
+use ergotree_ir::mir::coll_size::SizeOf;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for SizeOf {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        match input_v {
+            Value::Coll(coll) => Ok((coll.len() as i32).into()),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "SizeOf: expected input to be Value::Coll, got: {0:?}",
+                input_v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_size_of() {
+        let expr: Expr = SizeOf::new(vec![1i64, 2i64].into()).unwrap().into();
+        assert_eq!(eval_out_wo_ctx::<i32>(&expr), 2);
+        let expr: Expr = SizeOf::new(vec![1i8, 2i8, 3i8].into()).unwrap().into();
+        assert_eq!(eval_out_wo_ctx::<i32>(&expr), 3);
+    }
+}
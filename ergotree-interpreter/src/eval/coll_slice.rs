@@ -0,0 +1,84 @@
+use ergotree_ir::mir::coll_slice::Slice;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::CollKind;
+use ergotree_ir::mir::value::NativeColl;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Slice {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let from_v = self.from.eval(ctx)?;
+        let until_v = self.until.eval(ctx)?;
+        let from = from_v.try_extract_into::<i32>()?;
+        let until = until_v.try_extract_into::<i32>()?;
+        // out of bounds indices are clamped (as in Scala's slice)
+        let range = |len: usize| {
+            let from = (from.max(0) as usize).min(len);
+            let until = (until.max(0) as usize).min(len);
+            from..until.max(from)
+        };
+        if let Value::Coll(coll) = &input_v {
+            ctx.cost_accum
+                .add(Costs::DEFAULT.slice.cost(range(coll.len()).len()))?;
+        }
+        match input_v {
+            Value::Coll(CollKind::NativeColl(NativeColl::CollByte(bytes))) => {
+                Ok(bytes[range(bytes.len())].to_vec().into())
+            }
+            Value::Coll(CollKind::WrappedColl { elem_tpe, items }) => {
+                Ok(Value::Coll(CollKind::WrappedColl {
+                    elem_tpe,
+                    items: items[range(items.len())].to_vec(),
+                }))
+            }
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Slice: expected input to be Value::Coll, got: {0:?}",
+                input_v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    fn slice<T>(input: Vec<T>, from: i32, until: i32) -> Expr
+    where
+        Expr: From<Vec<T>>,
+    {
+        Slice::new(input.into(), from.into(), until.into())
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn eval_slice() {
+        let expr = slice(vec![1i64, 2i64, 3i64, 4i64], 1, 3);
+        assert_eq!(eval_out_wo_ctx::<Vec<i64>>(&expr), vec![2i64, 3i64]);
+    }
+
+    #[test]
+    fn eval_slice_bytes() {
+        let expr = slice(vec![1i8, 2i8, 3i8], 0, 2);
+        assert_eq!(eval_out_wo_ctx::<Vec<i8>>(&expr), vec![1i8, 2i8]);
+    }
+
+    #[test]
+    fn eval_slice_out_of_bounds() {
+        let expr = slice(vec![1i64, 2i64], -1, 10);
+        assert_eq!(eval_out_wo_ctx::<Vec<i64>>(&expr), vec![1i64, 2i64]);
+        let expr = slice(vec![1i64, 2i64], 2, 1);
+        assert_eq!(eval_out_wo_ctx::<Vec<i64>>(&expr), Vec::<i64>::new());
+    }
+}
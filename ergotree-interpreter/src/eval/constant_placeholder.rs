@@ -0,0 +1,110 @@
+use ergotree_ir::mir::constant::ConstantPlaceholder;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ConstantPlaceholder {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let c = ctx.constants.get(self.id as usize).ok_or_else(|| {
+            EvalError::NotFound(format!(
+                "ConstantPlaceholder: no constant with index {0} (tree has {1} constants)",
+                self.id,
+                ctx.constants.len()
+            ))
+        })?;
+        if c.tpe != self.tpe {
+            return Err(EvalError::UnexpectedValue(format!(
+                "ConstantPlaceholder: expected constant {0} to be of type {1:?}, got {2:?}",
+                self.id, self.tpe, c.tpe
+            )));
+        }
+        Ok(Value::from(c.v.clone()))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::ergo_tree::ErgoTree;
+    use ergotree_ir::ergo_tree::ErgoTreeHeader;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::bool_to_sigma::BoolToSigmaProp;
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+    use ergotree_ir::types::stype::SType;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::cost_accum::CostAccumulator;
+    use crate::eval::reduce_to_crypto;
+    use crate::eval::ReductionOptions;
+
+    fn eval_placeholder(
+        cp: ConstantPlaceholder,
+        constants: Vec<Constant>,
+    ) -> Result<Value, EvalError> {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let mut ectx =
+            EvalContext::new(ctx, CostAccumulator::new(0, None)).with_constants(constants);
+        Expr::ConstPlaceholder(cp).eval(&mut ectx)
+    }
+
+    #[test]
+    fn eval_from_constants() {
+        let cp = ConstantPlaceholder {
+            id: 1,
+            tpe: SType::SInt,
+        };
+        assert_eq!(
+            eval_placeholder(cp, vec![true.into(), 5i32.into()]).unwrap(),
+            Value::Int(5)
+        );
+    }
+
+    #[test]
+    fn eval_missing_or_mistyped_constant() {
+        let cp = ConstantPlaceholder {
+            id: 1,
+            tpe: SType::SInt,
+        };
+        assert!(eval_placeholder(cp.clone(), vec![5i32.into()]).is_err());
+        assert!(eval_placeholder(cp, vec![5i32.into(), 5i64.into()]).is_err());
+    }
+
+    #[test]
+    fn eval_template_with_different_constants() {
+        let expr: Expr = BoolToSigmaProp {
+            input: Box::new(
+                BinOp {
+                    kind: RelationOp::Gt.into(),
+                    left: Box::new(2i32.into()),
+                    right: Box::new(1i32.into()),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let tree = ErgoTree::new(ErgoTreeHeader::v0(true), &expr).unwrap();
+        let template = tree.proposition_with_placeholders().unwrap();
+        let reduce = |tree: &ErgoTree| {
+            let ctx = Rc::new(force_any_val::<Context>());
+            let constants = tree.get_constants().unwrap();
+            let opts = ReductionOptions {
+                constants: &constants,
+                ..Default::default()
+            };
+            reduce_to_crypto(&template, ctx, opts).unwrap().sigma_prop
+        };
+        assert_eq!(reduce(&tree), SigmaBoolean::TrivialProp(true));
+        let tree = tree.with_constant(0, 0i32.into()).unwrap();
+        assert_eq!(reduce(&tree), SigmaBoolean::TrivialProp(false));
+    }
+}
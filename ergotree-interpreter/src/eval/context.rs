@@ -1,8 +1,15 @@
 use std::sync::Arc;
 
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
 use crate::sigma_protocol::prover::ContextExtension;
 use bounded_vec::BoundedVec;
+use ergo_chain_types::EcPoint;
+use ergo_chain_types::Header;
+use ergo_chain_types::PreHeader;
 use ergotree_ir::chain::ergo_box::ErgoBox;
+use ergotree_ir::mir::value::Value;
 
 /// BoundedVec type for Tx inputs, output_candidates and outputs
 pub type TxIoVec<T> = BoundedVec<T, 1, { u16::MAX as usize }>;
@@ -10,6 +17,8 @@ pub type TxIoVec<T> = BoundedVec<T, 1, { u16::MAX as usize }>;
 /// Interpreter's context (blockchain state)
 #[derive(Debug)]
 pub struct Context {
+    /// Current height
+    pub height: u32,
     /// Box that contains the script we're evaluating (from spending transaction inputs)
     pub self_box: Arc<ErgoBox>,
     /// Spending transaction outputs
@@ -20,6 +29,12 @@ pub struct Context {
     pub inputs: TxIoVec<Arc<ErgoBox>>,
     /// prover-defined key-value pairs, that may be used inside a script
     pub extension: ContextExtension,
+    /// Public key of the miner of the block the spending transaction is included into
+    pub miner_pubkey: Box<EcPoint>,
+    /// Last block headers (newest first)
+    pub headers: Vec<Header>,
+    /// Header of the block the spending transaction is included into (without PoW data)
+    pub pre_header: PreHeader,
 }
 
 impl Context {
@@ -32,6 +47,12 @@ impl Context {
     }
 }
 
+impl Evaluable for ergotree_ir::mir::context::Context {
+    fn eval(&self, _ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        Ok(Value::Context)
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 #[allow(clippy::unwrap_used)]
 mod arbitrary {
@@ -44,26 +65,47 @@ mod arbitrary {
 
         fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
             (
+                0..i32::MAX as u32,
                 any::<ErgoBox>(),
                 vec(any::<ErgoBox>(), 1..3),
                 vec(any::<ErgoBox>(), 1..3),
                 of(vec(any::<ErgoBox>(), 1..3)),
                 any::<ContextExtension>(),
+                any::<EcPoint>(),
+                vec(any::<Header>(), 0..3),
+                any::<PreHeader>(),
             )
-                .prop_map(|(self_box, outputs, inputs, data_inputs, extension)| Self {
-                    self_box: Arc::new(self_box),
-                    outputs: outputs.into_iter().map(Arc::new).collect(),
-                    data_inputs: data_inputs
-                        .map(|v| TxIoVec::from_vec(v.into_iter().map(Arc::new).collect()).unwrap()),
-                    inputs: TxIoVec::from_vec(inputs.into_iter().map(Arc::new).collect()).unwrap(),
-                    extension,
-                })
+                .prop_map(
+                    |(
+                        height,
+                        self_box,
+                        outputs,
+                        inputs,
+                        data_inputs,
+                        extension,
+                        miner_pubkey,
+                        headers,
+                        pre_header,
+                    )| {
+                        Self {
+                            height,
+                            self_box: Arc::new(self_box),
+                            outputs: outputs.into_iter().map(Arc::new).collect(),
+                            data_inputs: data_inputs.map(|v| {
+                                TxIoVec::from_vec(v.into_iter().map(Arc::new).collect()).unwrap()
+                            }),
+                            inputs: TxIoVec::from_vec(inputs.into_iter().map(Arc::new).collect())
+                                .unwrap(),
+                            extension,
+                            miner_pubkey: miner_pubkey.into(),
+                            headers,
+                            pre_header,
+                        }
+                    },
+                )
                 .boxed()
         }
 
         type Strategy = BoxedStrategy<Self>;
     }
 }
-
-#[cfg(test)]
-mod tests {}
@@ -1,4 +1,11 @@
+use ergotree_ir::mir::bin_op::ArithOp;
+use ergotree_ir::mir::bin_op::BinOp;
+use ergotree_ir::mir::bin_op::BinOpKind;
+use ergotree_ir::mir::bin_op::LogicalOp;
+use ergotree_ir::mir::bin_op::RelationOp;
 use ergotree_ir::mir::expr::Expr;
+use ergotree_ir::mir::global_vars::GlobalVars;
+use ergotree_ir::types::stype::SType;
 
 extern crate derive_more;
 use derive_more::{From, Into};
@@ -6,17 +13,515 @@ use derive_more::{From, Into};
 #[derive(PartialEq, Eq, Debug, Clone, From, Into)]
 pub struct Cost(u32);
 
+/// Cost of an operation which depends on the number of processed items (e.g. bytes),
+/// charged for every (possibly incomplete) chunk of `chunk_size` items
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct PerItemCost {
+    /// Cost charged regardless of the number of items
+    pub base: Cost,
+    /// Cost charged for each chunk of items
+    pub per_chunk: Cost,
+    /// Number of items in one chunk
+    pub chunk_size: usize,
+}
+
+impl PerItemCost {
+    /// Number of chunks needed to process the given number of items
+    pub fn chunks(&self, n_items: usize) -> usize {
+        if n_items == 0 {
+            1
+        } else {
+            (n_items - 1) / self.chunk_size + 1
+        }
+    }
+
+    /// Cost of processing the given number of items
+    pub fn cost(&self, n_items: usize) -> Cost {
+        let chunks_cost = (self.chunks(n_items) as u64) * (self.per_chunk.0 as u64);
+        let total = (self.base.0 as u64).saturating_add(chunks_cost);
+        Cost(total.min(u32::MAX as u64) as u32)
+    }
+}
+
+/// Cost of an operation which depends on the type of its arguments
+/// (primitive numeric types vs BigInt, etc.)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TypeBasedCost {
+    /// Cost for primitive types (Byte, Short, Int, Long, Boolean)
+    pub primitive: Cost,
+    /// Cost for BigInt
+    pub big_int: Cost,
+    /// Cost for other types
+    pub other: Cost,
+}
+
+impl TypeBasedCost {
+    /// Cost of the operation on the values of the given type
+    pub fn cost(&self, tpe: &SType) -> Cost {
+        match tpe {
+            SType::SBoolean | SType::SByte | SType::SShort | SType::SInt | SType::SLong => {
+                self.primitive.clone()
+            }
+            SType::SBigInt => self.big_int.clone(),
+            _ => self.other.clone(),
+        }
+    }
+}
+
+/// JIT (v5) costs of the operations.
+/// Fixed and type-based costs of the nodes are charged before the node evaluation (see
+/// [`Costs::cost_of`]), per-item costs are charged during the evaluation, when the number
+/// of processed items is known.
 #[derive(Debug)]
 pub struct Costs {
-    pub eq_const_size: Cost,
+    pub constant: Cost,
+    pub constant_placeholder: Cost,
+    pub val_use: Cost,
+    pub func_value: Cost,
+    pub apply: Cost,
+    pub block_value: PerItemCost,
+    pub map: PerItemCost,
+    pub filter: PerItemCost,
+    pub fold: PerItemCost,
+    pub exists: PerItemCost,
+    pub for_all: PerItemCost,
+    pub by_index: Cost,
+    pub slice: PerItemCost,
+    pub append: PerItemCost,
+    pub size_of: Cost,
+    pub height: Cost,
+    pub inputs: Cost,
+    pub outputs: Cost,
+    pub self_box: Cost,
+    pub miner_pubkey: Cost,
+    pub context: Cost,
+    pub extract_amount: Cost,
+    pub extract_script_bytes: Cost,
+    pub extract_bytes: Cost,
+    pub extract_bytes_with_no_ref: Cost,
+    pub extract_id: Cost,
+    pub extract_creation_info: Cost,
+    pub extract_register_as: Cost,
+    pub get_var: Cost,
+    pub deserialize: Cost,
+    pub bool_to_sigma_prop: Cost,
+    pub sigma_and: PerItemCost,
+    pub sigma_or: PerItemCost,
+    pub atleast: PerItemCost,
+    pub create_prove_dlog: Cost,
+    pub create_prove_dh_tuple: Cost,
+    pub sigma_prop_bytes: PerItemCost,
+    pub option_get: Cost,
+    pub option_get_or_else: Cost,
+    pub option_is_defined: Cost,
+    pub tuple: Cost,
+    pub select_field: Cost,
+    pub long_to_byte_array: Cost,
+    pub byte_array_to_long: Cost,
+    pub byte_array_to_bigint: Cost,
+    pub exponentiate: Cost,
+    pub multiply_group: Cost,
+    pub decode_point: Cost,
+    pub group_generator: Cost,
+    pub upcast: Cost,
+    pub downcast: Cost,
+    pub mod_q: Cost,
+    pub plus_mod_q: Cost,
+    pub minus_mod_q: Cost,
+    pub negation: Cost,
+    pub logical_not: Cost,
+    pub method_call: Cost,
+    pub property_call: Cost,
+    pub arith_plus_minus: TypeBasedCost,
+    pub arith_multiply: TypeBasedCost,
+    pub arith_divide: TypeBasedCost,
+    pub arith_min_max: TypeBasedCost,
+    pub numeric_cmp: TypeBasedCost,
+    pub eq: TypeBasedCost,
+    pub eq_group_element: Cost,
+    pub eq_coll: PerItemCost,
+    pub logical_and_or: Cost,
+    pub logical_xor: Cost,
+    pub calc_blake2b256: PerItemCost,
+    pub calc_sha256: PerItemCost,
+    pub xor: PerItemCost,
+    pub coll_get_or_else: Cost,
+    pub coll_indices: PerItemCost,
+    pub coll_flat_map: PerItemCost,
+    pub coll_patch: PerItemCost,
+    pub coll_updated: PerItemCost,
+    pub coll_update_many: PerItemCost,
+    pub coll_index_of: PerItemCost,
+    pub coll_zip: PerItemCost,
+    pub avl_tree_create_verifier: PerItemCost,
+    pub avl_tree_lookup: PerItemCost,
+    pub avl_tree_insert: PerItemCost,
+    pub avl_tree_update: PerItemCost,
+    pub avl_tree_remove: PerItemCost,
+    pub prove_dlog_verification: Cost,
+    pub prove_dh_tuple_verification: Cost,
+    pub proof_tree_conjecture_to_bytes: Cost,
+    pub parse_polynomial: PerItemCost,
+    pub evaluate_polynomial: PerItemCost,
 }
 
 impl Costs {
     pub const DEFAULT: Costs = Costs {
-        eq_const_size: Cost(1),
+        constant: Cost(5),
+        constant_placeholder: Cost(1),
+        val_use: Cost(5),
+        func_value: Cost(5),
+        apply: Cost(30),
+        block_value: PerItemCost {
+            base: Cost(1),
+            per_chunk: Cost(1),
+            chunk_size: 10,
+        },
+        map: PerItemCost {
+            base: Cost(20),
+            per_chunk: Cost(1),
+            chunk_size: 10,
+        },
+        filter: PerItemCost {
+            base: Cost(20),
+            per_chunk: Cost(1),
+            chunk_size: 10,
+        },
+        fold: PerItemCost {
+            base: Cost(3),
+            per_chunk: Cost(1),
+            chunk_size: 10,
+        },
+        exists: PerItemCost {
+            base: Cost(3),
+            per_chunk: Cost(1),
+            chunk_size: 10,
+        },
+        for_all: PerItemCost {
+            base: Cost(3),
+            per_chunk: Cost(1),
+            chunk_size: 10,
+        },
+        by_index: Cost(30),
+        slice: PerItemCost {
+            base: Cost(10),
+            per_chunk: Cost(2),
+            chunk_size: 100,
+        },
+        append: PerItemCost {
+            base: Cost(20),
+            per_chunk: Cost(2),
+            chunk_size: 100,
+        },
+        size_of: Cost(14),
+        height: Cost(26),
+        inputs: Cost(10),
+        outputs: Cost(10),
+        self_box: Cost(10),
+        miner_pubkey: Cost(20),
+        context: Cost(1),
+        extract_amount: Cost(8),
+        extract_script_bytes: Cost(10),
+        extract_bytes: Cost(12),
+        extract_bytes_with_no_ref: Cost(12),
+        extract_id: Cost(12),
+        extract_creation_info: Cost(16),
+        extract_register_as: Cost(50),
+        get_var: Cost(100),
+        deserialize: Cost(100),
+        bool_to_sigma_prop: Cost(15),
+        sigma_and: PerItemCost {
+            base: Cost(10),
+            per_chunk: Cost(2),
+            chunk_size: 1,
+        },
+        sigma_or: PerItemCost {
+            base: Cost(10),
+            per_chunk: Cost(2),
+            chunk_size: 1,
+        },
+        atleast: PerItemCost {
+            base: Cost(20),
+            per_chunk: Cost(3),
+            chunk_size: 5,
+        },
+        create_prove_dlog: Cost(10),
+        create_prove_dh_tuple: Cost(20),
+        sigma_prop_bytes: PerItemCost {
+            base: Cost(35),
+            per_chunk: Cost(6),
+            chunk_size: 1,
+        },
+        option_get: Cost(15),
+        option_get_or_else: Cost(20),
+        option_is_defined: Cost(10),
+        tuple: Cost(15),
+        select_field: Cost(10),
+        long_to_byte_array: Cost(17),
+        byte_array_to_long: Cost(16),
+        byte_array_to_bigint: Cost(30),
+        exponentiate: Cost(900),
+        multiply_group: Cost(40),
+        decode_point: Cost(300),
+        group_generator: Cost(10),
+        upcast: Cost(10),
+        downcast: Cost(10),
+        mod_q: Cost(100),
+        plus_mod_q: Cost(100),
+        minus_mod_q: Cost(100),
+        negation: Cost(30),
+        logical_not: Cost(15),
+        method_call: Cost(10),
+        property_call: Cost(10),
+        arith_plus_minus: TypeBasedCost {
+            primitive: Cost(15),
+            big_int: Cost(20),
+            other: Cost(15),
+        },
+        arith_multiply: TypeBasedCost {
+            primitive: Cost(15),
+            big_int: Cost(40),
+            other: Cost(15),
+        },
+        arith_divide: TypeBasedCost {
+            primitive: Cost(15),
+            big_int: Cost(40),
+            other: Cost(15),
+        },
+        arith_min_max: TypeBasedCost {
+            primitive: Cost(5),
+            big_int: Cost(10),
+            other: Cost(5),
+        },
+        numeric_cmp: TypeBasedCost {
+            primitive: Cost(20),
+            big_int: Cost(30),
+            other: Cost(20),
+        },
+        eq: TypeBasedCost {
+            primitive: Cost(3),
+            big_int: Cost(5),
+            other: Cost(3),
+        },
+        eq_group_element: Cost(172),
+        eq_coll: PerItemCost {
+            base: Cost(10),
+            per_chunk: Cost(2),
+            chunk_size: 96,
+        },
+        logical_and_or: Cost(20),
+        logical_xor: Cost(20),
+        calc_blake2b256: PerItemCost {
+            base: Cost(20),
+            per_chunk: Cost(7),
+            chunk_size: 128,
+        },
+        calc_sha256: PerItemCost {
+            base: Cost(80),
+            per_chunk: Cost(8),
+            chunk_size: 64,
+        },
+        xor: PerItemCost {
+            base: Cost(10),
+            per_chunk: Cost(2),
+            chunk_size: 128,
+        },
+        coll_get_or_else: Cost(30),
+        coll_indices: PerItemCost {
+            base: Cost(20),
+            per_chunk: Cost(2),
+            chunk_size: 128,
+        },
+        coll_flat_map: PerItemCost {
+            base: Cost(60),
+            per_chunk: Cost(10),
+            chunk_size: 8,
+        },
+        coll_patch: PerItemCost {
+            base: Cost(30),
+            per_chunk: Cost(2),
+            chunk_size: 10,
+        },
+        coll_updated: PerItemCost {
+            base: Cost(20),
+            per_chunk: Cost(1),
+            chunk_size: 10,
+        },
+        coll_update_many: PerItemCost {
+            base: Cost(20),
+            per_chunk: Cost(2),
+            chunk_size: 10,
+        },
+        coll_index_of: PerItemCost {
+            base: Cost(20),
+            per_chunk: Cost(10),
+            chunk_size: 2,
+        },
+        coll_zip: PerItemCost {
+            base: Cost(10),
+            per_chunk: Cost(1),
+            chunk_size: 10,
+        },
+        avl_tree_create_verifier: PerItemCost {
+            base: Cost(110),
+            per_chunk: Cost(20),
+            chunk_size: 64,
+        },
+        avl_tree_lookup: PerItemCost {
+            base: Cost(40),
+            per_chunk: Cost(10),
+            chunk_size: 1,
+        },
+        avl_tree_insert: PerItemCost {
+            base: Cost(40),
+            per_chunk: Cost(10),
+            chunk_size: 1,
+        },
+        avl_tree_update: PerItemCost {
+            base: Cost(120),
+            per_chunk: Cost(20),
+            chunk_size: 1,
+        },
+        avl_tree_remove: PerItemCost {
+            base: Cost(100),
+            per_chunk: Cost(15),
+            chunk_size: 1,
+        },
+        // parse challenge (10) + compute commitments (3400) + serialize for Fiat-Shamir (570)
+        prove_dlog_verification: Cost(3980),
+        // parse challenge (10) + compute commitments (6450) + serialize for Fiat-Shamir (680)
+        prove_dh_tuple_verification: Cost(7140),
+        proof_tree_conjecture_to_bytes: Cost(15),
+        parse_polynomial: PerItemCost {
+            base: Cost(10),
+            per_chunk: Cost(10),
+            chunk_size: 1,
+        },
+        evaluate_polynomial: PerItemCost {
+            base: Cost(3),
+            per_chunk: Cost(3),
+            chunk_size: 1,
+        },
     };
 
-    pub fn cost_of(&self, _: &Expr) -> Cost {
-        Cost(1)
+    /// Fixed (or type-based) cost of the node, charged before the node is evaluated.
+    /// Nodes with per-item costs are charged during their evaluation (`Cost(0)` here).
+    pub fn cost_of(&self, expr: &Expr) -> Cost {
+        match expr {
+            Expr::Const(_) => self.constant.clone(),
+            Expr::ConstPlaceholder(_) => self.constant_placeholder.clone(),
+            Expr::BinOp(op) => self.bin_op_cost(&op.expr),
+            Expr::LogicalNot(_) => self.logical_not.clone(),
+            Expr::ValUse(_) => self.val_use.clone(),
+            Expr::FuncValue(_) => self.func_value.clone(),
+            Expr::Apply(_) => self.apply.clone(),
+            Expr::ByIndex(_) => self.by_index.clone(),
+            Expr::SizeOf(_) => self.size_of.clone(),
+            Expr::GlobalVars(v) => match v {
+                GlobalVars::Inputs => self.inputs.clone(),
+                GlobalVars::Outputs => self.outputs.clone(),
+                GlobalVars::Height => self.height.clone(),
+                GlobalVars::SelfBox => self.self_box.clone(),
+                GlobalVars::MinerPubKey => self.miner_pubkey.clone(),
+            },
+            Expr::Context(_) => self.context.clone(),
+            Expr::ExtractAmount(_) => self.extract_amount.clone(),
+            Expr::ExtractScriptBytes(_) => self.extract_script_bytes.clone(),
+            Expr::ExtractBytes(_) => self.extract_bytes.clone(),
+            Expr::ExtractBytesWithNoRef(_) => self.extract_bytes_with_no_ref.clone(),
+            Expr::ExtractId(_) => self.extract_id.clone(),
+            Expr::ExtractCreationInfo(_) => self.extract_creation_info.clone(),
+            Expr::ExtractRegisterAs(_) => self.extract_register_as.clone(),
+            Expr::GetVar(_) => self.get_var.clone(),
+            Expr::DeserializeContext(_) | Expr::DeserializeRegister(_) => self.deserialize.clone(),
+            Expr::BoolToSigmaProp(_) => self.bool_to_sigma_prop.clone(),
+            Expr::CreateProveDlog(_) => self.create_prove_dlog.clone(),
+            Expr::CreateProveDhTuple(_) => self.create_prove_dh_tuple.clone(),
+            Expr::OptionGet(_) => self.option_get.clone(),
+            Expr::OptionGetOrElse(_) => self.option_get_or_else.clone(),
+            Expr::OptionIsDefined(_) => self.option_is_defined.clone(),
+            Expr::Tuple(_) => self.tuple.clone(),
+            Expr::SelectField(_) => self.select_field.clone(),
+            Expr::LongToByteArray(_) => self.long_to_byte_array.clone(),
+            Expr::ByteArrayToLong(_) => self.byte_array_to_long.clone(),
+            Expr::ByteArrayToBigInt(_) => self.byte_array_to_bigint.clone(),
+            Expr::Exponentiate(_) => self.exponentiate.clone(),
+            Expr::MultiplyGroup(_) => self.multiply_group.clone(),
+            Expr::DecodePoint(_) => self.decode_point.clone(),
+            Expr::GroupGenerator(_) => self.group_generator.clone(),
+            Expr::Upcast(_) => self.upcast.clone(),
+            Expr::Downcast(_) => self.downcast.clone(),
+            Expr::ModQ(_) => self.mod_q.clone(),
+            Expr::PlusModQ(_) => self.plus_mod_q.clone(),
+            Expr::MinusModQ(_) => self.minus_mod_q.clone(),
+            Expr::Negation(_) => self.negation.clone(),
+            Expr::PropertyCall(_) => self.property_call.clone(),
+            Expr::MethodCall(_) => self.method_call.clone(),
+            // charged during the evaluation
+            Expr::ValDef(_)
+            | Expr::BlockValue(_)
+            | Expr::Map(_)
+            | Expr::Filter(_)
+            | Expr::Fold(_)
+            | Expr::Exists(_)
+            | Expr::ForAll(_)
+            | Expr::Slice(_)
+            | Expr::Append(_)
+            | Expr::SigmaAnd(_)
+            | Expr::SigmaOr(_)
+            | Expr::Atleast(_)
+            | Expr::SigmaPropBytes(_)
+            | Expr::CalcBlake2b256(_)
+            | Expr::CalcSha256(_)
+            | Expr::Xor(_) => Cost(0),
+        }
+    }
+
+    fn bin_op_cost(&self, op: &BinOp) -> Cost {
+        let tpe = op.left.tpe();
+        match op.kind {
+            BinOpKind::Arith(ArithOp::Plus) | BinOpKind::Arith(ArithOp::Minus) => {
+                self.arith_plus_minus.cost(&tpe)
+            }
+            BinOpKind::Arith(ArithOp::Multiply) => self.arith_multiply.cost(&tpe),
+            BinOpKind::Arith(ArithOp::Divide) | BinOpKind::Arith(ArithOp::Modulo) => {
+                self.arith_divide.cost(&tpe)
+            }
+            BinOpKind::Arith(ArithOp::Max) | BinOpKind::Arith(ArithOp::Min) => {
+                self.arith_min_max.cost(&tpe)
+            }
+            BinOpKind::Relation(RelationOp::Eq) | BinOpKind::Relation(RelationOp::NEq) => {
+                match tpe {
+                    SType::SGroupElement => self.eq_group_element.clone(),
+                    // collections are charged per item during the evaluation
+                    SType::SColl(_) => Cost(0),
+                    _ => self.eq.cost(&tpe),
+                }
+            }
+            BinOpKind::Relation(_) => self.numeric_cmp.cost(&tpe),
+            BinOpKind::Logical(LogicalOp::Xor) => self.logical_xor.clone(),
+            BinOpKind::Logical(_) => self.logical_and_or.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn per_item_cost() {
+        let c = Costs::DEFAULT.calc_blake2b256;
+        assert_eq!(c.cost(0), Cost(27));
+        assert_eq!(c.cost(128), Cost(27));
+        assert_eq!(c.cost(129), Cost(34));
+        assert_eq!(c.cost(1024), Cost(76));
+    }
+
+    #[test]
+    fn type_based_cost() {
+        let c = &Costs::DEFAULT.arith_multiply;
+        assert_eq!(c.cost(&SType::SInt), Cost(15));
+        assert_eq!(c.cost(&SType::SBigInt), Cost(40));
     }
 }
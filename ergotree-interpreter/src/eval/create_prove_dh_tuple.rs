@@ -0,0 +1,45 @@
+use ergo_chain_types::EcPoint;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::create_prove_dh_tuple::CreateProveDhTuple;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::sigma_boolean::ProveDhTuple;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for CreateProveDhTuple {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let g = self.g.eval(ctx)?.try_extract_into::<EcPoint>()?;
+        let h = self.h.eval(ctx)?.try_extract_into::<EcPoint>()?;
+        let u = self.u.eval(ctx)?.try_extract_into::<EcPoint>()?;
+        let v = self.v.eval(ctx)?.try_extract_into::<EcPoint>()?;
+        Ok(SigmaProp::from(ProveDhTuple::new(g, h, u, v)).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval() {
+        let dht = force_any_val::<ProveDhTuple>();
+        let expr: Expr = CreateProveDhTuple::new(
+            (*dht.g).clone().into(),
+            (*dht.h).clone().into(),
+            (*dht.u).clone().into(),
+            (*dht.v).clone().into(),
+        )
+        .unwrap()
+        .into();
+        assert_eq!(eval_out_wo_ctx::<SigmaProp>(&expr), SigmaProp::from(dht));
+    }
+}
@@ -0,0 +1,39 @@
+use ergo_chain_types::EcPoint;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::create_provedlog::CreateProveDlog;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for CreateProveDlog {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let value_v = self.input.eval(ctx)?;
+        let ecpoint = value_v.try_extract_into::<EcPoint>()?;
+        Ok(SigmaProp::from(ProveDlog::new(ecpoint)).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval() {
+        let pk = force_any_val::<EcPoint>();
+        let expr: Expr = CreateProveDlog::new(pk.clone().into()).unwrap().into();
+        assert_eq!(
+            eval_out_wo_ctx::<SigmaProp>(&expr),
+            SigmaProp::from(ProveDlog::new(pk))
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use ergo_chain_types::EcPoint;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::decode_point::DecodePoint;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::serialization::SigmaSerializable;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for DecodePoint {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let bytes = self.input.eval(ctx)?.try_extract_into::<Vec<u8>>()?;
+        Ok(EcPoint::sigma_parse_bytes(&bytes)?.into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    proptest! {
+
+        #[test]
+        fn eval_roundtrip(point in any::<EcPoint>()) {
+            let bytes = point.sigma_serialize_bytes().unwrap();
+            let expr: Expr = DecodePoint::new(bytes.into()).unwrap().into();
+            prop_assert_eq!(eval_out_wo_ctx::<EcPoint>(&expr), point);
+        }
+    }
+
+    #[test]
+    fn eval_invalid_bytes() {
+        let expr: Expr = DecodePoint::new(vec![1u8, 2, 3].into()).unwrap().into();
+        assert!(try_eval_out_wo_ctx::<EcPoint>(&expr).is_err());
+    }
+}
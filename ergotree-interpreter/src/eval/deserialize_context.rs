@@ -0,0 +1,136 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::deserialize_context::DeserializeContext;
+use ergotree_ir::mir::expr::Expr;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::serialization::sigma_byte_reader;
+use ergotree_ir::serialization::sigma_byte_reader::SigmaByteRead;
+use ergotree_ir::serialization::SigmaSerializable;
+use ergotree_ir::types::stype::SType;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for DeserializeContext {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let c = ctx
+            .ctx
+            .extension
+            .values
+            .get(&self.id)
+            .cloned()
+            .ok_or_else(|| {
+                EvalError::NotFound(format!(
+                    "DeserializeContext: no context variable with id {0}",
+                    self.id
+                ))
+            })?;
+        let expected_tpe = SType::SColl(SType::SByte.into());
+        if c.tpe != expected_tpe {
+            return Err(EvalError::UnexpectedExpr(format!(
+                "DeserializeContext: expected context variable {0} to be of type {1}, got: {2}",
+                self.id, expected_tpe, c.tpe
+            )));
+        }
+        let bytes = c.v.try_extract_into::<Vec<u8>>()?;
+        let expr = parse_deserialized_expr(&bytes, "DeserializeContext")?;
+        if expr.tpe() != self.tpe {
+            return Err(EvalError::UnexpectedExpr(format!(
+                "DeserializeContext: expected deserialized expr to have type {0}, got: {1}",
+                self.tpe,
+                expr.tpe()
+            )));
+        }
+        expr.eval(ctx)
+    }
+}
+
+/// Parse the expression to be substituted for DeserializeContext/DeserializeRegister.
+/// The bytes are supplied by the prover, so nested DeserializeContext/DeserializeRegister
+/// are rejected (they could refer back to the same bytes and recurse infinitely).
+pub(crate) fn parse_deserialized_expr(bytes: &[u8], op_name: &str) -> Result<Expr, EvalError> {
+    let mut r = sigma_byte_reader::from_bytes(bytes);
+    let expr = Expr::sigma_parse(&mut r)?;
+    if r.was_deserialize() {
+        return Err(EvalError::UnexpectedExpr(format!(
+            "{0}: nested DeserializeContext/DeserializeRegister in the deserialized expr",
+            op_name
+        )));
+    }
+    Ok(expr)
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use indexmap::IndexMap;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+    use crate::sigma_protocol::prover::ContextExtension;
+
+    fn ctx_with_script_var(id: u8, script: &Expr) -> Rc<Context> {
+        let mut values = IndexMap::new();
+        values.insert(id, script.sigma_serialize_bytes().unwrap().into());
+        Rc::new(force_any_val::<Context>().with_extension(ContextExtension { values }))
+    }
+
+    #[test]
+    fn eval_deserialize_context() {
+        let script: Expr = BinOp {
+            kind: RelationOp::Ge.into(),
+            left: Box::new(GlobalVars::Height.into()),
+            right: Box::new(0i32.into()),
+        }
+        .into();
+        let ctx = ctx_with_script_var(1, &script);
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SBoolean,
+            id: 1,
+        }
+        .into();
+        assert!(eval_out::<bool>(&expr, ctx));
+    }
+
+    #[test]
+    fn eval_deserialize_context_wrong_type() {
+        let ctx = ctx_with_script_var(1, &Expr::from(1i32));
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SBoolean,
+            id: 1,
+        }
+        .into();
+        assert!(try_eval_out::<bool>(&expr, ctx).is_err());
+    }
+
+    #[test]
+    fn eval_deserialize_context_self_reference() {
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SBoolean,
+            id: 1,
+        }
+        .into();
+        let ctx = ctx_with_script_var(1, &expr);
+        assert!(try_eval_out::<bool>(&expr, ctx).is_err());
+    }
+
+    #[test]
+    fn eval_deserialize_context_missing_var() {
+        let ctx = ctx_with_script_var(1, &Expr::from(true));
+        let expr: Expr = DeserializeContext {
+            tpe: SType::SBoolean,
+            id: 2,
+        }
+        .into();
+        assert!(try_eval_out::<bool>(&expr, ctx).is_err());
+    }
+}
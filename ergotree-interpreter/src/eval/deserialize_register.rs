@@ -0,0 +1,133 @@
+use std::convert::TryInto;
+
+use ergotree_ir::chain::ergo_box::RegisterId;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::deserialize_register::DeserializeRegister;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::types::stype::SType;
+
+use crate::eval::deserialize_context::parse_deserialized_expr;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for DeserializeRegister {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let reg_id: RegisterId = self.reg.try_into().map_err(|e| {
+            EvalError::RegisterIdOutOfBounds(format!(
+                "register index {} is out of bounds: {:?}",
+                self.reg, e
+            ))
+        })?;
+        let reg_val_opt = ctx.ctx.self_box.get_register(reg_id).map_err(|e| {
+            EvalError::NotFound(format!(
+                "Error getting the register id {} with error {:?}",
+                reg_id, e
+            ))
+        })?;
+        match reg_val_opt {
+            Some(c) => {
+                let expected_tpe = SType::SColl(SType::SByte.into());
+                if c.tpe != expected_tpe {
+                    return Err(EvalError::UnexpectedExpr(format!(
+                        "DeserializeRegister: expected register {0} to be of type {1}, got: {2}",
+                        reg_id, expected_tpe, c.tpe
+                    )));
+                }
+                let bytes = c.v.try_extract_into::<Vec<u8>>()?;
+                let expr = parse_deserialized_expr(&bytes, "DeserializeRegister")?;
+                if expr.tpe() != self.tpe {
+                    return Err(EvalError::UnexpectedExpr(format!(
+                        "DeserializeRegister: expected deserialized expr to have type {0}, got: {1}",
+                        self.tpe,
+                        expr.tpe()
+                    )));
+                }
+                expr.eval(ctx)
+            }
+            None => match &self.default {
+                Some(default) => default.eval(ctx),
+                None => Err(EvalError::NotFound(format!(
+                    "DeserializeRegister: register {0} is empty and no default value is provided",
+                    reg_id
+                ))),
+            },
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use ergotree_ir::chain::ergo_box::ErgoBox;
+    use ergotree_ir::chain::ergo_box::NonMandatoryRegisters;
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::serialization::SigmaSerializable;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+
+    fn ctx_with_self_regs(regs: Vec<Constant>) -> Rc<Context> {
+        let ctx = force_any_val::<Context>();
+        let b = force_any_val::<ErgoBox>();
+        let self_box = ErgoBox::new(
+            b.value,
+            b.ergo_tree,
+            b.tokens,
+            NonMandatoryRegisters::try_from(regs).unwrap(),
+            b.creation_height,
+            b.transaction_id,
+            b.index,
+        )
+        .unwrap();
+        Rc::new(Context {
+            self_box: Arc::new(self_box),
+            ..ctx
+        })
+    }
+
+    #[test]
+    fn eval_deserialize_register() {
+        let script: Expr = 42i64.into();
+        let ctx = ctx_with_self_regs(vec![script.sigma_serialize_bytes().unwrap().into()]);
+        let expr: Expr = DeserializeRegister {
+            reg: 4,
+            tpe: SType::SLong,
+            default: None,
+        }
+        .into();
+        assert_eq!(eval_out::<i64>(&expr, ctx), 42);
+    }
+
+    #[test]
+    fn eval_deserialize_register_default() {
+        let ctx = ctx_with_self_regs(vec![]);
+        let expr: Expr = DeserializeRegister {
+            reg: 4,
+            tpe: SType::SLong,
+            default: Some(Box::new(1i64.into())),
+        }
+        .into();
+        assert_eq!(eval_out::<i64>(&expr, ctx), 1);
+    }
+
+    #[test]
+    fn eval_deserialize_register_empty() {
+        let ctx = ctx_with_self_regs(vec![]);
+        let expr: Expr = DeserializeRegister {
+            reg: 4,
+            tpe: SType::SLong,
+            default: None,
+        }
+        .into();
+        assert!(try_eval_out::<i64>(&expr, ctx).is_err());
+    }
+}
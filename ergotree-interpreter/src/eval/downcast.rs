@@ -0,0 +1,111 @@
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use ergotree_ir::bigint256::BigInt256;
+use ergotree_ir::mir::downcast::Downcast;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::types::stype::SType;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+fn downcast_err<T: Display>(v: T, tpe: &str) -> EvalError {
+    EvalError::ArithmeticException(format!("Downcast: value {0} is out of {1} range", v, tpe))
+}
+
+fn downcast_to<T>(in_v: Value, tpe: &str) -> Result<T, EvalError>
+where
+    T: TryFrom<i8> + TryFrom<i16> + TryFrom<i32> + TryFrom<i64> + TryFrom<BigInt256>,
+{
+    match in_v {
+        Value::Byte(v) => T::try_from(v).map_err(|_| downcast_err(v, tpe)),
+        Value::Short(v) => T::try_from(v).map_err(|_| downcast_err(v, tpe)),
+        Value::Int(v) => T::try_from(v).map_err(|_| downcast_err(v, tpe)),
+        Value::Long(v) => T::try_from(v).map_err(|_| downcast_err(v, tpe)),
+        Value::BigInt(v) => T::try_from(v.clone()).map_err(|_| downcast_err(v, tpe)),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "Downcast: expected numeric value, got {0:?}",
+            in_v
+        ))),
+    }
+}
+
+impl Evaluable for Downcast {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        match self.tpe {
+            SType::SBigInt => match input_v {
+                Value::BigInt(_) => Ok(input_v),
+                _ => Err(EvalError::UnexpectedValue(format!(
+                    "Downcast: cannot downcast {0:?} to BigInt",
+                    input_v
+                ))),
+            },
+            SType::SLong => Ok(downcast_to::<i64>(input_v, "Long")?.into()),
+            SType::SInt => Ok(downcast_to::<i32>(input_v, "Int")?.into()),
+            SType::SShort => Ok(downcast_to::<i16>(input_v, "Short")?.into()),
+            SType::SByte => Ok(downcast_to::<i8>(input_v, "Byte")?.into()),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Downcast: expected numeric target type, got {0:?}",
+                self.tpe
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use num_traits::Bounded;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    proptest! {
+
+        #[test]
+        fn from_long_in_range(v in any::<i8>()) {
+            let c: Expr = (v as i64).into();
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i8>(&Downcast::new(c.clone(), SType::SByte).unwrap().into()),
+                v
+            );
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i16>(&Downcast::new(c.clone(), SType::SShort).unwrap().into()),
+                v as i16
+            );
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i32>(&Downcast::new(c, SType::SInt).unwrap().into()),
+                v as i32
+            );
+        }
+
+        #[test]
+        fn from_bigint_in_range(v in any::<i64>()) {
+            let c: Expr = BigInt256::from(v).into();
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i64>(&Downcast::new(c, SType::SLong).unwrap().into()),
+                v
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        let expr: Expr = Downcast::new((i32::MAX as i64 + 1).into(), SType::SInt)
+            .unwrap()
+            .into();
+        assert!(try_eval_out_wo_ctx::<i32>(&expr).is_err());
+        let expr: Expr = Downcast::new(128i32.into(), SType::SByte).unwrap().into();
+        assert!(try_eval_out_wo_ctx::<i8>(&expr).is_err());
+        let expr: Expr = Downcast::new(BigInt256::max_value().into(), SType::SLong)
+            .unwrap()
+            .into();
+        assert!(try_eval_out_wo_ctx::<i64>(&expr).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use ergotree_ir::mir::val_def::ValId;
+use ergotree_ir::mir::value::Value;
+
+/// Environment for the evaluation (values bound to ValDef ids)
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub(crate) struct Env {
+    store: HashMap<ValId, Value>,
+}
+
+impl Env {
+    /// Empty environment
+    pub(crate) fn empty() -> Env {
+        Env {
+            store: HashMap::new(),
+        }
+    }
+
+    /// Bind the value to the given id, returning the previously bound value (if any)
+    pub(crate) fn insert(&mut self, idx: ValId, v: Value) -> Option<Value> {
+        self.store.insert(idx, v)
+    }
+
+    /// Unbind the value for the given id
+    pub(crate) fn remove(&mut self, idx: &ValId) -> Option<Value> {
+        self.store.remove(idx)
+    }
+
+    /// Get the value bound to the given id
+    pub(crate) fn get(&self, idx: ValId) -> Option<&Value> {
+        self.store.get(&idx)
+    }
+
+    /// Copy of all bindings, ordered by id
+    pub(crate) fn snapshot(&self) -> Vec<(ValId, Value)> {
+        let mut bindings: Vec<(ValId, Value)> =
+            self.store.iter().map(|(id, v)| (*id, v.clone())).collect();
+        bindings.sort_by_key(|(id, _)| *id);
+        bindings
+    }
+}
@@ -46,6 +46,9 @@ pub enum EvalError {
     /// Arithmetic exception error
     #[error("Arithmetic exception: {0}")]
     ArithmeticException(String),
+    /// `get` called on an empty Option
+    #[error("Option.get is called on None")]
+    OptionGetOnNone,
     /// Misc error
     #[error("error: {0}")]
     Misc(String),
@@ -157,6 +160,14 @@ impl EvalError {
         })
     }
 
+    /// Underlying error (without the source span)
+    pub(crate) fn unwrap_spanned(&self) -> &EvalError {
+        match self {
+            EvalError::Spanned(e) => e.error.as_ref(),
+            e => e,
+        }
+    }
+
     /// Wrap eval error with source code
     pub fn wrap_spanned_with_src(self, source: String) -> Self {
         #[allow(clippy::panic)]
@@ -191,8 +202,10 @@ mod tests {
     use std::rc::Rc;
 
     use ergotree_ir::source_span::SourceSpan;
-    use expect_test::expect;
+    use expect_test::expect_file;
 
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
     use ergotree_ir::mir::expr::Expr;
     use ergotree_ir::pretty_printer::PosTrackingWriter;
     use ergotree_ir::pretty_printer::Print;
@@ -204,7 +217,7 @@ mod tests {
     use crate::eval::error::SpannedWithSourceEvalError;
     use crate::eval::tests::try_eval_out;
 
-    fn check(expr: Expr, expected_tree: expect_test::Expect) {
+    fn check(expr: Expr, expected_tree: expect_test::ExpectFile) {
         let mut w = PosTrackingWriter::new();
         let spanned_expr = expr.print(&mut w).unwrap();
         dbg!(&spanned_expr);
@@ -235,5 +248,36 @@ mod tests {
         assert_eq!(err_raw.source_span, expected_span);
     }
 
+    fn div_by_zero_expr() -> Expr {
+        BinOp {
+            kind: ArithOp::Plus.into(),
+            left: Box::new(4i32.into()),
+            right: Box::new(
+                BinOp {
+                    kind: ArithOp::Divide.into(),
+                    left: Box::new(2i32.into()),
+                    right: Box::new(0i32.into()),
+                }
+                .into(),
+            ),
+        }
+        .into()
+    }
+
+    #[test]
+    fn pretty_binop_div_zero() {
+        // the report is indented, so it is compared with a snapshot file
+        // (inline snapshots are stripped of the common indentation)
+        check(
+            div_by_zero_expr(),
+            expect_file!["snapshots/pretty_binop_div_zero.txt"],
+        );
+    }
+
+    #[test]
+    fn span_binop_div_zero() {
+        check_error_span(div_by_zero_expr(), (4, 5).into());
+    }
+
     // TODO mini: restore tests that was here before minification (see git history of this file)
 }
@@ -0,0 +1,89 @@
+use ergotree_ir::bigint256::BigInt256;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::exponentiate::Exponentiate;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::dlog_group;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Exponentiate {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let base = self
+            .left
+            .eval(ctx)?
+            .try_extract_into::<ergo_chain_types::EcPoint>()?;
+        let exponent = self.right.eval(ctx)?.try_extract_into::<BigInt256>()?;
+        // exponent is reduced modulo the group order (as in the reference implementation)
+        let scalar = dlog_group::bigint256_to_scalar_mod_q(exponent);
+        Ok(ergo_chain_types::ec_point::exponentiate(&base, &scalar).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergo_chain_types::ec_point::generator;
+    use ergo_chain_types::ec_point::identity;
+    use ergo_chain_types::EcPoint;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::group_generator::GroupGenerator;
+    use num_traits::Bounded;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_square() {
+        let expr: Expr = Exponentiate::new(GroupGenerator.into(), BigInt256::from(2i64).into())
+            .unwrap()
+            .into();
+        assert_eq!(
+            eval_out_wo_ctx::<EcPoint>(&expr),
+            generator() * &generator()
+        );
+    }
+
+    #[test]
+    fn eval_zero_exponent() {
+        let expr: Expr = Exponentiate::new(GroupGenerator.into(), BigInt256::from(0i64).into())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<EcPoint>(&expr), identity());
+    }
+
+    fn exponentiate_generator(exponent: BigInt256) -> EcPoint {
+        let expr: Expr = Exponentiate::new(GroupGenerator.into(), exponent.into())
+            .unwrap()
+            .into();
+        eval_out_wo_ctx::<EcPoint>(&expr)
+    }
+
+    #[test]
+    fn eval_negative_exponent() {
+        // g^-1 * g == 1
+        assert_eq!(
+            exponentiate_generator(BigInt256::from(-1i64)) * &generator(),
+            identity()
+        );
+        // g^-2 == (g^2)^-1
+        assert_eq!(
+            exponentiate_generator(BigInt256::from(-2i64)) * &exponentiate_generator(2i64.into()),
+            identity()
+        );
+    }
+
+    #[test]
+    fn eval_extreme_exponents() {
+        // exponents >= q do not fit into BigInt (2^255 - 1 < q),
+        // so check that g^(2^255 - 1) * g * g^(-2^255) == 1
+        let max = BigInt256::max_value();
+        let min = BigInt256::min_value();
+        assert_eq!(
+            exponentiate_generator(max) * &generator() * &exponentiate_generator(min),
+            identity()
+        );
+    }
+}
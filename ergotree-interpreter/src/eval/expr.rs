@@ -12,12 +12,76 @@ use super::Evaluable;
 impl Evaluable for Expr {
     fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
         ctx.cost_accum.add_cost_of(self)?;
+        if let Some(tracer) = ctx.tracer.as_mut() {
+            tracer.enter();
+        }
         let res = match self {
             Expr::Const(c) => Ok(Value::from(c.v.clone())),
-            Expr::ConstPlaceholder(_) => Err(EvalError::UnexpectedExpr(
-                ("ConstPlaceholder is not supported").to_string(),
+            Expr::ConstPlaceholder(op) => op.eval(ctx),
+            Expr::BinOp(op) => op.eval(ctx),
+            Expr::LogicalNot(op) => op.eval(ctx),
+            Expr::BlockValue(op) => op.eval(ctx),
+            Expr::ValDef(_) => Err(EvalError::UnexpectedExpr(
+                ("ValDef is evaluated in a BlockValue").to_string(),
             )),
+            Expr::ValUse(op) => op.eval(ctx),
+            Expr::FuncValue(op) => op.eval(ctx),
+            Expr::Apply(op) => op.eval(ctx),
+            Expr::Map(op) => op.eval(ctx),
+            Expr::Filter(op) => op.eval(ctx),
+            Expr::Fold(op) => op.eval(ctx),
+            Expr::Exists(op) => op.eval(ctx),
+            Expr::ForAll(op) => op.eval(ctx),
+            Expr::ByIndex(op) => op.eval(ctx),
+            Expr::Slice(op) => op.eval(ctx),
+            Expr::Append(op) => op.eval(ctx),
+            Expr::SizeOf(op) => op.eval(ctx),
+            Expr::GlobalVars(op) => op.eval(ctx),
+            Expr::Context(op) => op.eval(ctx),
+            Expr::ExtractAmount(op) => op.eval(ctx),
+            Expr::ExtractScriptBytes(op) => op.eval(ctx),
+            Expr::ExtractBytes(op) => op.eval(ctx),
+            Expr::ExtractBytesWithNoRef(op) => op.eval(ctx),
+            Expr::ExtractId(op) => op.eval(ctx),
+            Expr::ExtractCreationInfo(op) => op.eval(ctx),
+            Expr::ExtractRegisterAs(op) => op.eval(ctx),
+            Expr::GetVar(op) => op.eval(ctx),
+            Expr::DeserializeContext(op) => op.eval(ctx),
+            Expr::DeserializeRegister(op) => op.eval(ctx),
+            Expr::BoolToSigmaProp(op) => op.eval(ctx),
+            Expr::SigmaAnd(op) => op.eval(ctx),
+            Expr::SigmaOr(op) => op.eval(ctx),
+            Expr::Atleast(op) => op.eval(ctx),
+            Expr::CreateProveDlog(op) => op.eval(ctx),
+            Expr::CreateProveDhTuple(op) => op.eval(ctx),
+            Expr::SigmaPropBytes(op) => op.eval(ctx),
+            Expr::OptionGet(op) => op.eval(ctx),
+            Expr::OptionGetOrElse(op) => op.eval(ctx),
+            Expr::OptionIsDefined(op) => op.eval(ctx),
+            Expr::Tuple(op) => op.eval(ctx),
+            Expr::SelectField(op) => op.eval(ctx),
+            Expr::CalcBlake2b256(op) => op.eval(ctx),
+            Expr::CalcSha256(op) => op.eval(ctx),
+            Expr::Xor(op) => op.eval(ctx),
+            Expr::LongToByteArray(op) => op.eval(ctx),
+            Expr::ByteArrayToLong(op) => op.eval(ctx),
+            Expr::ByteArrayToBigInt(op) => op.eval(ctx),
+            Expr::Exponentiate(op) => op.eval(ctx),
+            Expr::MultiplyGroup(op) => op.eval(ctx),
+            Expr::DecodePoint(op) => op.eval(ctx),
+            Expr::GroupGenerator(op) => op.eval(ctx),
+            Expr::Upcast(op) => op.eval(ctx),
+            Expr::Downcast(op) => op.eval(ctx),
+            Expr::ModQ(op) => op.eval(ctx),
+            Expr::PlusModQ(op) => op.eval(ctx),
+            Expr::MinusModQ(op) => op.eval(ctx),
+            Expr::Negation(op) => op.eval(ctx),
+            Expr::PropertyCall(op) => op.eval(ctx),
+            Expr::MethodCall(op) => op.eval(ctx),
         };
+        if let Some(tracer) = ctx.tracer.as_mut() {
+            tracer.exit(self, &res);
+        }
         res.enrich_err(self.span())
     }
 }
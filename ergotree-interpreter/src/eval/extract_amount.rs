@@ -0,0 +1,43 @@
+use ergotree_ir::mir::extract_amount::ExtractAmount;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ExtractAmount {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        match self.input.eval(ctx)? {
+            Value::CBox(b) => Ok(Value::Long(b.value.as_i64())),
+            v => Err(EvalError::UnexpectedValue(format!(
+                "ExtractAmount: expected input to be Value::CBox, got: {0:?}",
+                v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+
+    #[test]
+    fn eval() {
+        let e: Expr = ExtractAmount::new(GlobalVars::SelfBox.into())
+            .unwrap()
+            .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let v = eval_out::<i64>(&e, ctx.clone());
+        assert_eq!(v, ctx.self_box.value.as_i64());
+    }
+}
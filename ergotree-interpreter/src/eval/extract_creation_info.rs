@@ -0,0 +1,45 @@
+use ergotree_ir::mir::extract_creation_info::ExtractCreationInfo;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ExtractCreationInfo {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        match self.input.eval(ctx)? {
+            Value::CBox(b) => Ok(b.creation_info().into()),
+            v => Err(EvalError::UnexpectedValue(format!(
+                "ExtractCreationInfo: expected input to be Value::CBox, got: {0:?}",
+                v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+
+    #[test]
+    fn eval() {
+        let e: Expr = ExtractCreationInfo::new(GlobalVars::SelfBox.into())
+            .unwrap()
+            .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<(i32, Vec<i8>)>(&e, ctx.clone()),
+            ctx.self_box.creation_info()
+        );
+    }
+}
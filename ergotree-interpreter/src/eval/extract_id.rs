@@ -0,0 +1,44 @@
+use ergotree_ir::mir::extract_id::ExtractId;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ExtractId {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        match self.input.eval(ctx)? {
+            Value::CBox(b) => {
+                let bytes: Vec<i8> = b.box_id().into();
+                Ok(bytes.into())
+            }
+            v => Err(EvalError::UnexpectedValue(format!(
+                "ExtractId: expected input to be Value::CBox, got: {0:?}",
+                v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+
+    #[test]
+    fn eval() {
+        let e: Expr = ExtractId::new(GlobalVars::SelfBox.into()).unwrap().into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let bytes: Vec<i8> = ctx.self_box.box_id().into();
+        assert_eq!(eval_out::<Vec<i8>>(&e, ctx.clone()), bytes);
+    }
+}
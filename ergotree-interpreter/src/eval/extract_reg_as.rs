@@ -0,0 +1,98 @@
+use std::convert::TryInto;
+
+use ergotree_ir::chain::ergo_box::RegisterId;
+use ergotree_ir::mir::extract_reg_as::ExtractRegisterAs;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ExtractRegisterAs {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let ir_box = match self.input.eval(ctx)? {
+            Value::CBox(b) => Ok(b),
+            v => Err(EvalError::UnexpectedValue(format!(
+                "ExtractRegisterAs: expected input to be Value::CBox, got: {0:?}",
+                v
+            ))),
+        }?;
+        let id: RegisterId = self.register_id.try_into().map_err(|e| {
+            EvalError::RegisterIdOutOfBounds(format!(
+                "register index {} is out of bounds: {:?}",
+                self.register_id, e
+            ))
+        })?;
+        let reg_val_opt = ir_box.get_register(id).map_err(|e| {
+            EvalError::NotFound(format!(
+                "Error getting the register id {} with error {:?}",
+                id, e
+            ))
+        })?;
+        match reg_val_opt {
+            Some(c) if c.tpe == self.elem_tpe => Ok(Value::Opt(Box::new(Some(c.v.into())))),
+            Some(c) => Err(EvalError::UnexpectedValue(format!(
+                "ExtractRegisterAs: expected register {} value to be of type {}, got: {}",
+                id, self.elem_tpe, c.tpe
+            ))),
+            None => Ok(Value::Opt(Box::new(None))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use ergotree_ir::types::stype::SType;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+
+    #[test]
+    fn eval_box_get_reg_r0() {
+        let get_reg_expr: Expr = ExtractRegisterAs::new(
+            GlobalVars::SelfBox.into(),
+            0,
+            SType::SOption(SType::SLong.into()),
+        )
+        .unwrap()
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let v = eval_out::<Option<i64>>(&get_reg_expr, ctx.clone());
+        assert_eq!(v, Some(ctx.self_box.value.as_i64()));
+    }
+
+    #[test]
+    fn eval_box_get_reg_wrong_type() {
+        let get_reg_expr: Expr = ExtractRegisterAs::new(
+            GlobalVars::SelfBox.into(),
+            0,
+            SType::SOption(SType::SInt.into()),
+        )
+        .unwrap()
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(try_eval_out::<Option<i32>>(&get_reg_expr, ctx).is_err());
+    }
+
+    #[test]
+    fn eval_box_get_reg_out_of_bounds() {
+        let get_reg_expr: Expr = ExtractRegisterAs::new(
+            GlobalVars::SelfBox.into(),
+            10,
+            SType::SOption(SType::SInt.into()),
+        )
+        .unwrap()
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(try_eval_out::<Option<i32>>(&get_reg_expr, ctx).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use ergotree_ir::mir::get_var::GetVar;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for GetVar {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        match ctx.ctx.extension.values.get(&self.var_id) {
+            None => Ok(Value::Opt(Box::new(None))),
+            Some(v) if v.tpe == self.var_tpe => Ok(Value::Opt(Box::new(Some(v.v.clone().into())))),
+            Some(v) => Err(EvalError::UnexpectedValue(format!(
+                "GetVar: expected variable {0} to be of type {1}, got: {2}",
+                self.var_id, self.var_tpe, v.tpe
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::types::stype::SType;
+    use indexmap::IndexMap;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+    use crate::sigma_protocol::prover::ContextExtension;
+
+    fn ctx_with_var(id: u8, c: Constant) -> Rc<Context> {
+        let mut values = IndexMap::new();
+        values.insert(id, c);
+        Rc::new(force_any_val::<Context>().with_extension(ContextExtension { values }))
+    }
+
+    #[test]
+    fn eval_get_var() {
+        let ctx = ctx_with_var(1, 42i32.into());
+        let expr: Expr = GetVar {
+            var_id: 1,
+            var_tpe: SType::SInt,
+        }
+        .into();
+        assert_eq!(eval_out::<Option<i32>>(&expr, ctx), Some(42));
+    }
+
+    #[test]
+    fn eval_get_var_missing() {
+        let ctx = ctx_with_var(1, 42i32.into());
+        let expr: Expr = GetVar {
+            var_id: 2,
+            var_tpe: SType::SInt,
+        }
+        .into();
+        assert_eq!(eval_out::<Option<i32>>(&expr, ctx), None);
+    }
+
+    #[test]
+    fn eval_get_var_wrong_type() {
+        let ctx = ctx_with_var(1, 42i32.into());
+        let expr: Expr = GetVar {
+            var_id: 1,
+            var_tpe: SType::SLong,
+        }
+        .into();
+        assert!(try_eval_out::<Option<i64>>(&expr, ctx).is_err());
+    }
+}
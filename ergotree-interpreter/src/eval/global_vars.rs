@@ -0,0 +1,86 @@
+use ergotree_ir::mir::global_vars::GlobalVars;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::serialization::SigmaSerializable;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for GlobalVars {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        match self {
+            GlobalVars::Height => Ok((ctx.ctx.height as i32).into()),
+            GlobalVars::SelfBox => Ok(ctx.ctx.self_box.clone().into()),
+            GlobalVars::Outputs => Ok(ctx.ctx.outputs.clone().into()),
+            GlobalVars::Inputs => Ok(ctx.ctx.inputs.as_vec().clone().into()),
+            GlobalVars::MinerPubKey => Ok(ctx.ctx.miner_pubkey.sigma_serialize_bytes()?.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use ergotree_ir::chain::ergo_box::ErgoBox;
+    use ergotree_ir::mir::expr::Expr;
+    use sigma_test_util::force_any_val;
+    use sigma_util::AsVecI8;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+
+    #[test]
+    fn eval_height() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let expr: Expr = GlobalVars::Height.into();
+        assert_eq!(eval_out::<i32>(&expr, ctx.clone()), ctx.height as i32);
+    }
+
+    #[test]
+    fn eval_self_box() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let expr: Expr = GlobalVars::SelfBox.into();
+        assert_eq!(
+            eval_out::<Arc<ErgoBox>>(&expr, ctx.clone()).as_ref(),
+            ctx.self_box.as_ref()
+        );
+    }
+
+    #[test]
+    fn eval_outputs() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let expr: Expr = GlobalVars::Outputs.into();
+        assert_eq!(
+            eval_out::<Vec<Arc<ErgoBox>>>(&expr, ctx.clone()),
+            ctx.outputs
+        );
+    }
+
+    #[test]
+    fn eval_inputs() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let expr: Expr = GlobalVars::Inputs.into();
+        assert_eq!(
+            eval_out::<Vec<Arc<ErgoBox>>>(&expr, ctx.clone()),
+            ctx.inputs.as_vec().clone()
+        );
+    }
+
+    #[test]
+    fn eval_miner_pubkey() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let expr: Expr = GlobalVars::MinerPubKey.into();
+        assert_eq!(
+            eval_out::<Vec<i8>>(&expr, ctx.clone()),
+            ctx.miner_pubkey
+                .sigma_serialize_bytes()
+                .unwrap()
+                .as_vec_i8()
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use ergo_chain_types::ec_point::generator;
+use ergotree_ir::mir::group_generator::GroupGenerator;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for GroupGenerator {
+    fn eval(&self, _ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        Ok(generator().into())
+    }
+}
@@ -0,0 +1,34 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::logical_not::LogicalNot;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for LogicalNot {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let input_v_bool = input_v.try_extract_into::<bool>()?;
+        Ok((!input_v_bool).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use ergotree_ir::mir::expr::Expr;
+
+    fn run_eval(input: bool) -> bool {
+        let expr: Expr = LogicalNot::new(input.into()).unwrap().into();
+        eval_out_wo_ctx::<bool>(&expr)
+    }
+
+    #[test]
+    fn eval() {
+        assert!(!run_eval(true));
+        assert!(run_eval(false));
+    }
+}
@@ -0,0 +1,45 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::long_to_byte_array::LongToByteArray;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for LongToByteArray {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let v = self.input.eval(ctx)?.try_extract_into::<i64>()?;
+        Ok(v.to_be_bytes().to_vec().into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::byte_array_to_long::ByteArrayToLong;
+    use ergotree_ir::mir::expr::Expr;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_long_to_byte_array() {
+        let expr: Expr = LongToByteArray::new(1i64.into()).unwrap().into();
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<u8>>(&expr),
+            vec![0u8, 0, 0, 0, 0, 0, 0, 1]
+        );
+    }
+
+    proptest! {
+
+        #[test]
+        fn roundtrip(v in any::<i64>()) {
+            let bytes_expr: Expr = LongToByteArray::new(v.into()).unwrap().into();
+            let expr: Expr = ByteArrayToLong::new(bytes_expr).unwrap().into();
+            prop_assert_eq!(eval_out_wo_ctx::<i64>(&expr), v);
+        }
+    }
+}
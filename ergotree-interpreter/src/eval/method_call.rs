@@ -0,0 +1,19 @@
+use ergotree_ir::mir::method_call::MethodCall;
+use ergotree_ir::mir::value::Value;
+
+use super::smethod_eval_fn;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for MethodCall {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let ov = self.obj.eval(ctx)?;
+        let argsv = self
+            .args
+            .iter()
+            .map(|arg| arg.eval(ctx))
+            .collect::<Result<Vec<_>, _>>()?;
+        smethod_eval_fn(&self.method)?(ctx, ov, argsv)
+    }
+}
@@ -0,0 +1,37 @@
+use ergotree_ir::bigint256::BigInt256;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::minus_mod_q::MinusModQ;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for MinusModQ {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let left = self.left.eval(ctx)?.try_extract_into::<BigInt256>()?;
+        let right = self.right.eval(ctx)?.try_extract_into::<BigInt256>()?;
+        Ok(left
+            .minus_mod_q(&right)
+            .map_err(EvalError::ArithmeticException)?
+            .into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval() {
+        let expr: Expr = MinusModQ::new(BigInt256::from(5i64).into(), BigInt256::from(3i64).into())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<BigInt256>(&expr), BigInt256::from(2i64));
+    }
+}
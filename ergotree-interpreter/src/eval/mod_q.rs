@@ -0,0 +1,39 @@
+use ergotree_ir::bigint256::BigInt256;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::mod_q::ModQ;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for ModQ {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let v = self.input.eval(ctx)?.try_extract_into::<BigInt256>()?;
+        Ok(v.mod_q().map_err(EvalError::ArithmeticException)?.into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    #[test]
+    fn eval_small() {
+        let expr: Expr = ModQ::new(BigInt256::from(42i64).into()).unwrap().into();
+        assert_eq!(eval_out_wo_ctx::<BigInt256>(&expr), BigInt256::from(42i64));
+    }
+
+    #[test]
+    fn eval_overflow() {
+        // -1 mod q = q - 1 which does not fit into BigInt256
+        let expr: Expr = ModQ::new(BigInt256::from(-1i64).into()).unwrap().into();
+        assert!(try_eval_out_wo_ctx::<BigInt256>(&expr).is_err());
+    }
+}
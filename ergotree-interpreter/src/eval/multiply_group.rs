@@ -0,0 +1,49 @@
+use ergo_chain_types::EcPoint;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::multiply_group::MultiplyGroup;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for MultiplyGroup {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let left = self.left.eval(ctx)?.try_extract_into::<EcPoint>()?;
+        let right = self.right.eval(ctx)?.try_extract_into::<EcPoint>()?;
+        Ok((left * &right).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergo_chain_types::ec_point::generator;
+    use ergo_chain_types::ec_point::identity;
+    use ergo_chain_types::ec_point::inverse;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::group_generator::GroupGenerator;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_with_inverse() {
+        let expr: Expr = MultiplyGroup::new(GroupGenerator.into(), inverse(&generator()).into())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<EcPoint>(&expr), identity());
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval_any(left in any::<EcPoint>(), right in any::<EcPoint>()) {
+            let expected = left.clone() * &right;
+            let expr: Expr = MultiplyGroup::new(left.into(), right.into()).unwrap().into();
+            prop_assert_eq!(eval_out_wo_ctx::<EcPoint>(&expr), expected);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use ergotree_ir::mir::negation::Negation;
+use ergotree_ir::mir::value::Value;
+use num_traits::CheckedNeg;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+fn checked_neg<T: CheckedNeg + std::fmt::Display + Into<Value>>(v: T) -> Result<Value, EvalError> {
+    v.checked_neg()
+        .map(|r| r.into())
+        .ok_or_else(|| EvalError::ArithmeticException(format!("Negation: overflow on -({0})", v)))
+}
+
+impl Evaluable for Negation {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        match input_v {
+            Value::Byte(v) => checked_neg(v),
+            Value::Short(v) => checked_neg(v),
+            Value::Int(v) => checked_neg(v),
+            Value::Long(v) => checked_neg(v),
+            Value::BigInt(v) => checked_neg(v),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Negation: expected numeric value, got {0:?}",
+                input_v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::bigint256::BigInt256;
+    use ergotree_ir::mir::expr::Expr;
+    use num_traits::Bounded;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    proptest! {
+
+        #[test]
+        fn eval_int(v in (i32::MIN + 1)..=i32::MAX) {
+            let expr: Expr = Negation::new(v.into()).unwrap().into();
+            prop_assert_eq!(eval_out_wo_ctx::<i32>(&expr), -v);
+        }
+
+        #[test]
+        fn eval_bigint(v in any::<i64>()) {
+            let expr: Expr = Negation::new(BigInt256::from(v).into()).unwrap().into();
+            prop_assert_eq!(
+                eval_out_wo_ctx::<BigInt256>(&expr),
+                -BigInt256::from(v)
+            );
+        }
+    }
+
+    #[test]
+    fn eval_overflow() {
+        let expr: Expr = Negation::new(i64::MIN.into()).unwrap().into();
+        assert!(try_eval_out_wo_ctx::<i64>(&expr).is_err());
+        let expr: Expr = Negation::new(BigInt256::min_value().into()).unwrap().into();
+        assert!(try_eval_out_wo_ctx::<BigInt256>(&expr).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use ergotree_ir::mir::option_get::OptionGet;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for OptionGet {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let v = self.input.eval(ctx)?;
+        match v {
+            Value::Opt(opt_v) => match *opt_v {
+                Some(v) => Ok(v),
+                None => Err(EvalError::OptionGetOnNone),
+            },
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "OptionGet: expected input to be Value::Opt, got: {0:?}",
+                v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::extract_reg_as::ExtractRegisterAs;
+    use ergotree_ir::mir::get_var::GetVar;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use ergotree_ir::types::stype::SType;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+    use crate::sigma_protocol::prover::ContextExtension;
+
+    #[test]
+    fn eval_get() {
+        let get_reg_expr: Expr = ExtractRegisterAs::new(
+            GlobalVars::SelfBox.into(),
+            0,
+            SType::SOption(SType::SLong.into()),
+        )
+        .unwrap()
+        .into();
+        let option_get_expr: Expr = OptionGet::new(get_reg_expr).unwrap().into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let v = eval_out::<i64>(&option_get_expr, ctx.clone());
+        assert_eq!(v, ctx.self_box.value.as_i64());
+    }
+
+    #[test]
+    fn eval_get_on_none() {
+        let get_var_expr: Expr = GetVar {
+            var_id: 1,
+            var_tpe: SType::SInt,
+        }
+        .into();
+        let option_get_expr: Expr = OptionGet::new(get_var_expr).unwrap().into();
+        let ctx = Rc::new(force_any_val::<Context>().with_extension(ContextExtension::empty()));
+        assert_eq!(
+            try_eval_out::<i32>(&option_get_expr, ctx),
+            Err(EvalError::OptionGetOnNone.wrap(option_get_expr.span()))
+        );
+    }
+}
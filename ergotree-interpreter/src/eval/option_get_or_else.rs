@@ -0,0 +1,92 @@
+use ergotree_ir::mir::option_get_or_else::OptionGetOrElse;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for OptionGetOrElse {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let v = self.input.eval(ctx)?;
+        // default is evaluated eagerly (as in the reference implementation)
+        let default_v = self.default.eval(ctx)?;
+        match v {
+            Value::Opt(opt_v) => match *opt_v {
+                Some(v) => Ok(v),
+                None => Ok(default_v),
+            },
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "OptionGetOrElse: expected input to be Value::Opt, got: {0:?}",
+                v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::get_var::GetVar;
+    use ergotree_ir::types::stype::SType;
+    use indexmap::IndexMap;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+    use crate::sigma_protocol::prover::ContextExtension;
+
+    fn get_var_or_else_expr(var_id: u8) -> Expr {
+        let get_var_expr: Expr = GetVar {
+            var_id,
+            var_tpe: SType::SInt,
+        }
+        .into();
+        OptionGetOrElse::new(get_var_expr, 1i32.into())
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn eval_some() {
+        let mut values = IndexMap::new();
+        values.insert(1, 42i32.into());
+        let ctx = Rc::new(force_any_val::<Context>().with_extension(ContextExtension { values }));
+        assert_eq!(eval_out::<i32>(&get_var_or_else_expr(1), ctx), 42);
+    }
+
+    #[test]
+    fn eval_none() {
+        let ctx = Rc::new(force_any_val::<Context>().with_extension(ContextExtension::empty()));
+        assert_eq!(eval_out::<i32>(&get_var_or_else_expr(1), ctx), 1);
+    }
+
+    #[test]
+    fn eval_some_with_failing_default() {
+        let mut values = IndexMap::new();
+        values.insert(1, 42i32.into());
+        let ctx = Rc::new(force_any_val::<Context>().with_extension(ContextExtension { values }));
+        let get_var_expr: Expr = GetVar {
+            var_id: 1,
+            var_tpe: SType::SInt,
+        }
+        .into();
+        let div_by_zero: Expr = BinOp {
+            kind: ArithOp::Divide.into(),
+            left: Box::new(1i32.into()),
+            right: Box::new(0i32.into()),
+        }
+        .into();
+        let expr: Expr = OptionGetOrElse::new(get_var_expr, div_by_zero)
+            .unwrap()
+            .into();
+        assert!(try_eval_out::<i32>(&expr, ctx).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use ergotree_ir::mir::option_is_defined::OptionIsDefined;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for OptionIsDefined {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let v = self.input.eval(ctx)?;
+        match v {
+            Value::Opt(opt_v) => Ok(opt_v.is_some().into()),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "OptionIsDefined: expected input to be Value::Opt, got: {0:?}",
+                v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::extract_reg_as::ExtractRegisterAs;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use ergotree_ir::types::stype::SType;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+
+    fn is_defined_reg_expr(register_id: i8) -> Expr {
+        let get_reg_expr: Expr = ExtractRegisterAs::new(
+            GlobalVars::SelfBox.into(),
+            register_id,
+            SType::SOption(SType::SLong.into()),
+        )
+        .unwrap()
+        .into();
+        OptionIsDefined::new(get_reg_expr).unwrap().into()
+    }
+
+    #[test]
+    fn eval_defined() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(eval_out::<bool>(&is_defined_reg_expr(0), ctx));
+    }
+}
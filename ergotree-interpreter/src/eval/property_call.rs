@@ -0,0 +1,14 @@
+use ergotree_ir::mir::property_call::PropertyCall;
+use ergotree_ir::mir::value::Value;
+
+use super::smethod_eval_fn;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for PropertyCall {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let ov = self.obj.eval(ctx)?;
+        smethod_eval_fn(&self.method)?(ctx, ov, Vec::new())
+    }
+}
@@ -0,0 +1,952 @@
+use bytes::Bytes;
+use ergo_avltree_rust::authenticated_tree_ops::AuthenticatedTreeOps;
+use ergo_avltree_rust::authenticated_tree_ops::AuthenticatedTreeOpsBase;
+use ergo_avltree_rust::batch_avl_verifier::BatchAVLVerifier;
+use ergo_avltree_rust::batch_node::AVLTree;
+use ergo_avltree_rust::batch_node::InternalNode;
+use ergo_avltree_rust::batch_node::LeafNode;
+use ergo_avltree_rust::batch_node::Node;
+use ergo_avltree_rust::batch_node::NodeHeader;
+use ergo_avltree_rust::operation::ADKey;
+use ergo_avltree_rust::operation::KeyValue;
+use ergo_avltree_rust::operation::Operation;
+use ergo_avltree_rust::operation::DIGEST_LENGTH;
+use ergo_chain_types::ADDigest;
+use ergotree_ir::mir::avl_tree_data::AvlTreeData;
+use ergotree_ir::mir::avl_tree_data::AvlTreeFlags;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::CollKind;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::types::stype::SType;
+
+use super::costs::Costs;
+use super::costs::PerItemCost;
+use super::method_arg;
+use super::EvalContext;
+use super::EvalError;
+use super::EvalFn;
+
+fn avl_tree_err(method_name: &str, e: impl std::fmt::Display) -> EvalError {
+    EvalError::AvlTree(format!("{0}: {1}", method_name, e))
+}
+
+/// Height of the tree (encoded in the last byte of the digest)
+fn tree_height(avl_tree_data: &AvlTreeData) -> usize {
+    avl_tree_data.digest.0[ADDigest::SIZE - 1] as usize
+}
+
+/// Charge the cost of `op_cost` per tree level for every one of `n_ops` operations
+fn add_tree_ops_cost(
+    ctx: &mut EvalContext,
+    op_cost: &PerItemCost,
+    avl_tree_data: &AvlTreeData,
+    n_ops: usize,
+) -> Result<(), EvalError> {
+    let height = tree_height(avl_tree_data);
+    for _ in 0..n_ops {
+        ctx.cost_accum.add(op_cost.cost(height))?;
+    }
+    Ok(())
+}
+
+/// Create a verifier for the given proof, reconstructing the part of the tree that is needed
+/// for the operations from the proof
+fn create_verifier(
+    ctx: &mut EvalContext,
+    method_name: &str,
+    avl_tree_data: &AvlTreeData,
+    proof: Vec<u8>,
+) -> Result<ProofVerifier, EvalError> {
+    ctx.cost_accum
+        .add(Costs::DEFAULT.avl_tree_create_verifier.cost(proof.len()))?;
+    ProofVerifier::new(avl_tree_data, proof).map_err(|e| avl_tree_err(method_name, e))
+}
+
+/// Perform the operation on the tree reconstructed from the proof
+fn perform_operation(
+    method_name: &str,
+    verifier: &mut ProofVerifier,
+    operation: Operation,
+) -> Result<Option<Bytes>, EvalError> {
+    verifier
+        .perform_one_operation(&operation)
+        .map_err(|e| avl_tree_err(method_name, e))
+}
+
+/// Batch AVL+ tree proof verifier.
+/// The tree is reconstructed from the proof by `BatchAVLVerifier` of `ergo_avltree_rust`
+/// (after the packed tree is checked by [`check_packed_tree`]), the operations are performed
+/// as in `BatchAVLVerifier` but the directions part of the proof is read with bounds checks,
+/// failing the operation if the proof is too short (`BatchAVLVerifier` panics).
+struct ProofVerifier {
+    proof: Vec<u8>,
+    base: AuthenticatedTreeOpsBase,
+    /// Current position (in bits) in the directions part of the proof
+    directions_index: usize,
+    /// Position of the last right step taken when going down the tree (needed for deletions)
+    last_right_step: usize,
+    /// Position when replaying the directions a second time (needed for deletions)
+    replay_index: usize,
+    /// Directions were read past the end of the proof
+    proof_exhausted: bool,
+}
+
+impl ProofVerifier {
+    fn new(avl_tree_data: &AvlTreeData, proof: Vec<u8>) -> Result<Self, String> {
+        let key_length = avl_tree_data.key_length as usize;
+        let value_length = avl_tree_data
+            .value_length_opt
+            .as_deref()
+            .map(|v| *v as usize);
+        let tree_end = check_packed_tree(&proof, key_length, value_length)?;
+        let tree = AVLTree::new(
+            |digest| Node::LabelOnly(NodeHeader::new(Some(*digest), None)),
+            key_length,
+            value_length,
+        );
+        let verifier = BatchAVLVerifier::new(
+            &Bytes::copy_from_slice(&avl_tree_data.digest.0),
+            &Bytes::copy_from_slice(&proof),
+            tree,
+            None,
+            None,
+        )
+        .map_err(|e| format!("invalid proof: {0}", e))?;
+        Ok(ProofVerifier {
+            proof,
+            base: AuthenticatedTreeOpsBase::new(verifier.get_state().tree.clone(), false),
+            // directions start right after the packed tree
+            directions_index: tree_end * 8,
+            last_right_step: 0,
+            replay_index: 0,
+            proof_exhausted: false,
+        })
+    }
+
+    /// Returns the old value of the key (if the key is in the tree).
+    /// After a failed operation all subsequent operations fail and the digest is `None`.
+    fn perform_one_operation(&mut self, operation: &Operation) -> Result<Option<Bytes>, String> {
+        let res = self.try_perform_one_operation(operation);
+        if res.is_err() {
+            self.base.tree.root = None;
+            self.base.tree.height = 0;
+        }
+        res
+    }
+
+    fn try_perform_one_operation(
+        &mut self,
+        operation: &Operation,
+    ) -> Result<Option<Bytes>, String> {
+        if let (Some(value_length), Operation::Insert(kv) | Operation::Update(kv)) =
+            (self.base.tree.value_length, operation)
+        {
+            if kv.value.len() != value_length {
+                return Err(format!(
+                    "value length {0} does not match the tree value length {1}",
+                    kv.value.len(),
+                    value_length
+                ));
+            }
+        }
+        let root = self
+            .base
+            .tree
+            .root
+            .clone()
+            .ok_or_else(|| "tree is not available after a failed operation".to_string())?;
+        self.replay_index = self.directions_index;
+        let res = self.return_result_of_one_operation(operation, &root);
+        if self.proof_exhausted {
+            return Err("proof is too short".to_string());
+        }
+        let res = res.map_err(|e| e.to_string())?;
+        if self.base.tree.height > u8::MAX as usize {
+            return Err("tree height overflow".to_string());
+        }
+        Ok(res)
+    }
+
+    fn direction_bit(&mut self, index: usize) -> bool {
+        match self.proof.get(index >> 3) {
+            Some(b) => b & (1 << (index & 7)) != 0,
+            None => {
+                self.proof_exhausted = true;
+                false
+            }
+        }
+    }
+}
+
+impl AuthenticatedTreeOps for ProofVerifier {
+    fn get_state(&self) -> &AuthenticatedTreeOpsBase {
+        &self.base
+    }
+
+    fn state(&mut self) -> &mut AuthenticatedTreeOpsBase {
+        &mut self.base
+    }
+
+    /// The key is either equal to the leaf's key or is between the leaf's key and its next leaf key
+    fn key_matches_leaf(&mut self, key: &ADKey, leaf: &LeafNode) -> anyhow::Result<bool> {
+        match &leaf.hdr.key {
+            Some(leaf_key) if key == leaf_key => Ok(true),
+            Some(leaf_key) if key > leaf_key && key < &leaf.next_node_key => Ok(false),
+            _ => Err(anyhow::anyhow!("key does not match the leaf")),
+        }
+    }
+
+    fn next_direction_is_left(&mut self, _key: &ADKey, _r: &InternalNode) -> bool {
+        let is_left = self.direction_bit(self.directions_index);
+        if !is_left {
+            self.last_right_step = self.directions_index;
+        }
+        self.directions_index += 1;
+        is_left
+    }
+
+    fn replay_comparison(&mut self) -> i32 {
+        let res = if self.replay_index == self.last_right_step {
+            0
+        } else if !self.direction_bit(self.replay_index) && self.replay_index < self.last_right_step
+        {
+            1
+        } else {
+            -1
+        };
+        self.replay_index += 1;
+        res
+    }
+}
+
+const LEAF_IN_PACKAGED_PROOF: u8 = 2;
+const LABEL_IN_PACKAGED_PROOF: u8 = 3;
+const END_OF_TREE_IN_PACKAGED_PROOF: u8 = 4;
+
+/// Check the post order traversal of the tree packed in the proof before it is read by
+/// `BatchAVLVerifier` (which reads it without bounds checks and panics on a malformed one).
+/// Returns the position of the directions part of the proof.
+fn check_packed_tree(
+    proof: &[u8],
+    key_length: usize,
+    value_length: Option<usize>,
+) -> Result<usize, String> {
+    let mut pos: usize = 0;
+    let mut stack_size: usize = 0;
+    let mut previous_is_leaf = false;
+    loop {
+        let node_type = *proof.get(pos).ok_or("unexpected end of proof")?;
+        pos += 1;
+        let node_length = match node_type {
+            END_OF_TREE_IN_PACKAGED_PROOF => break,
+            LABEL_IN_PACKAGED_PROOF => {
+                stack_size += 1;
+                previous_is_leaf = false;
+                DIGEST_LENGTH
+            }
+            LEAF_IN_PACKAGED_PROOF => {
+                // the key of a leaf following a leaf is the next leaf key of the previous one
+                let keys_length = if previous_is_leaf {
+                    key_length
+                } else {
+                    key_length.saturating_mul(2)
+                };
+                let value_length = match value_length {
+                    Some(value_length) => value_length,
+                    None => {
+                        let len_bytes = pos
+                            .checked_add(keys_length)
+                            .and_then(|start| proof.get(start..start.checked_add(4)?))
+                            .ok_or("unexpected end of proof")?;
+                        let mut buf = [0u8; 4];
+                        buf.copy_from_slice(len_bytes);
+                        (u32::from_be_bytes(buf) as usize).saturating_add(4)
+                    }
+                };
+                stack_size += 1;
+                previous_is_leaf = true;
+                keys_length.saturating_add(value_length)
+            }
+            0 | 1 | 0xff => {
+                stack_size = stack_size
+                    .checked_sub(1)
+                    .filter(|size| *size > 0)
+                    .ok_or("missing internal node child")?;
+                0
+            }
+            node_type => return Err(format!("unexpected node type {0}", node_type)),
+        };
+        pos = pos
+            .checked_add(node_length)
+            .filter(|end| *end <= proof.len())
+            .ok_or("unexpected end of proof")?;
+    }
+    if stack_size != 1 {
+        return Err(format!("expected a single root, got {0} nodes", stack_size));
+    }
+    Ok(pos)
+}
+
+/// Tree with the digest obtained after the operations performed by the verifier
+fn updated_tree(
+    method_name: &str,
+    avl_tree_data: AvlTreeData,
+    verifier: &ProofVerifier,
+) -> Result<Value, EvalError> {
+    match verifier.digest() {
+        Some(digest) => {
+            let digest =
+                ADDigest::try_from(digest.to_vec()).map_err(|e| avl_tree_err(method_name, e))?;
+            Ok(Some(Value::AvlTree(Box::new(AvlTreeData {
+                digest,
+                ..avl_tree_data
+            })))
+            .into())
+        }
+        None => Ok(Value::Opt(Box::new(None))),
+    }
+}
+
+pub(crate) static DIGEST_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    Ok(Vec::<u8>::from(avl_tree_data.digest).into())
+};
+
+pub(crate) static ENABLED_OPERATIONS_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    Ok(Value::Byte(avl_tree_data.tree_flags.serialize() as i8))
+};
+
+pub(crate) static KEY_LENGTH_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    Ok(Value::Int(avl_tree_data.key_length as i32))
+};
+
+pub(crate) static VALUE_LENGTH_OPT_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    Ok(avl_tree_data
+        .value_length_opt
+        .map(|v| Value::Int(*v as i32))
+        .into())
+};
+
+pub(crate) static IS_INSERT_ALLOWED_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    Ok(Value::Boolean(avl_tree_data.tree_flags.insert_allowed()))
+};
+
+pub(crate) static IS_UPDATE_ALLOWED_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    Ok(Value::Boolean(avl_tree_data.tree_flags.update_allowed()))
+};
+
+pub(crate) static IS_REMOVE_ALLOWED_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    Ok(Value::Boolean(avl_tree_data.tree_flags.remove_allowed()))
+};
+
+pub(crate) static UPDATE_OPERATIONS_EVAL_FN: EvalFn = |_ctx, obj, args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    let new_operations = method_arg(&args, 0, "updateOperations")?.try_extract_into::<i8>()?;
+    Ok(Value::AvlTree(Box::new(AvlTreeData {
+        tree_flags: AvlTreeFlags::parse(new_operations as u8),
+        ..avl_tree_data
+    })))
+};
+
+pub(crate) static CONTAINS_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    let key = method_arg(&args, 0, "contains")?.try_extract_into::<Vec<u8>>()?;
+    let proof = method_arg(&args, 1, "contains")?.try_extract_into::<Vec<u8>>()?;
+    let mut verifier = create_verifier(ctx, "contains", &avl_tree_data, proof)?;
+    add_tree_ops_cost(ctx, &Costs::DEFAULT.avl_tree_lookup, &avl_tree_data, 1)?;
+    let found = perform_operation(
+        "contains",
+        &mut verifier,
+        Operation::Lookup(Bytes::from(key)),
+    )?
+    .is_some();
+    Ok(Value::Boolean(found))
+};
+
+pub(crate) static GET_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    let key = method_arg(&args, 0, "get")?.try_extract_into::<Vec<u8>>()?;
+    let proof = method_arg(&args, 1, "get")?.try_extract_into::<Vec<u8>>()?;
+    let mut verifier = create_verifier(ctx, "get", &avl_tree_data, proof)?;
+    add_tree_ops_cost(ctx, &Costs::DEFAULT.avl_tree_lookup, &avl_tree_data, 1)?;
+    let value = perform_operation("get", &mut verifier, Operation::Lookup(Bytes::from(key)))?;
+    Ok(value.map(|v| v.to_vec()).into())
+};
+
+pub(crate) static GET_MANY_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    let keys = method_arg(&args, 0, "getMany")?.try_extract_into::<Vec<Vec<u8>>>()?;
+    let proof = method_arg(&args, 1, "getMany")?.try_extract_into::<Vec<u8>>()?;
+    let mut verifier = create_verifier(ctx, "getMany", &avl_tree_data, proof)?;
+    add_tree_ops_cost(
+        ctx,
+        &Costs::DEFAULT.avl_tree_lookup,
+        &avl_tree_data,
+        keys.len(),
+    )?;
+    let values = keys
+        .into_iter()
+        .map(|key| {
+            perform_operation(
+                "getMany",
+                &mut verifier,
+                Operation::Lookup(Bytes::from(key)),
+            )
+            .map(|value| Value::from(value.map(|v| v.to_vec())))
+        })
+        .collect::<Result<Vec<Value>, EvalError>>()?;
+    Ok(Value::Coll(CollKind::WrappedColl {
+        elem_tpe: SType::SOption(SType::SColl(SType::SByte.into()).into()),
+        items: values,
+    }))
+};
+
+pub(crate) static INSERT_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    let entries = method_arg(&args, 0, "insert")?.try_extract_into::<Vec<(Vec<u8>, Vec<u8>)>>()?;
+    let proof = method_arg(&args, 1, "insert")?.try_extract_into::<Vec<u8>>()?;
+    if !avl_tree_data.tree_flags.insert_allowed() {
+        return Ok(Value::Opt(Box::new(None)));
+    }
+    let mut verifier = create_verifier(ctx, "insert", &avl_tree_data, proof)?;
+    add_tree_ops_cost(
+        ctx,
+        &Costs::DEFAULT.avl_tree_insert,
+        &avl_tree_data,
+        entries.len(),
+    )?;
+    for (key, value) in entries {
+        perform_operation(
+            "insert",
+            &mut verifier,
+            Operation::Insert(KeyValue {
+                key: Bytes::from(key),
+                value: Bytes::from(value),
+            }),
+        )?;
+    }
+    updated_tree("insert", avl_tree_data, &verifier)
+};
+
+pub(crate) static UPDATE_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    let entries = method_arg(&args, 0, "update")?.try_extract_into::<Vec<(Vec<u8>, Vec<u8>)>>()?;
+    let proof = method_arg(&args, 1, "update")?.try_extract_into::<Vec<u8>>()?;
+    if !avl_tree_data.tree_flags.update_allowed() {
+        return Ok(Value::Opt(Box::new(None)));
+    }
+    let mut verifier = create_verifier(ctx, "update", &avl_tree_data, proof)?;
+    add_tree_ops_cost(
+        ctx,
+        &Costs::DEFAULT.avl_tree_update,
+        &avl_tree_data,
+        entries.len(),
+    )?;
+    for (key, value) in entries {
+        perform_operation(
+            "update",
+            &mut verifier,
+            Operation::Update(KeyValue {
+                key: Bytes::from(key),
+                value: Bytes::from(value),
+            }),
+        )?;
+    }
+    updated_tree("update", avl_tree_data, &verifier)
+};
+
+pub(crate) static REMOVE_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    let keys = method_arg(&args, 0, "remove")?.try_extract_into::<Vec<Vec<u8>>>()?;
+    let proof = method_arg(&args, 1, "remove")?.try_extract_into::<Vec<u8>>()?;
+    if !avl_tree_data.tree_flags.remove_allowed() {
+        return Ok(Value::Opt(Box::new(None)));
+    }
+    let mut verifier = create_verifier(ctx, "remove", &avl_tree_data, proof)?;
+    add_tree_ops_cost(
+        ctx,
+        &Costs::DEFAULT.avl_tree_remove,
+        &avl_tree_data,
+        keys.len(),
+    )?;
+    for key in keys {
+        perform_operation("remove", &mut verifier, Operation::Remove(Bytes::from(key)))?;
+    }
+    updated_tree("remove", avl_tree_data, &verifier)
+};
+
+pub(crate) static UPDATE_DIGEST_EVAL_FN: EvalFn = |_ctx, obj, args| {
+    let avl_tree_data = obj.try_extract_into::<AvlTreeData>()?;
+    let new_digest = method_arg(&args, 0, "updateDigest")?.try_extract_into::<Vec<u8>>()?;
+    let digest = ADDigest::try_from(new_digest).map_err(|e| avl_tree_err("updateDigest", e))?;
+    Ok(Value::AvlTree(Box::new(AvlTreeData {
+        digest,
+        ..avl_tree_data
+    })))
+};
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ergo_avltree_rust::batch_avl_prover::BatchAVLProver;
+    use ergotree_ir::mir::constant::Constant;
+    use ergotree_ir::mir::constant::Literal;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::method_call::MethodCall;
+    use ergotree_ir::types::savltree;
+    use ergotree_ir::types::smethod::SMethod;
+    use ergotree_ir::types::stuple::STuple;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+    use std::panic::catch_unwind;
+    use std::panic::AssertUnwindSafe;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    const KEY_LENGTH: usize = 32;
+
+    fn key(i: u8) -> Vec<u8> {
+        vec![i; KEY_LENGTH]
+    }
+
+    fn value(i: u8) -> Vec<u8> {
+        vec![i; 4]
+    }
+
+    fn prover_with_keys(n: u8) -> BatchAVLProver {
+        let tree = AVLTree::new(
+            |digest| Node::LabelOnly(NodeHeader::new(Some(*digest), None)),
+            KEY_LENGTH,
+            None,
+        );
+        let mut prover = BatchAVLProver::new(tree, true);
+        for i in 1..=n {
+            prover
+                .perform_one_operation(&Operation::Insert(KeyValue {
+                    key: Bytes::from(key(i)),
+                    value: Bytes::from(value(i)),
+                }))
+                .unwrap();
+        }
+        prover.generate_proof();
+        prover
+    }
+
+    fn tree_data(prover: &BatchAVLProver, tree_flags: AvlTreeFlags) -> AvlTreeData {
+        AvlTreeData {
+            digest: ADDigest::try_from(prover.digest().unwrap().to_vec()).unwrap(),
+            tree_flags,
+            key_length: KEY_LENGTH as u32,
+            value_length_opt: None,
+        }
+    }
+
+    fn key_values_const(entries: Vec<(Vec<u8>, Vec<u8>)>) -> Constant {
+        let elem_tpe = SType::STuple(STuple::pair(
+            SType::SColl(SType::SByte.into()),
+            SType::SColl(SType::SByte.into()),
+        ));
+        let items = entries
+            .into_iter()
+            .map(|(k, v)| Literal::Tup(vec![k.into(), v.into()].try_into().unwrap()))
+            .collect();
+        Constant {
+            tpe: SType::SColl(elem_tpe.clone().into()),
+            v: Literal::Coll(CollKind::WrappedColl { elem_tpe, items }),
+        }
+    }
+
+    fn method_call(tree: AvlTreeData, method: &SMethod, args: Vec<Constant>) -> Expr {
+        MethodCall::new(
+            tree.into(),
+            method.clone(),
+            args.into_iter().map(Expr::Const).collect(),
+        )
+        .unwrap()
+        .into()
+    }
+
+    #[test]
+    fn eval_contains_and_get() {
+        let mut prover = prover_with_keys(3);
+        let tree = tree_data(&prover, AvlTreeFlags::new(false, false, false));
+        prover
+            .perform_one_operation(&Operation::Lookup(Bytes::from(key(2))))
+            .unwrap();
+        let proof = prover.generate_proof().to_vec();
+
+        let contains = method_call(
+            tree.clone(),
+            &savltree::CONTAINS_METHOD,
+            vec![key(2).into(), proof.clone().into()],
+        );
+        assert!(eval_out_wo_ctx::<bool>(&contains));
+
+        let get = method_call(
+            tree.clone(),
+            &savltree::GET_METHOD,
+            vec![key(2).into(), proof.into()],
+        );
+        assert_eq!(eval_out_wo_ctx::<Option<Vec<u8>>>(&get), Some(value(2)));
+
+        let contains_bad_proof = method_call(
+            tree,
+            &savltree::CONTAINS_METHOD,
+            vec![key(2).into(), vec![1u8, 2, 3].into()],
+        );
+        assert!(try_eval_out_wo_ctx::<bool>(&contains_bad_proof).is_err());
+    }
+
+    #[test]
+    fn eval_malformed_proof() {
+        let mut prover = prover_with_keys(3);
+        let tree = tree_data(&prover, AvlTreeFlags::new(false, false, false));
+        prover
+            .perform_one_operation(&Operation::Lookup(Bytes::from(key(2))))
+            .unwrap();
+        let proof = prover.generate_proof().to_vec();
+        let get_no_directions = method_call(
+            tree.clone(),
+            &savltree::GET_METHOD,
+            vec![key(2).into(), proof[..proof.len() - 1].to_vec().into()],
+        );
+        assert!(try_eval_out_wo_ctx::<Option<Vec<u8>>>(&get_no_directions)
+            .unwrap_err()
+            .to_string()
+            .contains("proof is too short"));
+        let malformed_proofs = vec![
+            // no directions
+            proof[..proof.len() - 1].to_vec(),
+            // truncated tree
+            proof[..proof.len() / 2].to_vec(),
+            // internal node without children
+            vec![0u8, 4],
+            // unknown node type
+            vec![7u8, 4],
+            vec![],
+        ];
+        for malformed_proof in malformed_proofs {
+            let get = method_call(
+                tree.clone(),
+                &savltree::GET_METHOD,
+                vec![key(2).into(), malformed_proof.clone().into()],
+            );
+            assert!(try_eval_out_wo_ctx::<Option<Vec<u8>>>(&get).is_err());
+            let contains = method_call(
+                tree.clone(),
+                &savltree::CONTAINS_METHOD,
+                vec![key(2).into(), malformed_proof.into()],
+            );
+            assert!(try_eval_out_wo_ctx::<bool>(&contains).is_err());
+        }
+
+        // proof of another tree
+        let other_tree = tree_data(&prover_with_keys(4), AvlTreeFlags::new(false, false, false));
+        let get = method_call(
+            other_tree,
+            &savltree::GET_METHOD,
+            vec![key(2).into(), proof.into()],
+        );
+        assert!(try_eval_out_wo_ctx::<Option<Vec<u8>>>(&get).is_err());
+    }
+
+    #[test]
+    fn eval_get_many() {
+        let mut prover = prover_with_keys(3);
+        let tree = tree_data(&prover, AvlTreeFlags::new(false, false, false));
+        for k in [key(1), key(5)] {
+            prover
+                .perform_one_operation(&Operation::Lookup(Bytes::from(k)))
+                .unwrap();
+        }
+        let proof = prover.generate_proof().to_vec();
+        let get_many = method_call(
+            tree,
+            &savltree::GET_MANY_METHOD,
+            vec![vec![key(1), key(5)].into(), proof.into()],
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<Option<Vec<u8>>>>(&get_many),
+            vec![Some(value(1)), None]
+        );
+    }
+
+    #[test]
+    fn eval_insert() {
+        let mut prover = prover_with_keys(3);
+        let tree = tree_data(&prover, AvlTreeFlags::new(true, false, false));
+        prover
+            .perform_one_operation(&Operation::Insert(KeyValue {
+                key: Bytes::from(key(4)),
+                value: Bytes::from(value(4)),
+            }))
+            .unwrap();
+        let proof = prover.generate_proof().to_vec();
+        let expected = tree_data(&prover, AvlTreeFlags::new(true, false, false));
+
+        let insert = method_call(
+            tree.clone(),
+            &savltree::INSERT_METHOD,
+            vec![
+                key_values_const(vec![(key(4), value(4))]),
+                proof.clone().into(),
+            ],
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<AvlTreeData>>(&insert),
+            Some(expected)
+        );
+
+        let insert_not_allowed = method_call(
+            AvlTreeData {
+                tree_flags: AvlTreeFlags::new(false, false, false),
+                ..tree.clone()
+            },
+            &savltree::INSERT_METHOD,
+            vec![key_values_const(vec![(key(4), value(4))]), proof.into()],
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<AvlTreeData>>(&insert_not_allowed),
+            None
+        );
+
+        let insert_bad_proof = method_call(
+            tree,
+            &savltree::INSERT_METHOD,
+            vec![
+                key_values_const(vec![(key(4), value(4))]),
+                vec![1u8, 2, 3].into(),
+            ],
+        );
+        assert!(try_eval_out_wo_ctx::<Option<AvlTreeData>>(&insert_bad_proof).is_err());
+    }
+
+    #[test]
+    fn eval_update_and_remove() {
+        let mut prover = prover_with_keys(3);
+        let flags = AvlTreeFlags::new(false, true, true);
+        let tree = tree_data(&prover, flags);
+        prover
+            .perform_one_operation(&Operation::Update(KeyValue {
+                key: Bytes::from(key(1)),
+                value: Bytes::from(value(9)),
+            }))
+            .unwrap();
+        let update_proof = prover.generate_proof().to_vec();
+        let updated = tree_data(&prover, flags);
+        prover
+            .perform_one_operation(&Operation::Remove(Bytes::from(key(2))))
+            .unwrap();
+        let remove_proof = prover.generate_proof().to_vec();
+        let removed = tree_data(&prover, flags);
+
+        let update = method_call(
+            tree,
+            &savltree::UPDATE_METHOD,
+            vec![
+                key_values_const(vec![(key(1), value(9))]),
+                update_proof.into(),
+            ],
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<AvlTreeData>>(&update),
+            Some(updated.clone())
+        );
+
+        let remove = method_call(
+            updated,
+            &savltree::REMOVE_METHOD,
+            vec![vec![key(2)].into(), remove_proof.into()],
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<AvlTreeData>>(&remove),
+            Some(removed)
+        );
+    }
+
+    #[test]
+    fn eval_properties_and_updates() {
+        let tree = tree_data(&prover_with_keys(1), AvlTreeFlags::new(true, false, true));
+        let call = |method: &SMethod, args: Vec<Constant>| method_call(tree.clone(), method, args);
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<u8>>(&call(&savltree::DIGEST_METHOD, vec![])),
+            Vec::<u8>::from(tree.digest)
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<i8>(&call(&savltree::ENABLED_OPERATIONS_METHOD, vec![])),
+            0x05
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<i32>(&call(&savltree::KEY_LENGTH_METHOD, vec![])),
+            KEY_LENGTH as i32
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Option<i32>>(&call(&savltree::VALUE_LENGTH_OPT_METHOD, vec![])),
+            None
+        );
+        assert!(eval_out_wo_ctx::<bool>(&call(
+            &savltree::IS_INSERT_ALLOWED_METHOD,
+            vec![]
+        )));
+        assert!(!eval_out_wo_ctx::<bool>(&call(
+            &savltree::IS_UPDATE_ALLOWED_METHOD,
+            vec![]
+        )));
+        assert!(eval_out_wo_ctx::<bool>(&call(
+            &savltree::IS_REMOVE_ALLOWED_METHOD,
+            vec![]
+        )));
+        assert_eq!(
+            eval_out_wo_ctx::<AvlTreeData>(&call(
+                &savltree::UPDATE_OPERATIONS_METHOD,
+                vec![2i8.into()]
+            ))
+            .tree_flags,
+            AvlTreeFlags::new(false, true, false)
+        );
+        let new_digest = vec![7u8; ADDigest::SIZE];
+        assert_eq!(
+            eval_out_wo_ctx::<AvlTreeData>(&call(
+                &savltree::UPDATE_DIGEST_METHOD,
+                vec![new_digest.clone().into()]
+            ))
+            .digest,
+            ADDigest::try_from(new_digest).unwrap()
+        );
+        assert!(try_eval_out_wo_ctx::<AvlTreeData>(&call(
+            &savltree::UPDATE_DIGEST_METHOD,
+            vec![vec![7u8; 4].into()]
+        ))
+        .is_err());
+    }
+
+    /// Operations (lookup, insert, update or remove of the key `1..=2*n`) performed by
+    /// the prover on the tree with `n` keys, changed to succeed on the prover
+    fn prove_operations(n: u8, ops: Vec<(u8, u8)>) -> (AvlTreeData, Vec<Operation>, Vec<u8>) {
+        let mut prover = prover_with_keys(n);
+        let tree = tree_data(&prover, AvlTreeFlags::new(true, true, true));
+        let mut keys: BTreeSet<u8> = (1..=n).collect();
+        let operations: Vec<Operation> = ops
+            .into_iter()
+            .map(|(op, i)| {
+                let k = Bytes::from(key(i));
+                match op {
+                    0 => Operation::Lookup(k),
+                    _ if !keys.contains(&i) => {
+                        keys.insert(i);
+                        Operation::Insert(KeyValue {
+                            key: k,
+                            value: Bytes::from(value(i)),
+                        })
+                    }
+                    1 => Operation::Update(KeyValue {
+                        key: k,
+                        value: Bytes::from(value(i.wrapping_add(100))),
+                    }),
+                    _ => {
+                        keys.remove(&i);
+                        Operation::Remove(k)
+                    }
+                }
+            })
+            .collect();
+        for operation in &operations {
+            prover.perform_one_operation(operation).unwrap();
+        }
+        (tree, operations, prover.generate_proof().to_vec())
+    }
+
+    /// Results of the operations (`None` for a failed one) and the resulting digest
+    type VerificationResult = (Vec<Option<Option<Bytes>>>, Option<Bytes>);
+
+    fn verify(tree: &AvlTreeData, operations: &[Operation], proof: Vec<u8>) -> VerificationResult {
+        match ProofVerifier::new(tree, proof) {
+            Ok(mut verifier) => (
+                operations
+                    .iter()
+                    .map(|operation| verifier.perform_one_operation(operation).ok())
+                    .collect(),
+                verifier.digest(),
+            ),
+            Err(_) => (vec![None; operations.len()], None),
+        }
+    }
+
+    fn verify_with_library(
+        tree: &AvlTreeData,
+        operations: &[Operation],
+        proof: &[u8],
+    ) -> VerificationResult {
+        let avl_tree = AVLTree::new(
+            |digest| Node::LabelOnly(NodeHeader::new(Some(*digest), None)),
+            tree.key_length as usize,
+            None,
+        );
+        match BatchAVLVerifier::new(
+            &Bytes::copy_from_slice(&tree.digest.0),
+            &Bytes::copy_from_slice(proof),
+            avl_tree,
+            None,
+            None,
+        ) {
+            Ok(mut verifier) => (
+                operations
+                    .iter()
+                    .map(|operation| verifier.perform_one_operation(operation).ok())
+                    .collect(),
+                verifier.digest(),
+            ),
+            Err(_) => (vec![None; operations.len()], None),
+        }
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn verifier_matches_library_on_valid_proofs(
+            n in 1..16u8,
+            ops in vec((0..4u8, 1..32u8), 1..8),
+        ) {
+            let (tree, operations, proof) = prove_operations(n, ops);
+            let res = verify(&tree, &operations, proof.clone());
+            prop_assert!(res.0.iter().all(Option::is_some));
+            prop_assert!(res.1.is_some());
+            prop_assert_eq!(res, verify_with_library(&tree, &operations, &proof));
+        }
+
+        #[test]
+        fn verifier_matches_library_on_mutated_proofs(
+            n in 1..16u8,
+            ops in vec((0..4u8, 1..32u8), 1..8),
+            index in any::<prop::sample::Index>(),
+            byte in any::<u8>(),
+            truncate in any::<bool>(),
+        ) {
+            let (tree, operations, mut proof) = prove_operations(n, ops);
+            let i = index.index(proof.len());
+            if truncate {
+                proof.truncate(i);
+            } else {
+                proof[i] = byte;
+            }
+            let res = verify(&tree, &operations, proof.clone());
+            // the library panics on some malformed proofs
+            match catch_unwind(AssertUnwindSafe(|| verify_with_library(&tree, &operations, &proof))) {
+                Ok(library_res) => prop_assert_eq!(res, library_res),
+                Err(_) => prop_assert!(res.0.iter().any(Option::is_none)),
+            }
+        }
+    }
+}
@@ -0,0 +1,413 @@
+use std::convert::TryFrom;
+
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::CollKind;
+use ergotree_ir::mir::value::NativeColl;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::types::stuple::STuple;
+use ergotree_ir::types::stype::SType;
+
+use super::apply::eval_lambda;
+use super::costs::Costs;
+use super::method_arg;
+use super::EvalError;
+use super::EvalFn;
+
+fn extract_coll(method_name: &str, obj: Value) -> Result<CollKind<Value>, EvalError> {
+    match obj {
+        Value::Coll(coll) => Ok(coll),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "{0}: expected obj to be Value::Coll, got: {1:?}",
+            method_name, obj
+        ))),
+    }
+}
+
+fn index_out_of_bounds(method_name: &str, index: i32, len: usize) -> EvalError {
+    EvalError::Misc(format!(
+        "{0}: index {1} out of bounds for collection of length {2}",
+        method_name, index, len
+    ))
+}
+
+fn checked_index(method_name: &str, index: i32, len: usize) -> Result<usize, EvalError> {
+    usize::try_from(index)
+        .ok()
+        .filter(|i| *i < len)
+        .ok_or_else(|| index_out_of_bounds(method_name, index, len))
+}
+
+pub(crate) static GET_OR_ELSE_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let coll = extract_coll("getOrElse", obj)?;
+    let index = method_arg(&args, 0, "getOrElse")?.try_extract_into::<i32>()?;
+    let default = method_arg(&args, 1, "getOrElse")?;
+    ctx.cost_accum
+        .add(Costs::DEFAULT.coll_get_or_else.clone())?;
+    let item = match coll {
+        CollKind::NativeColl(NativeColl::CollByte(bytes)) => usize::try_from(index)
+            .ok()
+            .and_then(|i| bytes.get(i))
+            .map(|b| Value::Byte(*b)),
+        CollKind::WrappedColl { items, .. } => usize::try_from(index)
+            .ok()
+            .and_then(|i| items.get(i))
+            .cloned(),
+    };
+    Ok(item.unwrap_or(default))
+};
+
+pub(crate) static INDICES_EVAL_FN: EvalFn = |ctx, obj, _args| {
+    let coll = extract_coll("indices", obj)?;
+    ctx.cost_accum
+        .add(Costs::DEFAULT.coll_indices.cost(coll.len()))?;
+    let indices = (0..coll.len())
+        .map(|i| {
+            i32::try_from(i).map_err(|_| {
+                EvalError::UnexpectedValue(format!("indices: index {0} does not fit into Int", i))
+            })
+        })
+        .collect::<Result<Vec<i32>, EvalError>>()?;
+    Ok(indices.into())
+};
+
+pub(crate) static FLATMAP_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let coll = extract_coll("flatMap", obj)?;
+    let lambda = match method_arg(&args, 0, "flatMap")? {
+        Value::Lambda(lambda) => lambda,
+        v => {
+            return Err(EvalError::UnexpectedValue(format!(
+                "flatMap: expected mapper to be Value::Lambda, got: {0:?}",
+                v
+            )))
+        }
+    };
+    let out_elem_tpe = match lambda.body.tpe() {
+        SType::SColl(elem_tpe) => *elem_tpe,
+        tpe => {
+            return Err(EvalError::UnexpectedValue(format!(
+                "flatMap: expected mapper to return SColl, got: {0:?}",
+                tpe
+            )))
+        }
+    };
+    ctx.cost_accum
+        .add(Costs::DEFAULT.coll_flat_map.cost(coll.len()))?;
+    let mapped = coll
+        .as_vec()
+        .into_iter()
+        .map(|item| eval_lambda(&lambda, vec![item], ctx))
+        .collect::<Result<Vec<Value>, EvalError>>()?;
+    Ok(Value::Coll(CollKind::from_vec_vec(out_elem_tpe, mapped)?))
+};
+
+fn patch<T: Clone>(items: &[T], from: i32, patch: &[T], replaced: i32) -> Vec<T> {
+    let from = usize::try_from(from).unwrap_or(0).min(items.len());
+    let replaced = usize::try_from(replaced).unwrap_or(0);
+    let rest = from.saturating_add(replaced).min(items.len());
+    items[..from]
+        .iter()
+        .chain(patch)
+        .chain(&items[rest..])
+        .cloned()
+        .collect()
+}
+
+pub(crate) static PATCH_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let coll = extract_coll("patch", obj)?;
+    let from = method_arg(&args, 0, "patch")?.try_extract_into::<i32>()?;
+    let patch_coll = extract_coll("patch", method_arg(&args, 1, "patch")?)?;
+    let replaced = method_arg(&args, 2, "patch")?.try_extract_into::<i32>()?;
+    ctx.cost_accum.add(
+        Costs::DEFAULT
+            .coll_patch
+            .cost(coll.len() + patch_coll.len()),
+    )?;
+    let res = match (coll, patch_coll) {
+        (
+            CollKind::NativeColl(NativeColl::CollByte(bytes)),
+            CollKind::NativeColl(NativeColl::CollByte(patch_bytes)),
+        ) => CollKind::NativeColl(NativeColl::CollByte(patch(
+            &bytes,
+            from,
+            &patch_bytes,
+            replaced,
+        ))),
+        (coll, patch_coll) => CollKind::from_vec(
+            coll.elem_tpe().clone(),
+            patch(&coll.as_vec(), from, &patch_coll.as_vec(), replaced),
+        )?,
+    };
+    Ok(Value::Coll(res))
+};
+
+pub(crate) static UPDATED_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let coll = extract_coll("updated", obj)?;
+    let index = method_arg(&args, 0, "updated")?.try_extract_into::<i32>()?;
+    let value = method_arg(&args, 1, "updated")?;
+    ctx.cost_accum
+        .add(Costs::DEFAULT.coll_updated.cost(coll.len()))?;
+    let i = checked_index("updated", index, coll.len())?;
+    let res = match coll {
+        CollKind::NativeColl(NativeColl::CollByte(mut bytes)) => {
+            bytes[i] = value.try_extract_into::<i8>()?;
+            CollKind::NativeColl(NativeColl::CollByte(bytes))
+        }
+        CollKind::WrappedColl {
+            elem_tpe,
+            mut items,
+        } => {
+            items[i] = value;
+            CollKind::WrappedColl { elem_tpe, items }
+        }
+    };
+    Ok(Value::Coll(res))
+};
+
+pub(crate) static UPDATE_MANY_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let coll = extract_coll("updateMany", obj)?;
+    let indexes = method_arg(&args, 0, "updateMany")?.try_extract_into::<Vec<i32>>()?;
+    let values = extract_coll("updateMany", method_arg(&args, 1, "updateMany")?)?;
+    if indexes.len() != values.len() {
+        return Err(EvalError::Misc(format!(
+            "updateMany: got {0} indexes and {1} values",
+            indexes.len(),
+            values.len()
+        )));
+    }
+    ctx.cost_accum
+        .add(Costs::DEFAULT.coll_update_many.cost(coll.len()))?;
+    let len = coll.len();
+    let positions = indexes
+        .into_iter()
+        .map(|index| checked_index("updateMany", index, len))
+        .collect::<Result<Vec<usize>, EvalError>>()?;
+    let res = match (coll, values) {
+        (
+            CollKind::NativeColl(NativeColl::CollByte(mut bytes)),
+            CollKind::NativeColl(NativeColl::CollByte(new_bytes)),
+        ) => {
+            positions
+                .into_iter()
+                .zip(new_bytes)
+                .for_each(|(i, b)| bytes[i] = b);
+            CollKind::NativeColl(NativeColl::CollByte(bytes))
+        }
+        (coll, values) => {
+            let mut items = coll.as_vec();
+            positions
+                .into_iter()
+                .zip(values.as_vec())
+                .for_each(|(i, v)| items[i] = v);
+            CollKind::from_vec(coll.elem_tpe().clone(), items)?
+        }
+    };
+    Ok(Value::Coll(res))
+};
+
+pub(crate) static INDEX_OF_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let coll = extract_coll("indexOf", obj)?;
+    let elem = method_arg(&args, 0, "indexOf")?;
+    let from = method_arg(&args, 1, "indexOf")?.try_extract_into::<i32>()?;
+    ctx.cost_accum
+        .add(Costs::DEFAULT.coll_index_of.cost(coll.len()))?;
+    let from = usize::try_from(from).unwrap_or(0);
+    let position = match coll {
+        CollKind::NativeColl(NativeColl::CollByte(bytes)) => {
+            let elem = elem.try_extract_into::<i8>()?;
+            bytes.iter().skip(from).position(|b| *b == elem)
+        }
+        CollKind::WrappedColl { items, .. } => items.iter().skip(from).position(|i| *i == elem),
+    };
+    Ok(Value::Int(
+        position
+            .and_then(|p| i32::try_from(p + from).ok())
+            .unwrap_or(-1),
+    ))
+};
+
+pub(crate) static ZIP_EVAL_FN: EvalFn = |ctx, obj, args| {
+    let coll = extract_coll("zip", obj)?;
+    let other = extract_coll("zip", method_arg(&args, 0, "zip")?)?;
+    ctx.cost_accum
+        .add(Costs::DEFAULT.coll_zip.cost(coll.len().min(other.len())))?;
+    let elem_tpe = SType::STuple(STuple::pair(
+        coll.elem_tpe().clone(),
+        other.elem_tpe().clone(),
+    ));
+    let items = coll
+        .as_vec()
+        .into_iter()
+        .zip(other.as_vec())
+        .map(|(l, r)| Value::Tup([l, r].into()))
+        .collect();
+    Ok(Value::Coll(CollKind::WrappedColl { elem_tpe, items }))
+};
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::func_value::FuncArg;
+    use ergotree_ir::mir::func_value::FuncValue;
+    use ergotree_ir::mir::method_call::MethodCall;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::mir::val_def::ValId;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::types::scoll;
+    use ergotree_ir::types::smethod::SMethod;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    fn method_call(obj: Expr, method: &SMethod, args: Vec<Expr>) -> Expr {
+        MethodCall::new(obj, method.clone(), args).unwrap().into()
+    }
+
+    fn is_native_bytes(v: &Value) -> bool {
+        matches!(
+            v,
+            Value::Coll(CollKind::NativeColl(NativeColl::CollByte(_)))
+        )
+    }
+
+    #[test]
+    fn eval_get_or_else() {
+        let coll: Expr = vec![1i32, 2, 3].into();
+        let expr = method_call(
+            coll.clone(),
+            &scoll::GET_OR_ELSE_METHOD,
+            vec![1i32.into(), 0i32.into()],
+        );
+        assert_eq!(eval_out_wo_ctx::<i32>(&expr), 2);
+        let expr = method_call(
+            coll,
+            &scoll::GET_OR_ELSE_METHOD,
+            vec![(-1i32).into(), 0i32.into()],
+        );
+        assert_eq!(eval_out_wo_ctx::<i32>(&expr), 0);
+    }
+
+    #[test]
+    fn eval_indices() {
+        let coll: Expr = vec![1i8, 2, 3].into();
+        let expr: Expr = PropertyCall::new(coll, scoll::INDICES_METHOD.clone())
+            .unwrap()
+            .into();
+        assert_eq!(expr.tpe(), SType::SColl(SType::SInt.into()));
+        assert_eq!(eval_out_wo_ctx::<Vec<i32>>(&expr), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn eval_flatmap() {
+        let coll: Expr = vec![vec![1i8, 2], vec![], vec![3i8]].into();
+        let bytes_tpe = SType::SColl(SType::SByte.into());
+        let id_func: Expr = FuncValue::new(
+            vec![FuncArg {
+                idx: ValId(1),
+                tpe: bytes_tpe.clone(),
+            }],
+            ValUse {
+                val_id: ValId(1),
+                tpe: bytes_tpe.clone(),
+            }
+            .into(),
+        )
+        .into();
+        let expr = method_call(coll, &scoll::FLATMAP_METHOD, vec![id_func]);
+        assert_eq!(expr.tpe(), bytes_tpe);
+        let res = eval_out_wo_ctx::<Value>(&expr);
+        assert!(is_native_bytes(&res));
+        assert_eq!(res.try_extract_into::<Vec<i8>>().unwrap(), vec![1i8, 2, 3]);
+    }
+
+    #[test]
+    fn eval_patch() {
+        let coll: Expr = vec![1i8, 2, 3, 4].into();
+        let patch: Expr = vec![9i8, 9].into();
+        let expr = method_call(
+            coll,
+            &scoll::PATCH_METHOD,
+            vec![1i32.into(), patch, 2i32.into()],
+        );
+        let res = eval_out_wo_ctx::<Value>(&expr);
+        assert!(is_native_bytes(&res));
+        assert_eq!(
+            res.try_extract_into::<Vec<i8>>().unwrap(),
+            vec![1i8, 9, 9, 4]
+        );
+        let coll: Expr = vec![1i64, 2].into();
+        let patch: Expr = vec![3i64].into();
+        let expr = method_call(
+            coll,
+            &scoll::PATCH_METHOD,
+            vec![5i32.into(), patch, 1i32.into()],
+        );
+        assert_eq!(eval_out_wo_ctx::<Vec<i64>>(&expr), vec![1i64, 2, 3]);
+    }
+
+    #[test]
+    fn eval_updated() {
+        let coll: Expr = vec![1i8, 2, 3].into();
+        let expr = method_call(
+            coll.clone(),
+            &scoll::UPDATED_METHOD,
+            vec![2i32.into(), 5i8.into()],
+        );
+        let res = eval_out_wo_ctx::<Value>(&expr);
+        assert!(is_native_bytes(&res));
+        assert_eq!(res.try_extract_into::<Vec<i8>>().unwrap(), vec![1i8, 2, 5]);
+        let expr = method_call(coll, &scoll::UPDATED_METHOD, vec![3i32.into(), 5i8.into()]);
+        assert!(try_eval_out_wo_ctx::<Vec<i8>>(&expr).is_err());
+    }
+
+    #[test]
+    fn eval_update_many() {
+        let coll: Expr = vec![1i32, 2, 3].into();
+        let indexes: Expr = vec![0i32, 2].into();
+        let values: Expr = vec![7i32, 8].into();
+        let expr = method_call(
+            coll.clone(),
+            &scoll::UPDATE_MANY_METHOD,
+            vec![indexes, values.clone()],
+        );
+        assert_eq!(eval_out_wo_ctx::<Vec<i32>>(&expr), vec![7, 2, 8]);
+        let indexes: Expr = vec![0i32].into();
+        let expr = method_call(coll, &scoll::UPDATE_MANY_METHOD, vec![indexes, values]);
+        assert!(try_eval_out_wo_ctx::<Vec<i32>>(&expr).is_err());
+    }
+
+    #[test]
+    fn eval_index_of() {
+        let coll: Expr = vec![1i8, 2, 1].into();
+        let index_of = |elem: i8, from: i32| {
+            let expr = method_call(
+                coll.clone(),
+                &scoll::INDEX_OF_METHOD,
+                vec![elem.into(), from.into()],
+            );
+            eval_out_wo_ctx::<i32>(&expr)
+        };
+        assert_eq!(index_of(1, 0), 0);
+        assert_eq!(index_of(1, 1), 2);
+        assert_eq!(index_of(1, -5), 0);
+        assert_eq!(index_of(3, 0), -1);
+        assert_eq!(index_of(1, 10), -1);
+    }
+
+    #[test]
+    fn eval_zip() {
+        let left: Expr = vec![1i32, 2, 3].into();
+        let right: Expr = vec![4i64, 5].into();
+        let expr = method_call(left, &scoll::ZIP_METHOD, vec![right]);
+        assert_eq!(
+            expr.tpe(),
+            SType::SColl(SType::STuple(STuple::pair(SType::SInt, SType::SLong)).into())
+        );
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<(i32, i64)>>(&expr),
+            vec![(1, 4), (2, 5)]
+        );
+    }
+}
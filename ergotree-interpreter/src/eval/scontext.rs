@@ -0,0 +1,131 @@
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::serialization::SigmaSerializable;
+
+use super::EvalFn;
+
+pub(crate) static DATA_INPUTS_EVAL_FN: EvalFn = |ctx, _obj, _args| {
+    Ok(ctx
+        .ctx
+        .data_inputs
+        .as_ref()
+        .map(|data_inputs| data_inputs.as_vec().clone())
+        .unwrap_or_default()
+        .into())
+};
+
+pub(crate) static HEADERS_EVAL_FN: EvalFn = |ctx, _obj, _args| Ok(ctx.ctx.headers.clone().into());
+
+pub(crate) static PRE_HEADER_EVAL_FN: EvalFn =
+    |ctx, _obj, _args| Ok(ctx.ctx.pre_header.clone().into());
+
+pub(crate) static INPUTS_EVAL_FN: EvalFn =
+    |ctx, _obj, _args| Ok(ctx.ctx.inputs.as_vec().clone().into());
+
+pub(crate) static OUTPUTS_EVAL_FN: EvalFn = |ctx, _obj, _args| Ok(ctx.ctx.outputs.clone().into());
+
+pub(crate) static HEIGHT_EVAL_FN: EvalFn = |ctx, _obj, _args| Ok((ctx.ctx.height as i32).into());
+
+pub(crate) static SELF_EVAL_FN: EvalFn = |ctx, _obj, _args| Ok(ctx.ctx.self_box.clone().into());
+
+pub(crate) static SELF_BOX_INDEX_EVAL_FN: EvalFn = |ctx, _obj, _args| {
+    let self_box_id = ctx.ctx.self_box.box_id();
+    let index = ctx
+        .ctx
+        .inputs
+        .iter()
+        .position(|b| b.box_id() == self_box_id)
+        .map(|i| i as i32)
+        .unwrap_or(-1);
+    Ok(Value::Int(index))
+};
+
+pub(crate) static MINER_PUBKEY_EVAL_FN: EvalFn =
+    |ctx, _obj, _args| Ok(ctx.ctx.miner_pubkey.sigma_serialize_bytes()?.into());
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use ergo_chain_types::Header;
+    use ergo_chain_types::PreHeader;
+    use ergotree_ir::chain::ergo_box::ErgoBox;
+    use ergotree_ir::mir::context::Context;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::types::scontext;
+    use sigma_test_util::force_any_val;
+
+    use crate::eval::context::Context as EvalContext;
+    use crate::eval::tests::eval_out;
+
+    fn property(method: &ergotree_ir::types::smethod::SMethod) -> Expr {
+        PropertyCall::new(Context.into(), method.clone())
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn eval_data_inputs() {
+        let ctx = Rc::new(force_any_val::<EvalContext>());
+        let expected = ctx
+            .data_inputs
+            .clone()
+            .map(|d| d.as_vec().clone())
+            .unwrap_or_default();
+        assert_eq!(
+            eval_out::<Vec<Arc<ErgoBox>>>(&property(&scontext::DATA_INPUTS_PROPERTY), ctx),
+            expected
+        );
+    }
+
+    #[test]
+    fn eval_height() {
+        let ctx = Rc::new(force_any_val::<EvalContext>());
+        assert_eq!(
+            eval_out::<i32>(&property(&scontext::HEIGHT_PROPERTY), ctx.clone()),
+            ctx.height as i32
+        );
+    }
+
+    #[test]
+    fn eval_self_box_index() {
+        let ctx = force_any_val::<EvalContext>();
+        let self_box = ctx.inputs.as_vec()[0].clone();
+        let ctx = Rc::new(EvalContext { self_box, ..ctx });
+        assert_eq!(
+            eval_out::<i32>(&property(&scontext::SELF_BOX_INDEX_PROPERTY), ctx),
+            0
+        );
+        let ctx = force_any_val::<EvalContext>();
+        let not_an_input = force_any_val::<ErgoBox>();
+        let ctx = Rc::new(EvalContext {
+            self_box: Arc::new(not_an_input),
+            ..ctx
+        });
+        assert_eq!(
+            eval_out::<i32>(&property(&scontext::SELF_BOX_INDEX_PROPERTY), ctx),
+            -1
+        );
+    }
+
+    #[test]
+    fn eval_headers() {
+        let ctx = Rc::new(force_any_val::<EvalContext>());
+        assert_eq!(
+            eval_out::<Vec<Header>>(&property(&scontext::HEADERS_PROPERTY), ctx.clone()),
+            ctx.headers
+        );
+    }
+
+    #[test]
+    fn eval_pre_header() {
+        let ctx = Rc::new(force_any_val::<EvalContext>());
+        assert_eq!(
+            eval_out::<PreHeader>(&property(&scontext::PRE_HEADER_PROPERTY), ctx.clone()),
+            ctx.pre_header
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use ergotree_ir::mir::select_field::SelectField;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for SelectField {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let v = self.input.eval(ctx)?;
+        match v {
+            Value::Tup(items) => items
+                .get(self.field_index.zero_based())
+                .cloned()
+                .ok_or_else(|| {
+                    EvalError::NotFound(format!(
+                        "SelectField: field index {0:?} is out of bounds for tuple {1:?}",
+                        self.field_index, items
+                    ))
+                }),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "SelectField: expected input to be Value::Tup, got: {0:?}",
+                v
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::convert::TryFrom;
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::extract_creation_info::ExtractCreationInfo;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use ergotree_ir::mir::select_field::TupleFieldIndex;
+    use ergotree_ir::mir::tuple::Tuple;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_select_field() {
+        let tuple: Expr = Tuple::new(vec![1i32.into(), 2i64.into()]).unwrap().into();
+        let expr: Expr = SelectField::new(tuple, TupleFieldIndex::try_from(2u8).unwrap())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<i64>(&expr), 2);
+    }
+
+    #[test]
+    fn eval_creation_height() {
+        let creation_info: Expr = ExtractCreationInfo {
+            input: Box::new(GlobalVars::SelfBox.into()),
+        }
+        .into();
+        let expr: Expr = SelectField::new(creation_info, TupleFieldIndex::try_from(1u8).unwrap())
+            .unwrap()
+            .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<i32>(&expr, ctx.clone()),
+            ctx.self_box.creation_height as i32
+        );
+    }
+}
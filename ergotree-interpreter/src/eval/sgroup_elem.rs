@@ -0,0 +1,61 @@
+use ergo_chain_types::ec_point;
+use ergo_chain_types::EcPoint;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::serialization::SigmaSerializable;
+
+use super::EvalFn;
+
+pub(crate) static GET_ENCODED_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let point = obj.try_extract_into::<EcPoint>()?;
+    Ok(point.sigma_serialize_bytes()?.into())
+};
+
+pub(crate) static NEGATE_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let point = obj.try_extract_into::<EcPoint>()?;
+    Ok(Value::GroupElement(ec_point::inverse(&point).into()))
+};
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ergo_chain_types::ec_point::generator;
+    use ergo_chain_types::ec_point::identity;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::method_call::MethodCall;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::types::sgroup_elem;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    proptest! {
+
+        #[test]
+        fn eval_get_encoded(point in any::<EcPoint>()) {
+            let expr: Expr = PropertyCall::new(
+                point.clone().into(),
+                sgroup_elem::GET_ENCODED_METHOD.clone(),
+            )
+            .unwrap()
+            .into();
+            prop_assert_eq!(
+                eval_out_wo_ctx::<Vec<u8>>(&expr),
+                point.sigma_serialize_bytes().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn eval_negate() {
+        let g = generator();
+        let expr: Expr =
+            MethodCall::new(g.clone().into(), sgroup_elem::NEGATE_METHOD.clone(), vec![])
+                .unwrap()
+                .into();
+        let neg = eval_out_wo_ctx::<EcPoint>(&expr);
+        assert_eq!(neg * &g, identity());
+    }
+}
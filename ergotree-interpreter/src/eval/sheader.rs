@@ -0,0 +1,201 @@
+use std::convert::TryFrom;
+
+use ergo_chain_types::Header;
+use ergotree_ir::bigint256::BigInt256;
+use ergotree_ir::mir::avl_tree_data::AvlTreeData;
+use ergotree_ir::mir::avl_tree_data::AvlTreeFlags;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+
+use super::EvalError;
+use super::EvalFn;
+
+pub(crate) static ID_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Vec::<u8>::from(header.id.0).into())
+};
+
+pub(crate) static VERSION_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Value::Byte(header.version as i8))
+};
+
+pub(crate) static PARENT_ID_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Vec::<u8>::from(header.parent_id.0).into())
+};
+
+pub(crate) static AD_PROOFS_ROOT_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Vec::<u8>::from(header.ad_proofs_root).into())
+};
+
+pub(crate) static STATE_ROOT_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    // UTXO set tree: all operations allowed, 32-byte keys, values of arbitrary length
+    Ok(AvlTreeData {
+        digest: header.state_root,
+        tree_flags: AvlTreeFlags::new(true, true, true),
+        key_length: 32,
+        value_length_opt: None,
+    }
+    .into())
+};
+
+pub(crate) static TRANSACTIONS_ROOT_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Vec::<u8>::from(header.transaction_root).into())
+};
+
+pub(crate) static TIMESTAMP_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Value::Long(header.timestamp as i64))
+};
+
+pub(crate) static N_BITS_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Value::Long(header.n_bits as i64))
+};
+
+pub(crate) static HEIGHT_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Value::Int(header.height as i32))
+};
+
+pub(crate) static EXTENSION_ROOT_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Vec::<u8>::from(header.extension_root).into())
+};
+
+pub(crate) static MINER_PK_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Value::GroupElement(header.autolykos_solution.miner_pk))
+};
+
+pub(crate) static POW_ONETIME_PK_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(header.pow_onetime_pk().into())
+};
+
+pub(crate) static POW_NONCE_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(header.autolykos_solution.nonce.into())
+};
+
+pub(crate) static POW_DISTANCE_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    let distance = BigInt256::try_from(header.pow_distance()).map_err(EvalError::Misc)?;
+    Ok(Value::BigInt(distance))
+};
+
+pub(crate) static VOTES_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let header = obj.try_extract_into::<Header>()?;
+    Ok(Vec::<u8>::from(header.votes).into())
+};
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::rc::Rc;
+
+    use ergo_chain_types::ec_point::generator;
+    use ergo_chain_types::EcPoint;
+    use ergotree_ir::mir::coll_by_index::ByIndex;
+    use ergotree_ir::mir::context::Context;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::types::scontext;
+    use ergotree_ir::types::sheader;
+    use ergotree_ir::types::smethod::SMethod;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context as EvalContext;
+    use crate::eval::tests::eval_out;
+
+    fn ctx_with_header() -> (Rc<EvalContext>, Header) {
+        let mut ctx = force_any_val::<EvalContext>();
+        let header = force_any_val::<Header>();
+        ctx.headers = vec![header.clone()];
+        (Rc::new(ctx), header)
+    }
+
+    fn header_property(method: &SMethod) -> Expr {
+        let headers: Expr = PropertyCall::new(Context.into(), scontext::HEADERS_PROPERTY.clone())
+            .unwrap()
+            .into();
+        let header: Expr = ByIndex::new(headers, Expr::Const(0i32.into()), None)
+            .unwrap()
+            .into();
+        PropertyCall::new(header, method.clone()).unwrap().into()
+    }
+
+    #[test]
+    fn eval_header_properties() {
+        let (ctx, header) = ctx_with_header();
+        assert_eq!(
+            eval_out::<Vec<i8>>(&header_property(&sheader::ID_PROPERTY), ctx.clone()),
+            Vec::<i8>::from(header.id.0)
+        );
+        assert_eq!(
+            eval_out::<i8>(&header_property(&sheader::VERSION_PROPERTY), ctx.clone()),
+            header.version as i8
+        );
+        assert_eq!(
+            eval_out::<Vec<i8>>(&header_property(&sheader::PARENT_ID_PROPERTY), ctx.clone()),
+            Vec::<i8>::from(header.parent_id.0)
+        );
+        assert_eq!(
+            eval_out::<AvlTreeData>(&header_property(&sheader::STATE_ROOT_PROPERTY), ctx.clone()),
+            AvlTreeData {
+                digest: header.state_root,
+                tree_flags: AvlTreeFlags::new(true, true, true),
+                key_length: 32,
+                value_length_opt: None,
+            }
+        );
+        assert_eq!(
+            eval_out::<i64>(&header_property(&sheader::TIMESTAMP_PROPERTY), ctx.clone()),
+            header.timestamp as i64
+        );
+        assert_eq!(
+            eval_out::<i64>(&header_property(&sheader::N_BITS_PROPERTY), ctx.clone()),
+            header.n_bits as i64
+        );
+        assert_eq!(
+            eval_out::<i32>(&header_property(&sheader::HEIGHT_PROPERTY), ctx.clone()),
+            header.height as i32
+        );
+        assert_eq!(
+            eval_out::<EcPoint>(&header_property(&sheader::MINER_PK_PROPERTY), ctx.clone()),
+            *header.autolykos_solution.miner_pk
+        );
+        assert_eq!(
+            eval_out::<Vec<i8>>(&header_property(&sheader::VOTES_PROPERTY), ctx),
+            header.votes.0.iter().map(|b| *b as i8).collect::<Vec<i8>>()
+        );
+    }
+
+    #[test]
+    fn eval_pow_defaults_for_v2_header() {
+        let (ctx, mut header) = ctx_with_header();
+        header.autolykos_solution.pow_onetime_pk = None;
+        header.autolykos_solution.pow_distance = None;
+        let ctx = Rc::new(EvalContext {
+            headers: vec![header],
+            ..Rc::try_unwrap(ctx).unwrap()
+        });
+        assert_eq!(
+            eval_out::<EcPoint>(
+                &header_property(&sheader::POW_ONETIME_PK_PROPERTY),
+                ctx.clone()
+            ),
+            generator()
+        );
+        assert_eq!(
+            eval_out::<BigInt256>(&header_property(&sheader::POW_DISTANCE_PROPERTY), ctx),
+            BigInt256::from(0i64)
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::sigma_and::SigmaAnd;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::sigma_boolean::cand::Cand;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for SigmaAnd {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        ctx.cost_accum
+            .add(Costs::DEFAULT.sigma_and.cost(self.items.len()))?;
+        let items_v_res = self.items.try_mapped_ref(|it| it.eval(ctx));
+        let items_sigmabool = items_v_res?
+            .try_mapped(|it| it.try_extract_into::<SigmaProp>())?
+            .mapped(|it| it.value().clone());
+        Ok(SigmaProp::new(Cand::normalized(items_sigmabool)).into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::convert::TryInto;
+
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjecture;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn eval(sigmaprops in vec(any::<SigmaProp>(), 2..10)) {
+            let items = sigmaprops.clone().into_iter().map(|sp| sp.into()).collect::<Vec<Expr>>();
+            let expr: Expr = SigmaAnd::new(items).unwrap().into();
+            let res = eval_out_wo_ctx::<SigmaProp>(&expr);
+            let expected = Cand::normalized(
+                sigmaprops
+                    .into_iter()
+                    .map(|sp| sp.value().clone())
+                    .collect::<Vec<SigmaBoolean>>()
+                    .try_into()
+                    .unwrap(),
+            );
+            prop_assert_eq!(res.value(), &expected);
+        }
+    }
+
+    #[test]
+    fn eval_normalized() {
+        let pk: SigmaProp = force_any_val::<ProveDlog>().into();
+        let items: Vec<Expr> = vec![SigmaProp::new(true.into()).into(), pk.clone().into()];
+        let expr: Expr = SigmaAnd::new(items).unwrap().into();
+        assert_eq!(eval_out_wo_ctx::<SigmaProp>(&expr), pk);
+
+        let items: Vec<Expr> = vec![SigmaProp::new(false.into()).into(), pk.clone().into()];
+        let expr: Expr = SigmaAnd::new(items).unwrap().into();
+        assert_eq!(
+            eval_out_wo_ctx::<SigmaProp>(&expr),
+            SigmaProp::new(false.into())
+        );
+
+        let items: Vec<Expr> = vec![pk.clone().into(), pk.into()];
+        let expr: Expr = SigmaAnd::new(items).unwrap().into();
+        assert!(matches!(
+            eval_out_wo_ctx::<SigmaProp>(&expr).value(),
+            SigmaBoolean::SigmaConjecture(SigmaConjecture::Cand(_))
+        ));
+    }
+}
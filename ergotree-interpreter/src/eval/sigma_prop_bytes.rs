@@ -0,0 +1,92 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::sigma_prop_bytes::SigmaPropBytes;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjecture;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProp;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for SigmaPropBytes {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        let sigma_prop = input_v.try_extract_into::<SigmaProp>()?;
+        ctx.cost_accum.add(
+            Costs::DEFAULT
+                .sigma_prop_bytes
+                .cost(num_nodes(sigma_prop.value())),
+        )?;
+        Ok(sigma_prop.prop_bytes()?.into())
+    }
+}
+
+/// Number of nodes in the sigma tree
+fn num_nodes(sb: &SigmaBoolean) -> usize {
+    match sb {
+        SigmaBoolean::TrivialProp(_) | SigmaBoolean::ProofOfKnowledge(_) => 1,
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cand(cand)) => {
+            1 + cand.items.iter().map(num_nodes).sum::<usize>()
+        }
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cor(cor)) => {
+            1 + cor.items.iter().map(num_nodes).sum::<usize>()
+        }
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(th)) => {
+            1 + th.children.iter().map(num_nodes).sum::<usize>()
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use sigma_test_util::force_any_val;
+    use sigma_util::AsVecI8;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::cost_accum::CostAccumulator;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use ergotree_ir::sigma_protocol::sigma_boolean::cand::Cand;
+    use ergotree_ir::sigma_protocol::sigma_boolean::ProveDlog;
+    use std::convert::TryInto;
+    use std::rc::Rc;
+
+    #[test]
+    fn eval() {
+        let sp = force_any_val::<SigmaProp>();
+        let expr: Expr = SigmaPropBytes::new(sp.clone().into()).unwrap().into();
+        assert_eq!(
+            eval_out_wo_ctx::<Vec<i8>>(&expr),
+            sp.prop_bytes().unwrap().as_vec_i8()
+        );
+    }
+
+    #[test]
+    fn cost_per_node() {
+        let pk: SigmaBoolean = force_any_val::<ProveDlog>().into();
+        let and: SigmaBoolean = Cand {
+            items: vec![pk.clone(), pk.clone()].try_into().unwrap(),
+        }
+        .into();
+        assert_eq!(num_nodes(&pk), 1);
+        assert_eq!(num_nodes(&and), 3);
+        let cost = |sb: SigmaBoolean| {
+            let expr: Expr = SigmaPropBytes::new(SigmaProp::new(sb).into())
+                .unwrap()
+                .into();
+            let mut ectx = EvalContext::new(
+                Rc::new(force_any_val::<Context>()),
+                CostAccumulator::new(0, None),
+            );
+            expr.eval(&mut ectx).unwrap();
+            ectx.cost_accum.total_cost()
+        };
+        // 2 more nodes in the tree
+        assert_eq!(cost(and) - cost(pk), 2 * 6);
+    }
+}
@@ -0,0 +1,124 @@
+use ergo_chain_types::PreHeader;
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+
+use super::EvalFn;
+
+pub(crate) static VERSION_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let preheader = obj.try_extract_into::<PreHeader>()?;
+    Ok(Value::Byte(preheader.version as i8))
+};
+
+pub(crate) static PARENT_ID_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let preheader = obj.try_extract_into::<PreHeader>()?;
+    Ok(Vec::<u8>::from(preheader.parent_id.0).into())
+};
+
+pub(crate) static TIMESTAMP_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let preheader = obj.try_extract_into::<PreHeader>()?;
+    Ok(Value::Long(preheader.timestamp as i64))
+};
+
+pub(crate) static N_BITS_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let preheader = obj.try_extract_into::<PreHeader>()?;
+    Ok(Value::Long(preheader.n_bits as i64))
+};
+
+pub(crate) static HEIGHT_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let preheader = obj.try_extract_into::<PreHeader>()?;
+    Ok(Value::Int(preheader.height as i32))
+};
+
+pub(crate) static MINER_PK_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let preheader = obj.try_extract_into::<PreHeader>()?;
+    Ok(Value::GroupElement(preheader.miner_pk))
+};
+
+pub(crate) static VOTES_EVAL_FN: EvalFn = |_ctx, obj, _args| {
+    let preheader = obj.try_extract_into::<PreHeader>()?;
+    Ok(Vec::<u8>::from(preheader.votes).into())
+};
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::rc::Rc;
+
+    use ergo_chain_types::EcPoint;
+    use ergotree_ir::mir::context::Context;
+    use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::mir::property_call::PropertyCall;
+    use ergotree_ir::types::scontext;
+    use ergotree_ir::types::smethod::SMethod;
+    use ergotree_ir::types::spreheader;
+    use sigma_test_util::force_any_val;
+
+    use crate::eval::context::Context as EvalContext;
+    use crate::eval::tests::eval_out;
+
+    fn preheader_property(method: &SMethod) -> Expr {
+        let preheader: Expr =
+            PropertyCall::new(Context.into(), scontext::PRE_HEADER_PROPERTY.clone())
+                .unwrap()
+                .into();
+        PropertyCall::new(preheader, method.clone()).unwrap().into()
+    }
+
+    #[test]
+    fn eval_preheader_properties() {
+        let ctx = Rc::new(force_any_val::<EvalContext>());
+        let preheader = ctx.pre_header.clone();
+        assert_eq!(
+            eval_out::<i8>(
+                &preheader_property(&spreheader::VERSION_PROPERTY),
+                ctx.clone()
+            ),
+            preheader.version as i8
+        );
+        assert_eq!(
+            eval_out::<Vec<i8>>(
+                &preheader_property(&spreheader::PARENT_ID_PROPERTY),
+                ctx.clone()
+            ),
+            Vec::<i8>::from(preheader.parent_id.0)
+        );
+        assert_eq!(
+            eval_out::<i64>(
+                &preheader_property(&spreheader::TIMESTAMP_PROPERTY),
+                ctx.clone()
+            ),
+            preheader.timestamp as i64
+        );
+        assert_eq!(
+            eval_out::<i64>(
+                &preheader_property(&spreheader::N_BITS_PROPERTY),
+                ctx.clone()
+            ),
+            preheader.n_bits as i64
+        );
+        assert_eq!(
+            eval_out::<i32>(
+                &preheader_property(&spreheader::HEIGHT_PROPERTY),
+                ctx.clone()
+            ),
+            preheader.height as i32
+        );
+        assert_eq!(
+            eval_out::<EcPoint>(
+                &preheader_property(&spreheader::MINER_PK_PROPERTY),
+                ctx.clone()
+            ),
+            *preheader.miner_pk
+        );
+        assert_eq!(
+            eval_out::<Vec<i8>>(&preheader_property(&spreheader::VOTES_PROPERTY), ctx),
+            preheader
+                .votes
+                .0
+                .iter()
+                .map(|b| *b as i8)
+                .collect::<Vec<i8>>()
+        );
+    }
+}
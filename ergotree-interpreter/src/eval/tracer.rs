@@ -0,0 +1,313 @@
+//! Evaluation tracing for step-by-step script debugging
+
+use std::fmt::Write;
+
+use ergotree_ir::mir::expr::Expr;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::source_span::SourceSpan;
+
+use super::EvalError;
+
+/// Receives every evaluated node during the reduction (see `ReductionOptions::tracer`)
+pub trait EvalTracer {
+    /// Called once before the evaluation with the pretty printed expression.
+    /// Source spans passed to [`EvalTracer::on_eval`] point into this string.
+    fn on_start(&mut self, _printed_expr: &str) {}
+
+    /// Called after the node `expr` is evaluated to `result`.
+    /// `inputs` are the values of the subexpressions evaluated for this node (in evaluation order).
+    fn on_eval(&mut self, expr: &Expr, span: SourceSpan, inputs: &[Value], result: &Value);
+
+    /// Called when the evaluation of the node `expr` fails with `error`.
+    /// `inputs` are the values of the subexpressions evaluated before the failure.
+    fn on_error(&mut self, _expr: &Expr, _span: SourceSpan, _inputs: &[Value], _error: &EvalError) {
+    }
+}
+
+/// Evaluated node recorded by [`SourceTracer`]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TraceEntry {
+    /// Source position of the node in the pretty printed expression
+    pub span: SourceSpan,
+    /// Values of the evaluated subexpressions
+    pub inputs: Vec<Value>,
+    /// Evaluation result (or the error the evaluation failed with)
+    pub result: Result<Value, EvalError>,
+}
+
+/// Tracer that records the evaluated nodes with source positions and renders them as
+/// an annotated trace of the pretty printed expression
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct SourceTracer {
+    source: String,
+    entries: Vec<TraceEntry>,
+}
+
+impl SourceTracer {
+    /// Create new tracer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recorded nodes (in evaluation order)
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    /// Pretty printed expression followed by the evaluated nodes with their source location,
+    /// source, input values and result
+    pub fn render(&self) -> Result<String, std::fmt::Error> {
+        let mut out = String::new();
+        writeln!(out, "{}", self.source)?;
+        writeln!(out)?;
+        writeln!(out, "Evaluation trace:")?;
+        for entry in &self.entries {
+            let (line, col) = line_col(&self.source, entry.span.offset);
+            write!(out, "{}:{}: {}", line, col, self.snippet(entry.span))?;
+            if !entry.inputs.is_empty() {
+                let inputs: Vec<String> = entry.inputs.iter().map(|v| v.to_string()).collect();
+                write!(out, " <- ({})", inputs.join(", "))?;
+            }
+            match &entry.result {
+                Ok(v) => writeln!(out, " = {}", v)?,
+                Err(e) => writeln!(out, " failed: {}", e)?,
+            }
+        }
+        Ok(out)
+    }
+
+    fn push_entry(&mut self, span: SourceSpan, inputs: &[Value], result: Result<Value, EvalError>) {
+        // nodes without source position (constants, variables, etc.) are shown as inputs
+        if span.length > 0 {
+            self.entries.push(TraceEntry {
+                span,
+                inputs: inputs.to_vec(),
+                result,
+            });
+        }
+    }
+
+    /// First line of the source of the span (multiline sources are elided)
+    fn snippet(&self, span: SourceSpan) -> String {
+        let src = self
+            .source
+            .get(span.offset..span.offset + span.length)
+            .unwrap_or_default();
+        match src.lines().next() {
+            Some(first_line) if first_line.len() < src.len() => format!("{} ...", first_line),
+            _ => src.to_string(),
+        }
+    }
+}
+
+impl EvalTracer for SourceTracer {
+    fn on_start(&mut self, printed_expr: &str) {
+        self.source = printed_expr.to_string();
+        self.entries.clear();
+    }
+
+    fn on_eval(&mut self, _expr: &Expr, span: SourceSpan, inputs: &[Value], result: &Value) {
+        self.push_entry(span, inputs, Ok(result.clone()));
+    }
+
+    fn on_error(&mut self, _expr: &Expr, span: SourceSpan, inputs: &[Value], error: &EvalError) {
+        self.push_entry(span, inputs, Err(error.clone()));
+    }
+}
+
+/// 1-based line and column of the given offset
+fn line_col(source: &str, offset: usize) -> (usize, usize) {
+    let before = source.get(..offset).unwrap_or(source);
+    let line = before.matches('\n').count() + 1;
+    let col = before.len() - before.rfind('\n').map(|i| i + 1).unwrap_or(0) + 1;
+    (line, col)
+}
+
+/// Tracer with the values of the subexpressions of the nodes being evaluated
+pub(crate) struct TraceState<'a> {
+    tracer: &'a mut dyn EvalTracer,
+    /// Values of the already evaluated subexpressions, for each node being evaluated
+    frames: Vec<Vec<Value>>,
+}
+
+impl<'a> TraceState<'a> {
+    pub(crate) fn new(tracer: &'a mut dyn EvalTracer) -> Self {
+        TraceState {
+            tracer,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Start evaluation of a node
+    pub(crate) fn enter(&mut self) {
+        self.frames.push(Vec::new());
+    }
+
+    /// Start evaluation of a lambda body (it is evaluated for every application, so its
+    /// results are not recorded as inputs of the node applying the lambda)
+    pub(crate) fn enter_lambda_body(&mut self) {
+        self.frames.push(Vec::new());
+    }
+
+    /// Finish evaluation of a lambda body
+    pub(crate) fn exit_lambda_body(&mut self) {
+        self.frames.pop();
+    }
+
+    /// Finish evaluation of the node, report it to the tracer and record the result
+    /// as an input of the parent node
+    pub(crate) fn exit(&mut self, expr: &Expr, res: &Result<Value, EvalError>) {
+        let inputs = self.frames.pop().unwrap_or_default();
+        match res {
+            Ok(v) => {
+                self.tracer.on_eval(expr, expr.span(), &inputs, v);
+                if let Some(parent) = self.frames.last_mut() {
+                    parent.push(v.clone());
+                }
+            }
+            // errors of the subexpressions are reported with their source span
+            Err(e) => self
+                .tracer
+                .on_error(expr, expr.span(), &inputs, e.unwrap_spanned()),
+        }
+    }
+}
+
+impl std::fmt::Debug for TraceState<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TraceState")
+            .field("frames", &self.frames)
+            .finish()
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use std::rc::Rc;
+
+    use ergotree_ir::mir::bin_op::ArithOp;
+    use ergotree_ir::mir::bin_op::BinOp;
+    use ergotree_ir::mir::bin_op::RelationOp;
+    use ergotree_ir::mir::block::BlockValue;
+    use ergotree_ir::mir::bool_to_sigma::BoolToSigmaProp;
+    use ergotree_ir::mir::global_vars::GlobalVars;
+    use ergotree_ir::mir::val_def::ValDef;
+    use ergotree_ir::mir::val_use::ValUse;
+    use ergotree_ir::sigma_protocol::sigma_boolean::SigmaBoolean;
+    use ergotree_ir::types::stype::SType;
+    use expect_test::expect;
+    use sigma_test_util::force_any_val;
+
+    use super::*;
+    use crate::eval::context::Context;
+    use crate::eval::reduce_to_crypto;
+    use crate::eval::ReductionOptions;
+
+    #[test]
+    fn trace_false_guard() {
+        // sigmaProp({ val v1 = HEIGHT + 1; v1 > 100 })
+        let val_def: Expr = ValDef {
+            id: 1.into(),
+            rhs: Box::new(
+                BinOp {
+                    kind: ArithOp::Plus.into(),
+                    left: Box::new(GlobalVars::Height.into()),
+                    right: Box::new(1i32.into()),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let expr: Expr = BoolToSigmaProp {
+            input: Box::new(
+                BlockValue {
+                    items: vec![val_def],
+                    result: Box::new(
+                        BinOp {
+                            kind: RelationOp::Gt.into(),
+                            left: Box::new(
+                                ValUse {
+                                    val_id: 1.into(),
+                                    tpe: SType::SInt,
+                                }
+                                .into(),
+                            ),
+                            right: Box::new(100i32.into()),
+                        }
+                        .into(),
+                    ),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let ctx = Rc::new(Context {
+            height: 42,
+            ..force_any_val::<Context>()
+        });
+        let mut tracer = SourceTracer::new();
+        let opts = ReductionOptions {
+            tracer: Some(&mut tracer),
+            ..Default::default()
+        };
+        let res = reduce_to_crypto(&expr, ctx, opts).unwrap();
+        assert_eq!(res.sigma_prop, SigmaBoolean::TrivialProp(false));
+        assert_eq!(tracer.entries().len(), 3);
+        expect![[r#"
+            sigmaProp({
+              val v1 = HEIGHT + 1
+              v1 > 100
+            })
+
+            Evaluation trace:
+            2:12: HEIGHT + 1 <- (42, 1) = 43
+            3:3: v1 > 100 <- (43, 100) = false
+            1:11: { ... <- (43, false) = false
+        "#]]
+        .assert_eq(&tracer.render().unwrap());
+    }
+
+    #[test]
+    fn trace_error() {
+        // sigmaProp(HEIGHT / 0 > 1)
+        let expr: Expr = BoolToSigmaProp {
+            input: Box::new(
+                BinOp {
+                    kind: RelationOp::Gt.into(),
+                    left: Box::new(
+                        BinOp {
+                            kind: ArithOp::Divide.into(),
+                            left: Box::new(GlobalVars::Height.into()),
+                            right: Box::new(0i32.into()),
+                        }
+                        .into(),
+                    ),
+                    right: Box::new(1i32.into()),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let ctx = Rc::new(Context {
+            height: 42,
+            ..force_any_val::<Context>()
+        });
+        let mut tracer = SourceTracer::new();
+        let opts = ReductionOptions {
+            tracer: Some(&mut tracer),
+            ..Default::default()
+        };
+        assert!(reduce_to_crypto(&expr, ctx, opts).is_err());
+        assert!(tracer.entries().iter().all(|e| e.result.is_err()));
+        expect![[r#"
+            sigmaProp(HEIGHT / 0 > 1)
+
+            Evaluation trace:
+            1:11: HEIGHT / 0 <- (42, 0) failed: Arithmetic exception: (42) / (0) resulted in exception
+            1:11: HEIGHT / 0 > 1 failed: Arithmetic exception: (42) / (0) resulted in exception
+        "#]]
+        .assert_eq(&tracer.render().unwrap());
+    }
+}
@@ -0,0 +1,33 @@
+use ergotree_ir::mir::tuple::Tuple;
+use ergotree_ir::mir::value::Value;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Tuple {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let items = self.items.try_mapped_ref(|i| i.eval(ctx))?;
+        Ok(Value::Tup(items))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_pair() {
+        let expr: Expr = Tuple::new(vec![1i32.into(), true.into()]).unwrap().into();
+        let res = eval_out_wo_ctx::<Value>(&expr);
+        assert_eq!(
+            res,
+            Value::Tup([Value::Int(1), Value::Boolean(true)].into())
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use ergotree_ir::bigint256::BigInt256;
+use ergotree_ir::mir::upcast::Upcast;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::types::stype::SType;
+
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+fn upcast_to_bigint(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(v) => Ok(BigInt256::from(v).into()),
+        Value::Short(v) => Ok(BigInt256::from(v).into()),
+        Value::Int(v) => Ok(BigInt256::from(v).into()),
+        Value::Long(v) => Ok(BigInt256::from(v).into()),
+        Value::BigInt(_) => Ok(in_v),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "Upcast: cannot upcast {0:?} to BigInt",
+            in_v
+        ))),
+    }
+}
+
+fn upcast_to_long(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(v) => Ok((v as i64).into()),
+        Value::Short(v) => Ok((v as i64).into()),
+        Value::Int(v) => Ok((v as i64).into()),
+        Value::Long(_) => Ok(in_v),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "Upcast: cannot upcast {0:?} to Long",
+            in_v
+        ))),
+    }
+}
+
+fn upcast_to_int(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(v) => Ok((v as i32).into()),
+        Value::Short(v) => Ok((v as i32).into()),
+        Value::Int(_) => Ok(in_v),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "Upcast: cannot upcast {0:?} to Int",
+            in_v
+        ))),
+    }
+}
+
+fn upcast_to_short(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(v) => Ok((v as i16).into()),
+        Value::Short(_) => Ok(in_v),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "Upcast: cannot upcast {0:?} to Short",
+            in_v
+        ))),
+    }
+}
+
+fn upcast_to_byte(in_v: Value) -> Result<Value, EvalError> {
+    match in_v {
+        Value::Byte(_) => Ok(in_v),
+        _ => Err(EvalError::UnexpectedValue(format!(
+            "Upcast: cannot upcast {0:?} to Byte",
+            in_v
+        ))),
+    }
+}
+
+impl Evaluable for Upcast {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input_v = self.input.eval(ctx)?;
+        match self.tpe {
+            SType::SBigInt => upcast_to_bigint(input_v),
+            SType::SLong => upcast_to_long(input_v),
+            SType::SInt => upcast_to_int(input_v),
+            SType::SShort => upcast_to_short(input_v),
+            SType::SByte => upcast_to_byte(input_v),
+            _ => Err(EvalError::UnexpectedValue(format!(
+                "Upcast: expected numeric target type, got {0:?}",
+                self.tpe
+            ))),
+        }
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+    use crate::eval::tests::try_eval_out_wo_ctx;
+
+    proptest! {
+
+        #[test]
+        fn from_byte(v in any::<i8>()) {
+            let c: Expr = v.into();
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i16>(&Upcast::new(c.clone(), SType::SShort).unwrap().into()),
+                v as i16
+            );
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i32>(&Upcast::new(c.clone(), SType::SInt).unwrap().into()),
+                v as i32
+            );
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i64>(&Upcast::new(c.clone(), SType::SLong).unwrap().into()),
+                v as i64
+            );
+            prop_assert_eq!(
+                eval_out_wo_ctx::<BigInt256>(&Upcast::new(c, SType::SBigInt).unwrap().into()),
+                BigInt256::from(v)
+            );
+        }
+
+        #[test]
+        fn from_int(v in any::<i32>()) {
+            let c: Expr = v.into();
+            prop_assert_eq!(
+                eval_out_wo_ctx::<i64>(&Upcast::new(c.clone(), SType::SLong).unwrap().into()),
+                v as i64
+            );
+            prop_assert_eq!(
+                eval_out_wo_ctx::<BigInt256>(&Upcast::new(c, SType::SBigInt).unwrap().into()),
+                BigInt256::from(v)
+            );
+        }
+    }
+
+    #[test]
+    fn narrowing_fails() {
+        let expr: Expr = Upcast::new(1i64.into(), SType::SInt).unwrap().into();
+        assert!(try_eval_out_wo_ctx::<i32>(&expr).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use ergotree_ir::mir::constant::TryExtractInto;
+use ergotree_ir::mir::value::Value;
+use ergotree_ir::mir::xor::Xor;
+
+use crate::eval::costs::Costs;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+use crate::eval::Evaluable;
+
+impl Evaluable for Xor {
+    fn eval(&self, ctx: &mut EvalContext) -> Result<Value, EvalError> {
+        let left = self.left.eval(ctx)?.try_extract_into::<Vec<i8>>()?;
+        let right = self.right.eval(ctx)?.try_extract_into::<Vec<i8>>()?;
+        let res: Vec<i8> = left.iter().zip(right.iter()).map(|(l, r)| l ^ r).collect();
+        ctx.cost_accum.add(Costs::DEFAULT.xor.cost(res.len()))?;
+        Ok(res.into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use ergotree_ir::mir::expr::Expr;
+
+    use super::*;
+    use crate::eval::tests::eval_out_wo_ctx;
+
+    #[test]
+    fn eval_xor() {
+        let expr: Expr = Xor::new(vec![1u8, 2, 0xff].into(), vec![3u8, 2].into())
+            .unwrap()
+            .into();
+        assert_eq!(eval_out_wo_ctx::<Vec<u8>>(&expr), vec![2u8, 0]);
+    }
+}
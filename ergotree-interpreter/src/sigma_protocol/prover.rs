@@ -7,6 +7,7 @@ pub mod hint;
 
 use crate::eval::reduce_to_crypto;
 use crate::eval::ReductionDiagnosticInfo;
+use crate::eval::ReductionOptions;
 use crate::sigma_protocol::crypto_utils::secure_random_bytes;
 use crate::sigma_protocol::fiat_shamir::fiat_shamir_hash_fn;
 use crate::sigma_protocol::fiat_shamir::fiat_shamir_tree_to_bytes;
@@ -144,9 +145,19 @@ pub trait Prover {
         message: &[u8],
         hints_bag: &HintsBag,
     ) -> Result<ProverResult, ProverError> {
-        let expr = tree.proposition()?;
+        let expr = tree.proposition_with_placeholders()?;
+        let constants = tree.get_constants()?;
         let ctx_ext = ctx.extension.clone();
-        let reduction_result = reduce_to_crypto(&expr, ctx).map_err(ProverError::EvalError)?;
+        let reduction_result = reduce_to_crypto(
+            &expr,
+            ctx,
+            ReductionOptions {
+                constants: &constants,
+                analyze: true,
+                ..Default::default()
+            },
+        )
+        .map_err(ProverError::EvalError)?;
         self.generate_proof(reduction_result.sigma_prop, message, hints_bag)
             .map(|p| ProverResult {
                 proof: p,
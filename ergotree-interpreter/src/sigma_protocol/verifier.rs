@@ -16,11 +16,16 @@ use super::{
     SigmaBoolean, UncheckedTree,
 };
 use crate::eval::context::Context;
+use crate::eval::cost_accum::CostError;
+use crate::eval::costs::Cost;
+use crate::eval::costs::Costs;
 use crate::eval::EvalError;
-use crate::eval::{reduce_to_crypto, ReductionDiagnosticInfo};
+use crate::eval::{reduce_to_crypto, ReductionDiagnosticInfo, ReductionOptions};
 use dlog_protocol::FirstDlogProverMessage;
 use ergotree_ir::ergo_tree::ErgoTree;
 use ergotree_ir::ergo_tree::ErgoTreeError;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaConjecture;
+use ergotree_ir::sigma_protocol::sigma_boolean::SigmaProofOfKnowledgeTree;
 
 use derive_more::From;
 use thiserror::Error;
@@ -46,7 +51,7 @@ pub enum VerifierError {
 pub struct VerificationResult {
     /// result of SigmaProp condition verification via sigma protocol
     pub result: bool,
-    /// estimated cost of contract execution
+    /// estimated cost of contract execution (script reduction and proof verification)
     pub cost: u64,
     /// Diagnostic information about the reduction (pretty printed expr and/or env)
     pub diag: ReductionDiagnosticInfo,
@@ -58,19 +63,40 @@ pub trait Verifier {
     /// Step 1: Deserialize context variables
     /// Step 2: Evaluate expression and produce SigmaProp value, which is zero-knowledge statement (see also `SigmaBoolean`).
     /// Step 3: Verify that the proof is presented to satisfy SigmaProp conditions.
+    /// Fails if the script evaluation cost (along with the estimated cost of the proof
+    /// verification) exceeds `cost_limit` (if set).
     fn verify(
         &self,
         tree: &ErgoTree,
         ctx: Rc<Context>,
         proof: ProofBytes,
         message: &[u8],
+        cost_limit: Option<u64>,
     ) -> Result<VerificationResult, VerifierError> {
-        let expr = tree.proposition()?;
-        let reduction_result = reduce_to_crypto(&expr, ctx)?;
-        let res: bool = match reduction_result.sigma_prop {
-            SigmaBoolean::TrivialProp(b) => b,
+        let expr = tree.proposition_with_placeholders()?;
+        let constants = tree.get_constants()?;
+        let reduction_result = reduce_to_crypto(
+            &expr,
+            ctx,
+            ReductionOptions {
+                constants: &constants,
+                cost_limit,
+                ..Default::default()
+            },
+        )?;
+        let (res, cost) = match reduction_result.sigma_prop {
+            SigmaBoolean::TrivialProp(b) => (b, reduction_result.cost),
             sb => {
-                match proof {
+                // crypto verification cost is charged ahead of the verification
+                let cost = reduction_result
+                    .cost
+                    .saturating_add(estimate_crypto_verify_cost(&sb));
+                if let Some(limit) = cost_limit {
+                    if cost > limit {
+                        return Err(EvalError::CostError(CostError::LimitExceeded(limit)).into());
+                    }
+                }
+                let res = match proof {
                     ProofBytes::Empty => false,
                     ProofBytes::Some(proof_bytes) => {
                         // Perform Verifier Steps 1-3
@@ -78,17 +104,55 @@ pub trait Verifier {
                         // Perform Verifier Steps 4-6
                         check_commitments(unchecked_tree, message)?
                     }
-                }
+                };
+                (res, cost)
             }
         };
         Ok(VerificationResult {
             result: res,
-            cost: 0,
+            cost,
             diag: reduction_result.diag,
         })
     }
 }
 
+/// Estimated cost (in JIT cost units) of the verification of a proof for the given
+/// proposition: every ProveDlog/ProveDHTuple leaf and every AND/OR/THRESHOLD node is charged
+/// (see `Interpreter.estimateCryptoVerifyCost` in the reference Scala implementation)
+pub fn estimate_crypto_verify_cost(sb: &SigmaBoolean) -> u64 {
+    let costs = &Costs::DEFAULT;
+    let cost = |c: Cost| u32::from(c) as u64;
+    let children_cost = |children: &[SigmaBoolean]| -> u64 {
+        children.iter().map(estimate_crypto_verify_cost).sum()
+    };
+    match sb {
+        SigmaBoolean::TrivialProp(_) => 0,
+        SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDlog(_)) => {
+            cost(costs.prove_dlog_verification.clone())
+        }
+        SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDhTuple(_)) => {
+            cost(costs.prove_dh_tuple_verification.clone())
+        }
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cand(cand)) => {
+            cost(costs.proof_tree_conjecture_to_bytes.clone())
+                + children_cost(cand.items.as_slice())
+        }
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cor(cor)) => {
+            cost(costs.proof_tree_conjecture_to_bytes.clone()) + children_cost(cor.items.as_slice())
+        }
+        SigmaBoolean::SigmaConjecture(SigmaConjecture::Cthreshold(th)) => {
+            let n_children = th.children.len();
+            let n_coefs = n_children.saturating_sub(th.k as usize);
+            let parse_cost = cost(costs.parse_polynomial.cost(n_coefs));
+            let eval_cost = cost(costs.evaluate_polynomial.cost(n_coefs)) * n_children as u64;
+            parse_cost
+                + eval_cost
+                + cost(costs.proof_tree_conjecture_to_bytes.clone())
+                + children_cost(th.children.as_slice())
+        }
+    }
+}
+
 /// Verify that the signature is presented to satisfy SigmaProp conditions.
 pub fn verify_signature(
     sigma_tree: SigmaBoolean,
@@ -182,9 +246,13 @@ mod tests {
 
     use super::*;
     use ergotree_ir::mir::expr::Expr;
+    use ergotree_ir::sigma_protocol::sigma_boolean::cand::Cand;
+    use ergotree_ir::sigma_protocol::sigma_boolean::cor::Cor;
+    use ergotree_ir::sigma_protocol::sigma_boolean::cthreshold::Cthreshold;
     use proptest::collection::vec;
     use proptest::prelude::*;
     use sigma_test_util::force_any_val;
+    use std::convert::TryInto;
 
     fn proof_append_some_byte(proof: &ProofBytes) -> ProofBytes {
         match proof {
@@ -217,7 +285,7 @@ mod tests {
             prop_assert_eq!(verifier.verify(&tree,
                                             Rc::new(force_any_val::<Context>()),
                                             proof.clone(),
-                                            message.as_slice())
+                                            message.as_slice(), None)
                             .unwrap().result,
                             true);
 
@@ -225,7 +293,7 @@ mod tests {
             prop_assert_eq!(verifier.verify(&tree,
                                             Rc::new(force_any_val::<Context>()),
                                             proof_append_some_byte(&proof),
-                                            message.as_slice())
+                                            message.as_slice(), None)
                             .unwrap().result,
                             true);
 
@@ -233,7 +301,7 @@ mod tests {
             prop_assert_eq!(verifier.verify(&tree,
                                             Rc::new(force_any_val::<Context>()),
                                             proof,
-                                            vec![1u8; 100].as_slice())
+                                            vec![1u8; 100].as_slice(), None)
                             .unwrap().result,
                             false);
         }
@@ -255,7 +323,7 @@ mod tests {
             prop_assert_eq!(verifier.verify(&tree,
                                             Rc::new(force_any_val::<Context>()),
                                             proof.clone(),
-                                            message.as_slice())
+                                            message.as_slice(), None)
                             .unwrap().result,
                             true);
 
@@ -263,7 +331,7 @@ mod tests {
             prop_assert_eq!(verifier.verify(&tree,
                                             Rc::new(force_any_val::<Context>()),
                                             proof_append_some_byte(&proof),
-                                            message.as_slice())
+                                            message.as_slice(), None)
                             .unwrap().result,
                             true);
 
@@ -271,11 +339,91 @@ mod tests {
             prop_assert_eq!(verifier.verify(&tree,
                                             Rc::new(force_any_val::<Context>()),
                                             proof,
-                                            vec![1u8; 100].as_slice())
+                                            vec![1u8; 100].as_slice(), None)
                             .unwrap().result,
                             false);
         }
 
         // TODO mini: restore tests that was here before minification (see git history of this file)
     }
+
+    #[test]
+    fn test_verify_p2pk_cost() {
+        let secret = force_any_val::<DlogProverInput>();
+        let pk = secret.public_image();
+        let expr = Expr::Const(pk.into());
+        let tree = ErgoTree::try_from(expr.clone()).unwrap();
+        let message = vec![0u8; 100];
+        let prover = TestProver {
+            secrets: vec![PrivateInput::DlogProverInput(secret)],
+        };
+        let ctx = Rc::new(force_any_val::<Context>());
+        let proof = prover
+            .prove(&tree, ctx.clone(), message.as_slice(), &HintsBag::empty())
+            .unwrap()
+            .proof;
+        let reduction_cost = reduce_to_crypto(&expr, ctx.clone(), ReductionOptions::default())
+            .unwrap()
+            .cost;
+        let verifier = TestVerifier;
+        let res = verifier
+            .verify(&tree, ctx.clone(), proof.clone(), message.as_slice(), None)
+            .unwrap();
+        assert!(res.result);
+        assert_eq!(res.cost, reduction_cost + 3980);
+        let total_cost = res.cost;
+        assert!(
+            verifier
+                .verify(
+                    &tree,
+                    ctx.clone(),
+                    proof.clone(),
+                    message.as_slice(),
+                    Some(total_cost)
+                )
+                .unwrap()
+                .result
+        );
+        assert!(matches!(
+            verifier.verify(&tree, ctx, proof, message.as_slice(), Some(total_cost - 1)),
+            Err(VerifierError::EvalError(EvalError::CostError(
+                CostError::LimitExceeded(_)
+            )))
+        ));
+    }
+
+    #[test]
+    fn test_estimate_crypto_verify_cost() {
+        let dlog: SigmaBoolean = force_any_val::<DlogProverInput>().public_image().into();
+        let dht: SigmaBoolean = force_any_val::<DhTupleProverInput>()
+            .public_image()
+            .clone()
+            .into();
+        assert_eq!(estimate_crypto_verify_cost(&true.into()), 0);
+        assert_eq!(estimate_crypto_verify_cost(&dlog), 3980);
+        assert_eq!(estimate_crypto_verify_cost(&dht), 7140);
+        let cand: SigmaBoolean = Cand {
+            items: vec![dlog.clone(), dht.clone()].try_into().unwrap(),
+        }
+        .into();
+        assert_eq!(estimate_crypto_verify_cost(&cand), 15 + 3980 + 7140);
+        let cor: SigmaBoolean = Cor {
+            items: vec![dlog.clone(), cand].try_into().unwrap(),
+        }
+        .into();
+        assert_eq!(
+            estimate_crypto_verify_cost(&cor),
+            15 + 3980 + 15 + 3980 + 7140
+        );
+        // 2-of-3: 1 polynomial coefficient, evaluated for each of the 3 children
+        let th: SigmaBoolean = Cthreshold {
+            k: 2,
+            children: vec![dlog.clone(), dlog, dht].try_into().unwrap(),
+        }
+        .into();
+        assert_eq!(
+            estimate_crypto_verify_cost(&th),
+            (10 + 10) + (3 + 3) * 3 + 15 + 3980 * 2 + 7140
+        );
+    }
 }
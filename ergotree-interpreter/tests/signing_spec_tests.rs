@@ -38,6 +38,7 @@ fn sig_test_vector_provedlog() {
         Rc::new(force_any_val::<Context>()),
         signature.into(),
         msg.as_slice(),
+        None,
     );
     assert!(ver_res.unwrap().result);
 }
@@ -75,6 +76,7 @@ fn sig_test_vector_prove_dht() {
         Rc::new(force_any_val::<Context>()),
         signature.into(),
         msg.as_slice(),
+        None,
     );
     assert!(ver_res.unwrap().result);
 }
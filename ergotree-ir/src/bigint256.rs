@@ -11,7 +11,8 @@ use num_bigint::ToBigInt;
 use num_derive::{One, Zero};
 use num_integer::Integer;
 use num_traits::{
-    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Num, Zero,
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Num,
+    ToPrimitive, Zero,
 };
 
 /// 256-bit signed integer type
@@ -91,6 +92,83 @@ impl From<BigInt256> for BigInt {
     }
 }
 
+impl TryFrom<BigInt256> for i8 {
+    type Error = String;
+
+    fn try_from(value: BigInt256) -> Result<Self, Self::Error> {
+        BigInt::from(value.clone())
+            .to_i8()
+            .ok_or_else(|| format!("BigInt256: Value {} is out of i8 range", value))
+    }
+}
+
+impl TryFrom<BigInt256> for i16 {
+    type Error = String;
+
+    fn try_from(value: BigInt256) -> Result<Self, Self::Error> {
+        BigInt::from(value.clone())
+            .to_i16()
+            .ok_or_else(|| format!("BigInt256: Value {} is out of i16 range", value))
+    }
+}
+
+impl TryFrom<BigInt256> for i32 {
+    type Error = String;
+
+    fn try_from(value: BigInt256) -> Result<Self, Self::Error> {
+        BigInt::from(value.clone())
+            .to_i32()
+            .ok_or_else(|| format!("BigInt256: Value {} is out of i32 range", value))
+    }
+}
+
+impl TryFrom<BigInt256> for i64 {
+    type Error = String;
+
+    fn try_from(value: BigInt256) -> Result<Self, Self::Error> {
+        BigInt::from(value.clone())
+            .to_i64()
+            .ok_or_else(|| format!("BigInt256: Value {} is out of i64 range", value))
+    }
+}
+
+/// Order of the secp256k1 group (`q`) which is used in `modQ` and related operations
+const GROUP_ORDER_HEX: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+fn group_order() -> BigInt {
+    #[allow(clippy::unwrap_used)] // valid hex constant
+    BigInt::from_str_radix(GROUP_ORDER_HEX, 16).unwrap()
+}
+
+impl BigInt256 {
+    /// Returns `self mod q`, where `q` is the order of the secp256k1 group.
+    /// Returns an error if the result does not fit into 256-bit signed integer
+    pub fn mod_q(&self) -> Result<BigInt256, String> {
+        Self::try_from(BigInt::from(self.clone()).mod_floor(&group_order()))
+    }
+
+    /// Returns `(self + other) mod q`, where `q` is the order of the secp256k1 group.
+    /// Returns an error if the result does not fit into 256-bit signed integer
+    pub fn plus_mod_q(&self, other: &BigInt256) -> Result<BigInt256, String> {
+        let sum = BigInt::from(self.clone()) + BigInt::from(other.clone());
+        Self::try_from(sum.mod_floor(&group_order()))
+    }
+
+    /// Returns `(self - other) mod q`, where `q` is the order of the secp256k1 group.
+    /// Returns an error if the result does not fit into 256-bit signed integer
+    pub fn minus_mod_q(&self, other: &BigInt256) -> Result<BigInt256, String> {
+        let diff = BigInt::from(self.clone()) - BigInt::from(other.clone());
+        Self::try_from(diff.mod_floor(&group_order()))
+    }
+
+    /// Returns `(self * other) mod q`, where `q` is the order of the secp256k1 group.
+    /// Returns an error if the result does not fit into 256-bit signed integer
+    pub fn mult_mod_q(&self, other: &BigInt256) -> Result<BigInt256, String> {
+        let product = BigInt::from(self.clone()) * BigInt::from(other.clone());
+        Self::try_from(product.mod_floor(&group_order()))
+    }
+}
+
 impl Deref for BigInt256 {
     type Target = Int256;
 
@@ -349,4 +427,40 @@ mod tests {
         let bigint_from_bytes = BigInt256::try_from(&bytes[..]);
         assert!(bigint_from_bytes.is_err());
     }
+
+    #[test]
+    fn to_primitive() {
+        assert_eq!(i8::try_from(BigInt256::from(i8::MIN)), Ok(i8::MIN));
+        assert_eq!(i16::try_from(BigInt256::from(i16::MAX)), Ok(i16::MAX));
+        assert_eq!(i32::try_from(BigInt256::from(-1i32)), Ok(-1));
+        assert_eq!(i64::try_from(BigInt256::from(i64::MAX)), Ok(i64::MAX));
+        assert!(i8::try_from(BigInt256::from(128i16)).is_err());
+        assert!(i64::try_from(BigInt256::max_value()).is_err());
+    }
+
+    #[test]
+    fn mod_q_ops() {
+        let q_minus_one = BigInt256::from(-1i64).mod_q();
+        // q - 1 is larger than 2^255 - 1
+        assert!(q_minus_one.is_err());
+        assert_eq!(BigInt256::from(10i64).mod_q(), Ok(BigInt256::from(10i64)));
+        assert_eq!(
+            BigInt256::from(2i64).plus_mod_q(&BigInt256::from(3i64)),
+            Ok(BigInt256::from(5i64))
+        );
+        assert_eq!(
+            BigInt256::from(3i64).minus_mod_q(&BigInt256::from(2i64)),
+            Ok(BigInt256::from(1i64))
+        );
+        assert_eq!(
+            BigInt256::from(3i64).mult_mod_q(&BigInt256::from(4i64)),
+            Ok(BigInt256::from(12i64))
+        );
+        // (2^255 - 1) * 2 = 2^256 - 2 = q + (2^256 - q - 2)
+        let expected = BigInt256::from_str_radix("14551231950b75fc4402da1732fc9bebd", 16).unwrap();
+        assert_eq!(
+            BigInt256::max_value().mult_mod_q(&BigInt256::from(2i64)),
+            Ok(expected)
+        );
+    }
 }
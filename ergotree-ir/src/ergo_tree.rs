@@ -227,6 +227,13 @@ impl ErgoTree {
         }
     }
 
+    /// get Expr out of ErgoTree as it's stored in the tree, i.e. with ConstantPlaceholder nodes
+    /// instead of Constant nodes (if constants are segregated). The placeholders refer to the tree
+    /// constants (see `get_constants`).
+    pub fn proposition_with_placeholders(&self) -> Result<Expr, ErgoTreeError> {
+        Ok(self.parsed_tree()?.root.clone())
+    }
+
     /// Prints with newlines
     pub fn debug_tree(&self) -> String {
         let tree = format!("{:#?}", self);
@@ -1,5 +1,65 @@
 //! Mid-level IR (ErgoTree)
 
+pub mod apply;
+pub mod atleast;
+pub mod avl_tree_data;
+pub mod bin_op;
+pub mod block;
+pub mod bool_to_sigma;
+pub mod byte_array_to_bigint;
+pub mod byte_array_to_long;
+pub mod calc_blake2b256;
+pub mod calc_sha256;
+pub mod coll_append;
+pub mod coll_by_index;
+pub mod coll_exists;
+pub mod coll_filter;
+pub mod coll_fold;
+pub mod coll_forall;
+pub mod coll_map;
+pub mod coll_size;
+pub mod coll_slice;
 pub mod constant;
+pub mod context;
+pub mod create_prove_dh_tuple;
+pub mod create_provedlog;
+pub mod decode_point;
+pub mod deserialize_context;
+pub mod deserialize_register;
+pub mod downcast;
+pub mod exponentiate;
 pub mod expr;
+pub mod extract_amount;
+pub mod extract_bytes;
+pub mod extract_bytes_with_no_ref;
+pub mod extract_creation_info;
+pub mod extract_id;
+pub mod extract_reg_as;
+pub mod extract_script_bytes;
+pub mod func_value;
+pub mod get_var;
+pub mod global_vars;
+pub mod group_generator;
+pub mod logical_not;
+pub mod long_to_byte_array;
+pub mod method_call;
+pub mod minus_mod_q;
+pub mod mod_q;
+pub mod multiply_group;
+pub mod negation;
+pub mod option_get;
+pub mod option_get_or_else;
+pub mod option_is_defined;
+pub mod plus_mod_q;
+pub mod property_call;
+pub mod select_field;
+pub mod sigma_and;
+pub mod sigma_or;
+pub mod sigma_prop_bytes;
+pub mod tuple;
+pub mod unary_op;
+pub mod upcast;
+pub mod val_def;
+pub mod val_use;
 pub mod value;
+pub mod xor;
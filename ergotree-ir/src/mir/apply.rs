@@ -0,0 +1,148 @@
+//! Application of function
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Application of function `func` to given arguments `args`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Apply {
+    /// Function
+    pub func: Box<Expr>,
+    /// Arguments
+    pub args: Vec<Expr>,
+}
+
+impl Apply {
+    /// Create new object, returns an error if the function is not of SFunc type or
+    /// the argument types don't match the function signature
+    pub fn new(func: Expr, args: Vec<Expr>) -> Result<Self, InvalidArgumentError> {
+        match func.tpe() {
+            SType::SFunc(sfunc) => {
+                let arg_types: Vec<SType> = args.iter().map(|a| a.tpe()).collect();
+                if sfunc.t_dom != arg_types {
+                    return Err(InvalidArgumentError(format!(
+                        "Apply: expected arguments of types {0:?}, got {1:?}",
+                        sfunc.t_dom, arg_types
+                    )));
+                }
+                Ok(Apply {
+                    func: Box::new(func),
+                    args,
+                })
+            }
+            _ => Err(InvalidArgumentError(format!(
+                "Apply: expected func to be of SFunc type, got {0:?}",
+                func
+            ))),
+        }
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        match self.func.tpe() {
+            SType::SFunc(sfunc) => *sfunc.t_range,
+            tpe => tpe,
+        }
+    }
+}
+
+impl HasStaticOpCode for Apply {
+    const OP_CODE: OpCode = OpCode::FUNCTION_APPLY;
+}
+
+impl SigmaSerializable for Apply {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.func.sigma_serialize(w)?;
+        w.put_usize_as_u32_unwrapped(self.args.len())?;
+        self.args.iter().try_for_each(|a| a.sigma_serialize(w))
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let func = Expr::sigma_parse(r)?;
+        let args_count = r.get_u32()?;
+        let mut args = Vec::with_capacity(args_count as usize);
+        for _ in 0..args_count {
+            args.push(Expr::sigma_parse(r)?);
+        }
+        Ok(Apply::new(func, args)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::mir::func_value::FuncArg;
+    use crate::mir::func_value::FuncValue;
+    use crate::mir::val_def::ValId;
+    use crate::mir::val_use::ValUse;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Apply {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any::<ValId>(),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SInt,
+                    depth: 1,
+                }),
+            )
+                .prop_map(|(idx, arg)| {
+                    // { (v: Int) => v }(arg)
+                    let func: Expr = FuncValue::new(
+                        vec![FuncArg {
+                            idx,
+                            tpe: SType::SInt,
+                        }],
+                        ValUse {
+                            val_id: idx,
+                            tpe: SType::SInt,
+                        }
+                        .into(),
+                    )
+                    .into();
+                    Self {
+                        func: Box::new(func),
+                        args: vec![arg],
+                    }
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Apply>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_non_func() {
+        assert!(Apply::new(1i32.into(), vec![1i32.into()]).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+//! THRESHOLD conjunction for sigma propositions
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// THRESHOLD conjunction for sigma propositions ("atLeast(bound, props)" in ErgoScript).
+/// Is proven if at least `bound` of the given propositions are proven
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Atleast {
+    /// Minimum number of propositions to be proven (SInt)
+    pub bound: Box<Expr>,
+    /// Collection of propositions (Coll[SigmaProp])
+    pub input: Box<Expr>,
+}
+
+impl Atleast {
+    /// Maximum number of items in the input collection
+    pub const MAX_CHILDREN_COUNT: usize = 255;
+
+    /// Create new object, returns an error if the bound is not SInt or the input is not
+    /// Coll[SigmaProp]
+    pub fn new(bound: Expr, input: Expr) -> Result<Self, InvalidArgumentError> {
+        bound.check_post_eval_tpe(&SType::SInt)?;
+        input.check_post_eval_tpe(&SType::SColl(SType::SSigmaProp.into()))?;
+        Ok(Self {
+            bound: bound.into(),
+            input: input.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SSigmaProp
+    }
+}
+
+impl HasStaticOpCode for Atleast {
+    const OP_CODE: OpCode = OpCode::ATLEAST;
+}
+
+impl SigmaSerializable for Atleast {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.bound.sigma_serialize(w)?;
+        self.input.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let bound = Expr::sigma_parse(r)?;
+        let input = Expr::sigma_parse(r)?;
+        Ok(Self::new(bound, input)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Atleast {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SInt,
+                    depth: 0,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SColl(SType::SSigmaProp.into()),
+                    depth: 0,
+                }),
+            )
+                .prop_map(|(bound, input)| Self {
+                    bound: bound.into(),
+                    input: input.into(),
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Atleast>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_wrong_input_type() {
+        assert!(Atleast::new(1i32.into(), vec![1i32, 2i32].into()).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+//! AVL tree data
+
+use ergo_chain_types::ADDigest;
+use sigma_ser::ScorexSerializable;
+
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+
+/// Type of operations allowed on the AVL tree
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct AvlTreeFlags(u8);
+
+impl AvlTreeFlags {
+    /// Create new flags
+    pub fn new(insert_allowed: bool, update_allowed: bool, remove_allowed: bool) -> Self {
+        let read_only = 0;
+        let i = if insert_allowed {
+            read_only | 0x01
+        } else {
+            read_only
+        };
+        let u = if update_allowed { i | 0x02 } else { i };
+        let r = if remove_allowed { u | 0x04 } else { u };
+        AvlTreeFlags(r)
+    }
+
+    /// Parse flags from byte
+    pub fn parse(serialized_flags: u8) -> Self {
+        let insert_allowed = serialized_flags & 0x01 != 0;
+        let update_allowed = serialized_flags & 0x02 != 0;
+        let remove_allowed = serialized_flags & 0x04 != 0;
+        AvlTreeFlags::new(insert_allowed, update_allowed, remove_allowed)
+    }
+
+    /// Serialize flags to byte
+    pub fn serialize(self) -> u8 {
+        self.0
+    }
+
+    /// Returns true if inserting is allowed
+    pub fn insert_allowed(self) -> bool {
+        self.0 & 0x01 != 0
+    }
+
+    /// Returns true if updating is allowed
+    pub fn update_allowed(self) -> bool {
+        self.0 & 0x02 != 0
+    }
+
+    /// Returns true if removing is allowed
+    pub fn remove_allowed(self) -> bool {
+        self.0 & 0x04 != 0
+    }
+}
+
+/// Authenticated dictionary (AVL+ tree) data: the root hash and the tree parameters
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AvlTreeData {
+    /// Authenticated tree digest: root hash along with tree height
+    pub digest: ADDigest,
+    /// Allowed modifications
+    pub tree_flags: AvlTreeFlags,
+    /// All the elements under the tree have the same length
+    pub key_length: u32,
+    /// If non-empty, all the values under the tree are of the same length
+    pub value_length_opt: Option<Box<u32>>,
+}
+
+impl SigmaSerializable for AvlTreeData {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.digest.scorex_serialize(w)?;
+        w.put_u8(self.tree_flags.serialize())?;
+        w.put_u32(self.key_length)?;
+        self.value_length_opt.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let digest = ADDigest::scorex_parse(r)?;
+        let tree_flags = AvlTreeFlags::parse(r.get_u8()?);
+        let key_length = r.get_u32()?;
+        let value_length_opt = Option::<Box<u32>>::sigma_parse(r)?;
+        Ok(AvlTreeData {
+            digest,
+            tree_flags,
+            key_length,
+            value_length_opt,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary {
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for AvlTreeFlags {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (any::<bool>(), any::<bool>(), any::<bool>())
+                .prop_map(|(i, u, r)| AvlTreeFlags::new(i, u, r))
+                .boxed()
+        }
+    }
+
+    impl Arbitrary for AvlTreeData {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any::<ADDigest>(),
+                any::<AvlTreeFlags>(),
+                1..64u32,
+                proptest::option::of(0..64u32),
+            )
+                .prop_map(
+                    |(digest, tree_flags, key_length, value_length_opt)| AvlTreeData {
+                        digest,
+                        tree_flags,
+                        key_length,
+                        value_length_opt: value_length_opt.map(Box::new),
+                    },
+                )
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<AvlTreeData>()) {
+            prop_assert_eq![sigma_serialize_roundtrip(&v), v];
+        }
+
+        #[test]
+        fn flags_roundtrip(v in any::<AvlTreeFlags>()) {
+            prop_assert_eq![AvlTreeFlags::parse(v.serialize()), v];
+        }
+    }
+}
@@ -0,0 +1,290 @@
+//! Operators in ErgoTree
+
+use std::fmt::Display;
+
+use super::expr::Expr;
+use crate::has_opcode::HasOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+extern crate derive_more;
+use derive_more::From;
+
+#[cfg(feature = "arbitrary")]
+use proptest_derive::Arbitrary;
+
+/// Operations for numerical types
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum ArithOp {
+    /// Addition
+    Plus,
+    /// Subtraction
+    Minus,
+    /// Multiplication
+    Multiply,
+    /// Division
+    Divide,
+    /// Max of two values
+    Max,
+    /// Min of two values
+    Min,
+    /// Remainder of the division
+    Modulo,
+}
+
+impl From<ArithOp> for OpCode {
+    fn from(op: ArithOp) -> Self {
+        match op {
+            ArithOp::Plus => OpCode::PLUS,
+            ArithOp::Minus => OpCode::MINUS,
+            ArithOp::Multiply => OpCode::MULTIPLY,
+            ArithOp::Divide => OpCode::DIVISION,
+            ArithOp::Max => OpCode::MAX,
+            ArithOp::Min => OpCode::MIN,
+            ArithOp::Modulo => OpCode::MODULO,
+        }
+    }
+}
+
+impl Display for ArithOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArithOp::Plus => write!(f, "+"),
+            ArithOp::Minus => write!(f, "-"),
+            ArithOp::Multiply => write!(f, "*"),
+            ArithOp::Divide => write!(f, "/"),
+            ArithOp::Max => write!(f, "max"),
+            ArithOp::Min => write!(f, "min"),
+            ArithOp::Modulo => write!(f, "%"),
+        }
+    }
+}
+
+/// Relational operations
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum RelationOp {
+    /// Equality
+    Eq,
+    /// Non-equality
+    NEq,
+    /// Greater or equal
+    Ge,
+    /// Greater than
+    Gt,
+    /// Less or equal
+    Le,
+    /// Less than
+    Lt,
+}
+
+impl From<RelationOp> for OpCode {
+    fn from(op: RelationOp) -> Self {
+        match op {
+            RelationOp::Eq => OpCode::EQ,
+            RelationOp::NEq => OpCode::NEQ,
+            RelationOp::Ge => OpCode::GE,
+            RelationOp::Gt => OpCode::GT,
+            RelationOp::Le => OpCode::LE,
+            RelationOp::Lt => OpCode::LT,
+        }
+    }
+}
+
+impl Display for RelationOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RelationOp::Eq => write!(f, "=="),
+            RelationOp::NEq => write!(f, "!="),
+            RelationOp::Ge => write!(f, ">="),
+            RelationOp::Gt => write!(f, ">"),
+            RelationOp::Le => write!(f, "<="),
+            RelationOp::Lt => write!(f, "<"),
+        }
+    }
+}
+
+/// Logical operations
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum LogicalOp {
+    /// Logical AND
+    And,
+    /// Logical OR
+    Or,
+    /// Logical XOR
+    Xor,
+}
+
+impl From<LogicalOp> for OpCode {
+    fn from(op: LogicalOp) -> Self {
+        match op {
+            LogicalOp::And => OpCode::BIN_AND,
+            LogicalOp::Or => OpCode::BIN_OR,
+            LogicalOp::Xor => OpCode::BIN_XOR,
+        }
+    }
+}
+
+impl Display for LogicalOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogicalOp::And => write!(f, "&&"),
+            LogicalOp::Or => write!(f, "||"),
+            LogicalOp::Xor => write!(f, "^"),
+        }
+    }
+}
+
+/// Binary operations
+#[derive(PartialEq, Eq, Debug, Clone, Copy, From)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum BinOpKind {
+    /// Arithmetic operations
+    Arith(ArithOp),
+    /// Relation operations (equality, comparison, etc.)
+    Relation(RelationOp),
+    /// Logical operations
+    Logical(LogicalOp),
+}
+
+impl From<BinOpKind> for OpCode {
+    fn from(op: BinOpKind) -> Self {
+        match op {
+            BinOpKind::Arith(o) => o.into(),
+            BinOpKind::Relation(o) => o.into(),
+            BinOpKind::Logical(o) => o.into(),
+        }
+    }
+}
+
+impl Display for BinOpKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinOpKind::Arith(op) => write!(f, "{}", op),
+            BinOpKind::Relation(op) => write!(f, "{}", op),
+            BinOpKind::Logical(op) => write!(f, "{}", op),
+        }
+    }
+}
+
+/// Binary operation
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BinOp {
+    /// Operation kind
+    pub kind: BinOpKind,
+    /// Left operand
+    pub left: Box<Expr>,
+    /// Right operand
+    pub right: Box<Expr>,
+}
+
+impl BinOp {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        match self.kind {
+            BinOpKind::Arith(_) => self.left.tpe(),
+            BinOpKind::Relation(_) => SType::SBoolean,
+            BinOpKind::Logical(_) => SType::SBoolean,
+        }
+    }
+}
+
+impl HasOpCode for BinOp {
+    fn op_code(&self) -> OpCode {
+        self.kind.into()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for BinOp {
+        type Parameters = ArbExprParams;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+            match args.tpe {
+                SType::SBoolean => {
+                    let numeric_args = ArbExprParams {
+                        tpe: SType::SInt,
+                        depth: args.depth,
+                    };
+                    prop_oneof![
+                        (
+                            any::<RelationOp>().prop_map_into(),
+                            any_with::<Expr>(numeric_args.clone()),
+                            any_with::<Expr>(numeric_args),
+                        ),
+                        (
+                            any::<LogicalOp>().prop_map_into(),
+                            any_with::<Expr>(args.clone()),
+                            any_with::<Expr>(args),
+                        ),
+                    ]
+                    .prop_map(|(kind, left, right)| BinOp {
+                        kind,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    })
+                    .boxed()
+                }
+                _ => (
+                    any::<ArithOp>().prop_map_into(),
+                    any_with::<Expr>(args.clone()),
+                    any_with::<Expr>(args),
+                )
+                    .prop_map(|(kind, left, right)| BinOp {
+                        kind,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    })
+                    .boxed(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any_with::<BinOp>(ArbExprParams {tpe: SType::SInt, depth: 0})) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+
+        #[test]
+        fn ser_roundtrip_bool(v in any_with::<BinOp>(ArbExprParams {tpe: SType::SBoolean, depth: 0})) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn ser_bool_consts() {
+        // both operands are boolean constants, serialized as a collection of bits
+        let expr: Expr = BinOp {
+            kind: RelationOp::Eq.into(),
+            left: Box::new(true.into()),
+            right: Box::new(false.into()),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,134 @@
+//! Block of expressions
+
+use super::expr::Expr;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// The order of ValDefs in the block is used to assign ids to ValUse(id) nodes
+/// For all i: items(i).id == {number of ValDefs preceded in a graph} with respect to topological order.
+/// Specific topological order doesn't really matter, what is important is to preserve semantic linkage
+/// between ValUse(id) and ValDef with the corresponding id.
+/// This convention allow to valid serializing ids because we always serializing and deserializing
+/// in a fixed well defined order.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BlockValue {
+    /// Block items (ValDef's)
+    pub items: Vec<Expr>,
+    /// Resulting expression
+    pub result: Box<Expr>,
+}
+
+impl BlockValue {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.result.tpe()
+    }
+}
+
+impl HasStaticOpCode for BlockValue {
+    const OP_CODE: OpCode = OpCode::BLOCK_VALUE;
+}
+
+impl SigmaSerializable for BlockValue {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        w.put_usize_as_u32_unwrapped(self.items.len())?;
+        self.items.iter().try_for_each(|i| i.sigma_serialize(w))?;
+        self.result.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let items_count = r.get_u32()?;
+        // items count is untrusted, do not preallocate
+        let mut items = Vec::new();
+        for _ in 0..items_count {
+            items.push(Expr::sigma_parse(r)?)
+        }
+        let result = Expr::sigma_parse(r)?;
+        Ok(BlockValue {
+            items,
+            result: Box::new(result),
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::mir::val_def::ValDef;
+
+    use super::*;
+    use proptest::collection::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for BlockValue {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ArbExprParams;
+
+        fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+            (
+                vec(any::<ValDef>().prop_map_into(), 0..4),
+                any_with::<Expr>(args),
+            )
+                .prop_map(|(items, result)| Self {
+                    items,
+                    result: Box::new(result),
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::mir::val_def::ValDef;
+    use crate::mir::val_def::ValId;
+    use crate::mir::val_use::ValUse;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any_with::<BlockValue>(ArbExprParams::default())) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn parse_huge_items_count() {
+        // VLQ-encoded u32::MAX items count followed by nothing
+        assert!(BlockValue::sigma_parse_bytes(&[0xff, 0xff, 0xff, 0xff, 0x0f]).is_err());
+    }
+
+    #[test]
+    fn ser_roundtrip_val_use() {
+        let expr: Expr = BlockValue {
+            items: vec![ValDef {
+                id: ValId(1),
+                rhs: Box::new(1i32.into()),
+            }
+            .into()],
+            result: Box::new(
+                ValUse {
+                    val_id: ValId(1),
+                    tpe: SType::SInt,
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,95 @@
+//! Embedding of Boolean values to SigmaProp
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::OneArgOp;
+use super::unary_op::OneArgOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+/// Embedding of Boolean values to SigmaProp ("sigmaProp(bool)" in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BoolToSigmaProp {
+    /// Input expr of SBoolean type
+    pub input: Box<Expr>,
+}
+
+impl BoolToSigmaProp {
+    /// Create new object, returns an error if the input is not of SBoolean type
+    pub fn new(input: Expr) -> Result<Self, InvalidArgumentError> {
+        input.check_post_eval_tpe(&SType::SBoolean)?;
+        Ok(Self {
+            input: input.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SSigmaProp
+    }
+}
+
+impl HasStaticOpCode for BoolToSigmaProp {
+    const OP_CODE: OpCode = OpCode::BOOL_TO_SIGMA_PROP;
+}
+
+impl OneArgOp for BoolToSigmaProp {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl OneArgOpTryBuild for BoolToSigmaProp {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        Self::new(input)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for BoolToSigmaProp {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = usize;
+
+        fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+            any_with::<Expr>(ArbExprParams {
+                tpe: SType::SBoolean,
+                depth: args,
+            })
+            .prop_map(|input| Self {
+                input: input.into(),
+            })
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any_with::<BoolToSigmaProp>(1)) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_wrong_input_type() {
+        assert!(BoolToSigmaProp::new(1i32.into()).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+//! Collection concatenation
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Puts elements of other collection after the elements of input collection (concatenation of two collections)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Append {
+    /// Collection - First operand of concatenation
+    pub input: Box<Expr>,
+    /// Collection - Second operand of concatenation
+    pub col_2: Box<Expr>,
+}
+
+impl Append {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(input: Expr, col_2: Expr) -> Result<Self, InvalidArgumentError> {
+        let input_elem_tpe = input.coll_elem_tpe()?;
+        let col_2_elem_tpe = col_2.coll_elem_tpe()?;
+        if input_elem_tpe != col_2_elem_tpe {
+            return Err(InvalidArgumentError(format!(
+                "Append: collection element types differ: {0:?} and {1:?}",
+                input_elem_tpe, col_2_elem_tpe
+            )));
+        }
+        Ok(Append {
+            input: input.into(),
+            col_2: col_2.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.input.tpe()
+    }
+}
+
+impl HasStaticOpCode for Append {
+    const OP_CODE: OpCode = OpCode::APPEND;
+}
+
+impl SigmaSerializable for Append {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input.sigma_serialize(w)?;
+        self.col_2.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let col_2 = Expr::sigma_parse(r)?;
+        Ok(Append::new(input, col_2)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Append {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                (
+                    any_with::<Expr>(ArbExprParams {
+                        tpe: SType::SColl(SType::SByte.into()),
+                        depth: 0,
+                    }),
+                    any_with::<Expr>(ArbExprParams {
+                        tpe: SType::SColl(SType::SByte.into()),
+                        depth: 0,
+                    }),
+                ),
+                (
+                    any_with::<Expr>(ArbExprParams {
+                        tpe: SType::SColl(SType::SLong.into()),
+                        depth: 0,
+                    }),
+                    any_with::<Expr>(ArbExprParams {
+                        tpe: SType::SColl(SType::SLong.into()),
+                        depth: 0,
+                    }),
+                ),
+            ]
+            .prop_map(|(input, col_2)| Self::new(input, col_2).unwrap())
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Append>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
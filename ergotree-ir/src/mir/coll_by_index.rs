@@ -0,0 +1,137 @@
+//! Extract collection element by index
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Get collection element by index
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ByIndex {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Element index
+    pub index: Box<Expr>,
+    /// Default value, returned if index is out of bounds in "Coll.getOrElse()" op
+    pub default: Option<Box<Expr>>,
+    /// Collection element type
+    pub elem_tpe: SType,
+}
+
+impl ByIndex {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(
+        input: Expr,
+        index: Expr,
+        default: Option<Box<Expr>>,
+    ) -> Result<Self, InvalidArgumentError> {
+        let elem_tpe = input.coll_elem_tpe()?;
+        index.check_post_eval_tpe(&SType::SInt)?;
+        if let Some(default) = &default {
+            default.check_post_eval_tpe(&elem_tpe)?;
+        }
+        Ok(ByIndex {
+            input: input.into(),
+            index: index.into(),
+            default,
+            elem_tpe,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.elem_tpe.clone()
+    }
+}
+
+impl HasStaticOpCode for ByIndex {
+    const OP_CODE: OpCode = OpCode::BY_INDEX;
+}
+
+impl SigmaSerializable for ByIndex {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input.sigma_serialize(w)?;
+        self.index.sigma_serialize(w)?;
+        match &self.default {
+            Some(default) => {
+                w.put_u8(1)?;
+                default.sigma_serialize(w)
+            }
+            None => {
+                w.put_u8(0)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let index = Expr::sigma_parse(r)?;
+        let default = match r.get_u8()? {
+            0 => None,
+            _ => Some(Box::new(Expr::sigma_parse(r)?)),
+        };
+        Ok(ByIndex::new(input, index, default)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::option;
+    use proptest::prelude::*;
+
+    impl Arbitrary for ByIndex {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SColl(SType::SInt.into()),
+                    depth: 0,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SInt,
+                    depth: 0,
+                }),
+                option::of(any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SInt,
+                    depth: 0,
+                })),
+            )
+                .prop_map(|(input, index, default)| {
+                    Self::new(input, index, default.map(Box::new)).unwrap()
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<ByIndex>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
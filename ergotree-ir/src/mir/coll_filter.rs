@@ -0,0 +1,126 @@
+//! Filter collection
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Selects all elements of the collection that satisfy the condition.
+/// Returns a new collection consisting of all elements of this collection that satisfy the
+/// given `condition`. The order of the elements is preserved.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Filter {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Function (lambda) to test each element
+    pub condition: Box<Expr>,
+    /// Collection element type
+    pub elem_tpe: SType,
+}
+
+impl Filter {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(input: Expr, condition: Expr) -> Result<Self, InvalidArgumentError> {
+        let elem_tpe = input.coll_elem_tpe()?;
+        let condition_sfunc = condition.lambda_sfunc(std::slice::from_ref(&elem_tpe))?;
+        if *condition_sfunc.t_range != SType::SBoolean {
+            return Err(InvalidArgumentError(format!(
+                "Expected Filter condition to return SBoolean, got {0:?}",
+                condition_sfunc.t_range
+            )));
+        }
+        Ok(Filter {
+            input: input.into(),
+            condition: condition.into(),
+            elem_tpe,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SColl(self.elem_tpe.clone().into())
+    }
+}
+
+impl HasStaticOpCode for Filter {
+    const OP_CODE: OpCode = OpCode::FILTER;
+}
+
+impl SigmaSerializable for Filter {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input.sigma_serialize(w)?;
+        self.condition.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let condition = Expr::sigma_parse(r)?;
+        Ok(Filter::new(input, condition)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::mir::func_value::FuncArg;
+    use crate::mir::func_value::FuncValue;
+    use crate::mir::val_def::ValId;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Filter {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SColl(SType::SBoolean.into()),
+                    depth: 1,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SBoolean,
+                    depth: 1,
+                }),
+            )
+                .prop_map(|(input, condition_body)| {
+                    let condition = FuncValue::new(
+                        vec![FuncArg {
+                            idx: ValId(1),
+                            tpe: SType::SBoolean,
+                        }],
+                        condition_body,
+                    );
+                    Self::new(input, condition.into()).unwrap()
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Filter>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Fold collection elements
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stuple::STuple;
+use crate::types::stype::SType;
+
+/// Applies a binary operator to a start value and all elements of this collection,
+/// going left to right.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Fold {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Starting value
+    pub zero: Box<Expr>,
+    /// Function (lambda) with one argument, a tuple of (accumulator, element)
+    pub fold_op: Box<Expr>,
+}
+
+impl Fold {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(input: Expr, zero: Expr, fold_op: Expr) -> Result<Self, InvalidArgumentError> {
+        let input_elem_tpe = input.coll_elem_tpe()?;
+        let acc_tpe = zero.tpe();
+        let fold_op_sfunc =
+            fold_op.lambda_sfunc(&[STuple::pair(acc_tpe.clone(), input_elem_tpe).into()])?;
+        if *fold_op_sfunc.t_range != acc_tpe {
+            return Err(InvalidArgumentError(format!(
+                "Fold: expected fold_op to return {0:?}, got {1:?}",
+                acc_tpe, fold_op_sfunc.t_range
+            )));
+        }
+        Ok(Fold {
+            input: input.into(),
+            zero: zero.into(),
+            fold_op: fold_op.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.zero.tpe()
+    }
+}
+
+impl HasStaticOpCode for Fold {
+    const OP_CODE: OpCode = OpCode::FOLD;
+}
+
+impl SigmaSerializable for Fold {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input.sigma_serialize(w)?;
+        self.zero.sigma_serialize(w)?;
+        self.fold_op.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let zero = Expr::sigma_parse(r)?;
+        let fold_op = Expr::sigma_parse(r)?;
+        Ok(Fold::new(input, zero, fold_op)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::mir::func_value::FuncArg;
+    use crate::mir::func_value::FuncValue;
+    use crate::mir::val_def::ValId;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Fold {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SColl(SType::SLong.into()),
+                    depth: 1,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SLong,
+                    depth: 1,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SLong,
+                    depth: 1,
+                }),
+            )
+                .prop_map(|(input, zero, fold_op_body)| {
+                    let fold_op = FuncValue::new(
+                        vec![FuncArg {
+                            idx: ValId(1),
+                            tpe: STuple::pair(SType::SLong, SType::SLong).into(),
+                        }],
+                        fold_op_body,
+                    );
+                    Self::new(input, zero, fold_op.into()).unwrap()
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Fold>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Map collection elements
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::sfunc::SFunc;
+use crate::types::stype::SType;
+
+/// Builds a new collection by applying a function to all elements of this collection.
+/// Returns a new collection of type `Coll[B]` resulting from applying the given function
+/// `mapper` to each element of this collection and collecting the results.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Map {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Function (lambda) to apply to each element
+    pub mapper: Box<Expr>,
+    /// Type signature of the function(mapper)
+    pub mapper_sfunc: SFunc,
+}
+
+impl Map {
+    /// Create new object, returns an error if any of the requirements failed
+    pub fn new(input: Expr, mapper: Expr) -> Result<Self, InvalidArgumentError> {
+        let input_elem_type = input.coll_elem_tpe()?;
+        let mapper_sfunc = mapper.lambda_sfunc(&[input_elem_type])?;
+        Ok(Map {
+            input: input.into(),
+            mapper: mapper.into(),
+            mapper_sfunc,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SColl(self.mapper_sfunc.t_range.clone())
+    }
+
+    /// Type of the element in the resulted collection
+    pub fn out_elem_tpe(&self) -> SType {
+        *self.mapper_sfunc.t_range.clone()
+    }
+}
+
+impl HasStaticOpCode for Map {
+    const OP_CODE: OpCode = OpCode::MAP;
+}
+
+impl SigmaSerializable for Map {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input.sigma_serialize(w)?;
+        self.mapper.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let mapper = Expr::sigma_parse(r)?;
+        Ok(Map::new(input, mapper)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::mir::func_value::FuncArg;
+    use crate::mir::func_value::FuncValue;
+    use crate::mir::val_def::ValId;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Map {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SColl(SType::SBoolean.into()),
+                    depth: 1,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SBoolean,
+                    depth: 1,
+                }),
+            )
+                .prop_map(|(input, mapper_body)| {
+                    let mapper = FuncValue::new(
+                        vec![FuncArg {
+                            idx: ValId(1),
+                            tpe: SType::SBoolean,
+                        }],
+                        mapper_body,
+                    );
+                    Self::new(input, mapper.into()).unwrap()
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Map>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Collection size
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::OneArgOp;
+use super::unary_op::OneArgOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+/// Collection size
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SizeOf {
+    /// Collection
+    pub input: Box<Expr>,
+}
+
+impl SizeOf {
+    /// Create new object, returns an error if the input is not a collection
+    pub fn new(input: Expr) -> Result<Self, InvalidArgumentError> {
+        input.coll_elem_tpe()?;
+        Ok(Self {
+            input: input.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SInt
+    }
+}
+
+impl HasStaticOpCode for SizeOf {
+    const OP_CODE: OpCode = OpCode::SIZE_OF;
+}
+
+impl OneArgOp for SizeOf {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl OneArgOpTryBuild for SizeOf {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        Self::new(input)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for SizeOf {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any_with::<Expr>(ArbExprParams {
+                tpe: SType::SColl(SType::SLong.into()),
+                depth: 1,
+            })
+            .prop_map(|input| Self {
+                input: input.into(),
+            })
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<SizeOf>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_non_coll_input() {
+        assert!(SizeOf::new(1i32.into()).is_err());
+    }
+}
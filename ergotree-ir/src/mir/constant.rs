@@ -2,8 +2,9 @@
 
 use crate::base16_str::Base16Str;
 use crate::bigint256::BigInt256;
-// use crate::chain::ergo_box::ErgoBox;
+use crate::chain::ergo_box::ErgoBox;
 use crate::chain::token::TokenId;
+use crate::mir::avl_tree_data::AvlTreeData;
 use crate::mir::value::CollKind;
 use crate::serialization::SigmaParsingError;
 use crate::serialization::SigmaSerializable;
@@ -26,6 +27,7 @@ use sigma_util::AsVecU8;
 use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::fmt::Formatter;
+use std::sync::Arc;
 
 mod constant_placeholder;
 
@@ -67,6 +69,10 @@ pub enum Literal {
     SigmaProp(Box<SigmaProp>),
     /// GroupElement
     GroupElement(Box<EcPoint>),
+    /// Ergo box
+    CBox(Arc<ErgoBox>),
+    /// AVL tree
+    AvlTree(Box<AvlTreeData>),
     /// Collection
     Coll(CollKind<Literal>),
     /// Option type
@@ -105,6 +111,8 @@ impl std::fmt::Debug for Literal {
             Literal::BigInt(v) => v.fmt(f),
             Literal::SigmaProp(v) => v.fmt(f),
             Literal::GroupElement(v) => v.fmt(f),
+            Literal::CBox(v) => v.fmt(f),
+            Literal::AvlTree(v) => v.fmt(f),
         }
     }
 }
@@ -160,6 +168,8 @@ impl std::fmt::Display for Literal {
             Literal::BigInt(v) => v.fmt(f),
             Literal::SigmaProp(v) => v.fmt(f),
             Literal::GroupElement(v) => v.fmt(f),
+            Literal::CBox(v) => write!(f, "ErgoBox({})", v.box_id()),
+            Literal::AvlTree(v) => write!(f, "AvlTree({:?})", v),
         }
     }
 }
@@ -212,12 +222,30 @@ impl From<SigmaProp> for Literal {
     }
 }
 
+impl From<AvlTreeData> for Literal {
+    fn from(v: AvlTreeData) -> Literal {
+        Literal::AvlTree(Box::new(v))
+    }
+}
+
 impl From<EcPoint> for Literal {
     fn from(v: EcPoint) -> Literal {
         Literal::GroupElement(Box::new(v))
     }
 }
 
+impl From<Arc<ErgoBox>> for Literal {
+    fn from(b: Arc<ErgoBox>) -> Self {
+        Literal::CBox(b)
+    }
+}
+
+impl From<ErgoBox> for Literal {
+    fn from(b: ErgoBox) -> Self {
+        Literal::CBox(Arc::new(b))
+    }
+}
+
 impl From<Vec<u8>> for Literal {
     fn from(v: Vec<u8>) -> Self {
         Literal::Coll(CollKind::NativeColl(NativeColl::CollByte(v.as_vec_i8())))
@@ -327,6 +355,12 @@ impl TryFrom<Value> for Constant {
                     Err("Can't convert Value:Tup element".into())
                 }
             }
+            Value::Lambda(_) => Err("Can't convert from Value::Lambda to Constant".into()),
+            Value::CBox(b) => Ok(Constant::from(b)),
+            Value::AvlTree(a) => Ok(Constant::from(*a)),
+            Value::Context => Err("Can't convert from Value::Context to Constant".into()),
+            Value::Header(_) => Err("Can't convert from Value::Header to Constant".into()),
+            Value::PreHeader(_) => Err("Can't convert from Value::PreHeader to Constant".into()),
         }
     }
 }
@@ -403,6 +437,33 @@ impl From<EcPoint> for Constant {
     }
 }
 
+impl From<Arc<ErgoBox>> for Constant {
+    fn from(b: Arc<ErgoBox>) -> Self {
+        Constant {
+            tpe: SType::SBox,
+            v: b.into(),
+        }
+    }
+}
+
+impl From<AvlTreeData> for Constant {
+    fn from(a: AvlTreeData) -> Self {
+        Constant {
+            tpe: SType::SAvlTree,
+            v: a.into(),
+        }
+    }
+}
+
+impl From<ErgoBox> for Constant {
+    fn from(b: ErgoBox) -> Self {
+        Constant {
+            tpe: SType::SBox,
+            v: b.into(),
+        }
+    }
+}
+
 impl From<Vec<u8>> for Constant {
     fn from(v: Vec<u8>) -> Self {
         Constant {
@@ -614,6 +675,18 @@ impl TryExtractFrom<Literal> for EcPoint {
     }
 }
 
+impl TryExtractFrom<Literal> for AvlTreeData {
+    fn try_extract_from(cv: Literal) -> Result<AvlTreeData, TryExtractFromError> {
+        match cv {
+            Literal::AvlTree(v) => Ok(*v),
+            _ => Err(TryExtractFromError(format!(
+                "expected AvlTreeData, found {:?}",
+                cv
+            ))),
+        }
+    }
+}
+
 impl TryExtractFrom<Literal> for SigmaProp {
     fn try_extract_from(cv: Literal) -> Result<SigmaProp, TryExtractFromError> {
         match cv {
@@ -626,6 +699,18 @@ impl TryExtractFrom<Literal> for SigmaProp {
     }
 }
 
+impl TryExtractFrom<Literal> for Arc<ErgoBox> {
+    fn try_extract_from(cv: Literal) -> Result<Arc<ErgoBox>, TryExtractFromError> {
+        match cv {
+            Literal::CBox(b) => Ok(b),
+            _ => Err(TryExtractFromError(format!(
+                "expected ErgoBox, found {:?}",
+                cv
+            ))),
+        }
+    }
+}
+
 impl<T: TryExtractFrom<Literal> + StoreWrapped> TryExtractFrom<Literal> for Vec<T> {
     fn try_extract_from(c: Literal) -> Result<Self, TryExtractFromError> {
         match c {
@@ -855,6 +940,8 @@ pub(crate) mod arbitrary {
             SType::SBigInt => any::<i64>().prop_map(|v| BigInt256::from(v).into()).boxed(),
             SType::SGroupElement => any::<EcPoint>().prop_map_into().boxed(),
             SType::SSigmaProp => any::<SigmaProp>().prop_map_into().boxed(),
+            SType::SBox => any::<ErgoBox>().prop_map_into().boxed(),
+            SType::SAvlTree => any::<AvlTreeData>().prop_map_into().boxed(),
             // SType::SOption(tpe) =>
             SType::SOption(tpe) => match *tpe {
                 SType::SBoolean => any::<Option<bool>>().prop_map_into().boxed(),
@@ -871,6 +958,15 @@ pub(crate) mod arbitrary {
                 SType::SInt => vec(any::<i32>(), 0..400).prop_map_into().boxed(),
                 SType::SLong => vec(any::<i64>(), 0..400).prop_map_into().boxed(),
                 SType::SSigmaProp => vec(any::<SigmaProp>(), 0..3).prop_map_into().boxed(),
+                SType::SBox => vec(any::<ErgoBox>(), 0..3)
+                    .prop_map(|boxes| {
+                        boxes
+                            .into_iter()
+                            .map(Arc::new)
+                            .collect::<Vec<Arc<ErgoBox>>>()
+                            .into()
+                    })
+                    .boxed(),
                 _ => todo!(),
             },
             // SType::STuple(_) => {}
@@ -1022,6 +1118,16 @@ pub mod tests {
             test_constant_roundtrip(v);
         }
 
+        #[test]
+        fn ergo_box_roundtrip(v in any::<ErgoBox>()) {
+            test_constant_roundtrip(Arc::new(v));
+        }
+
+        #[test]
+        fn avl_tree_data_roundtrip(v in any::<AvlTreeData>()) {
+            test_constant_roundtrip(v);
+        }
+
         #[test]
         fn vec_i8_roundtrip(v in any::<Vec<i8>>()) {
             test_constant_roundtrip(v);
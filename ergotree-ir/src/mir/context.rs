@@ -0,0 +1,60 @@
+//! Context(blockchain state) access
+
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Context object ("CONTEXT" in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Context;
+
+impl Context {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SContext
+    }
+}
+
+impl HasStaticOpCode for Context {
+    const OP_CODE: OpCode = OpCode::CONTEXT;
+}
+
+impl SigmaSerializable for Context {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, _w: &mut W) -> SigmaSerializeResult {
+        Ok(())
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(_r: &mut R) -> Result<Self, SigmaParsingError> {
+        Ok(Context)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::panic)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::mir::expr::Expr;
+    use crate::mir::property_call::PropertyCall;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::scontext;
+
+    #[test]
+    fn ser_roundtrip_context() {
+        let expr: Expr = Context.into();
+        assert_eq!(sigma_serialize_roundtrip(&expr), expr);
+    }
+
+    #[test]
+    fn ser_roundtrip_data_inputs() {
+        let expr: Expr = PropertyCall::new(Context.into(), scontext::DATA_INPUTS_PROPERTY.clone())
+            .unwrap()
+            .into();
+        assert_eq!(sigma_serialize_roundtrip(&expr), expr);
+    }
+}
@@ -0,0 +1,116 @@
+//! Create ProveDhTuple
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Create ProveDhTuple sigma proposition ("proveDHTuple(g, h, u, v)" in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CreateProveDhTuple {
+    /// Generator `g` (SGroupElement)
+    pub g: Box<Expr>,
+    /// Point `h` (SGroupElement)
+    pub h: Box<Expr>,
+    /// Point `u = g^w` (SGroupElement)
+    pub u: Box<Expr>,
+    /// Point `v = h^w` (SGroupElement)
+    pub v: Box<Expr>,
+}
+
+impl CreateProveDhTuple {
+    /// Create new object, returns an error if any of the arguments is not of SGroupElement type
+    pub fn new(g: Expr, h: Expr, u: Expr, v: Expr) -> Result<Self, InvalidArgumentError> {
+        g.check_post_eval_tpe(&SType::SGroupElement)?;
+        h.check_post_eval_tpe(&SType::SGroupElement)?;
+        u.check_post_eval_tpe(&SType::SGroupElement)?;
+        v.check_post_eval_tpe(&SType::SGroupElement)?;
+        Ok(Self {
+            g: g.into(),
+            h: h.into(),
+            u: u.into(),
+            v: v.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SSigmaProp
+    }
+}
+
+impl HasStaticOpCode for CreateProveDhTuple {
+    const OP_CODE: OpCode = OpCode::PROVE_DIFFIE_HELLMAN_TUPLE;
+}
+
+impl SigmaSerializable for CreateProveDhTuple {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.g.sigma_serialize(w)?;
+        self.h.sigma_serialize(w)?;
+        self.u.sigma_serialize(w)?;
+        self.v.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let g = Expr::sigma_parse(r)?;
+        let h = Expr::sigma_parse(r)?;
+        let u = Expr::sigma_parse(r)?;
+        let v = Expr::sigma_parse(r)?;
+        Ok(Self::new(g, h, u, v)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for CreateProveDhTuple {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            let ge = || {
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SGroupElement,
+                    depth: 0,
+                })
+            };
+            (ge(), ge(), ge(), ge())
+                .prop_map(|(g, h, u, v)| Self {
+                    g: g.into(),
+                    h: h.into(),
+                    u: u.into(),
+                    v: v.into(),
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<CreateProveDhTuple>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Extract bytes from context variable and deserialize them into an expression
+
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Extract bytes of the context variable with the given id, deserialize them into an
+/// expression and evaluate it ("executeFromVar[T](id)" in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct DeserializeContext {
+    /// Result type of the deserialized expression
+    pub tpe: SType,
+    /// Context variable id
+    pub id: u8,
+}
+
+impl DeserializeContext {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.tpe.clone()
+    }
+}
+
+impl HasStaticOpCode for DeserializeContext {
+    const OP_CODE: OpCode = OpCode::DESERIALIZE_CONTEXT;
+}
+
+impl SigmaSerializable for DeserializeContext {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.tpe.sigma_serialize(w)?;
+        w.put_u8(self.id)?;
+        Ok(())
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let tpe = SType::sigma_parse(r)?;
+        let id = r.get_u8()?;
+        r.set_deserialize(true);
+        Ok(Self { tpe, id })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for DeserializeContext {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                prop_oneof![
+                    Just(SType::SBoolean),
+                    Just(SType::SInt),
+                    Just(SType::SLong),
+                    Just(SType::SSigmaProp),
+                ],
+                any::<u8>(),
+            )
+                .prop_map(|(tpe, id)| Self { tpe, id })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::mir::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<DeserializeContext>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Extract bytes from SELF box register and deserialize them into an expression
+
+use super::expr::Expr;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Extract bytes of the SELF box register with the given id, deserialize them into an
+/// expression and evaluate it ("executeFromSelfReg[T](id, default)" in ErgoScript).
+/// If the register is empty the default expression is evaluated
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct DeserializeRegister {
+    /// Register number (0-9)
+    pub reg: u8,
+    /// Result type of the deserialized expression
+    pub tpe: SType,
+    /// Default value, evaluated if the register is empty
+    pub default: Option<Box<Expr>>,
+}
+
+impl DeserializeRegister {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.tpe.clone()
+    }
+}
+
+impl HasStaticOpCode for DeserializeRegister {
+    const OP_CODE: OpCode = OpCode::DESERIALIZE_REGISTER;
+}
+
+impl SigmaSerializable for DeserializeRegister {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        w.put_u8(self.reg)?;
+        self.tpe.sigma_serialize(w)?;
+        match &self.default {
+            Some(default) => {
+                w.put_u8(1)?;
+                default.sigma_serialize(w)
+            }
+            None => {
+                w.put_u8(0)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let reg = r.get_u8()?;
+        let tpe = SType::sigma_parse(r)?;
+        let default = match r.get_u8()? {
+            0 => None,
+            _ => Some(Box::new(Expr::sigma_parse(r)?)),
+        };
+        r.set_deserialize(true);
+        Ok(Self { reg, tpe, default })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::option;
+    use proptest::prelude::*;
+
+    impl Arbitrary for DeserializeRegister {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                0..=9u8,
+                option::of(any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SBoolean,
+                    depth: 1,
+                })),
+            )
+                .prop_map(|(reg, default)| Self {
+                    reg,
+                    tpe: SType::SBoolean,
+                    default: default.map(Box::new),
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<DeserializeRegister>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
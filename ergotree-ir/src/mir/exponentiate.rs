@@ -0,0 +1,110 @@
+//! Exponentiate op for GroupElement
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Exponentiate op for GroupElement (`g.exp(k)` in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Exponentiate {
+    /// Group element
+    pub left: Box<Expr>,
+    /// Exponent (non-negative BigInt)
+    pub right: Box<Expr>,
+}
+
+impl Exponentiate {
+    /// Create new object, returns an error if the operands are not of SGroupElement and SBigInt types
+    pub fn new(left: Expr, right: Expr) -> Result<Self, InvalidArgumentError> {
+        left.check_post_eval_tpe(&SType::SGroupElement)?;
+        right.check_post_eval_tpe(&SType::SBigInt)?;
+        Ok(Exponentiate {
+            left: left.into(),
+            right: right.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SGroupElement
+    }
+}
+
+impl HasStaticOpCode for Exponentiate {
+    const OP_CODE: OpCode = OpCode::EXPONENTIATE;
+}
+
+impl SigmaSerializable for Exponentiate {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.left.sigma_serialize(w)?;
+        self.right.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let left = Expr::sigma_parse(r)?;
+        let right = Expr::sigma_parse(r)?;
+        Ok(Self::new(left, right)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Exponentiate {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SGroupElement,
+                    depth: 0,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SBigInt,
+                    depth: 0,
+                }),
+            )
+                .prop_map(|(left, right)| Self {
+                    left: left.into(),
+                    right: right.into(),
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Exponentiate>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_wrong_operand_type() {
+        assert!(Exponentiate::new(1i64.into(), 1i64.into()).is_err());
+    }
+}
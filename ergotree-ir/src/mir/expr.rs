@@ -3,14 +3,75 @@
 use std::convert::TryFrom;
 use std::convert::TryInto;
 
+use crate::source_span::Spanned;
+use crate::types::sfunc::SFunc;
 use crate::types::stype::LiftIntoSType;
 use crate::types::stype::SType;
+use crate::types::type_unify::TypeUnificationError;
 
+use super::apply::Apply;
+use super::atleast::Atleast;
+use super::bin_op::BinOp;
+use super::block::BlockValue;
+use super::bool_to_sigma::BoolToSigmaProp;
+use super::byte_array_to_bigint::ByteArrayToBigInt;
+use super::byte_array_to_long::ByteArrayToLong;
+use super::calc_blake2b256::CalcBlake2b256;
+use super::calc_sha256::CalcSha256;
+use super::coll_append::Append;
+use super::coll_by_index::ByIndex;
+use super::coll_exists::Exists;
+use super::coll_filter::Filter;
+use super::coll_fold::Fold;
+use super::coll_forall::ForAll;
+use super::coll_map::Map;
+use super::coll_size::SizeOf;
+use super::coll_slice::Slice;
 use super::constant::Constant;
 use super::constant::ConstantPlaceholder;
 use super::constant::Literal;
 use super::constant::TryExtractFrom;
 use super::constant::TryExtractFromError;
+use super::context::Context;
+use super::create_prove_dh_tuple::CreateProveDhTuple;
+use super::create_provedlog::CreateProveDlog;
+use super::decode_point::DecodePoint;
+use super::deserialize_context::DeserializeContext;
+use super::deserialize_register::DeserializeRegister;
+use super::downcast::Downcast;
+use super::exponentiate::Exponentiate;
+use super::extract_amount::ExtractAmount;
+use super::extract_bytes::ExtractBytes;
+use super::extract_bytes_with_no_ref::ExtractBytesWithNoRef;
+use super::extract_creation_info::ExtractCreationInfo;
+use super::extract_id::ExtractId;
+use super::extract_reg_as::ExtractRegisterAs;
+use super::extract_script_bytes::ExtractScriptBytes;
+use super::func_value::FuncValue;
+use super::get_var::GetVar;
+use super::global_vars::GlobalVars;
+use super::group_generator::GroupGenerator;
+use super::logical_not::LogicalNot;
+use super::long_to_byte_array::LongToByteArray;
+use super::method_call::MethodCall;
+use super::minus_mod_q::MinusModQ;
+use super::mod_q::ModQ;
+use super::multiply_group::MultiplyGroup;
+use super::negation::Negation;
+use super::option_get::OptionGet;
+use super::option_get_or_else::OptionGetOrElse;
+use super::option_is_defined::OptionIsDefined;
+use super::plus_mod_q::PlusModQ;
+use super::property_call::PropertyCall;
+use super::select_field::SelectField;
+use super::sigma_and::SigmaAnd;
+use super::sigma_or::SigmaOr;
+use super::sigma_prop_bytes::SigmaPropBytes;
+use super::tuple::Tuple;
+use super::upcast::Upcast;
+use super::val_def::ValDef;
+use super::val_use::ValUse;
+use super::xor::Xor;
 
 extern crate derive_more;
 use bounded_vec::BoundedVecOutOfBounds;
@@ -25,6 +86,124 @@ pub enum Expr {
     Const(Constant),
     /// Placeholder for a constant
     ConstPlaceholder(ConstantPlaceholder),
+    /// Binary operation
+    BinOp(Spanned<BinOp>),
+    /// Logical NOT
+    LogicalNot(LogicalNot),
+    /// Block (statements, followed by an expression)
+    BlockValue(Spanned<BlockValue>),
+    /// let-bound expression
+    /// NOTE: Since this node is used to define a variable in a block, it's type is not used
+    /// anywhere and it's value is not an expression
+    ValDef(Spanned<ValDef>),
+    /// Reference to ValDef
+    ValUse(ValUse),
+    /// User-defined function
+    FuncValue(FuncValue),
+    /// Application of function
+    Apply(Spanned<Apply>),
+    /// Collection map op
+    Map(Spanned<Map>),
+    /// Collection filter op
+    Filter(Spanned<Filter>),
+    /// Collection fold op
+    Fold(Spanned<Fold>),
+    /// Tests whether a predicate holds for at least one element of this collection
+    Exists(Spanned<Exists>),
+    /// Tests whether a predicate holds for all elements of this collection
+    ForAll(Spanned<ForAll>),
+    /// Collection, get element by index
+    ByIndex(Spanned<ByIndex>),
+    /// Collection slice
+    Slice(Spanned<Slice>),
+    /// Collection concatenation
+    Append(Spanned<Append>),
+    /// Collection size
+    SizeOf(SizeOf),
+    /// Predefined global variables
+    GlobalVars(GlobalVars),
+    /// Context object
+    Context(Context),
+    /// Box monetary value
+    ExtractAmount(ExtractAmount),
+    /// Serialized guarding script of the box
+    ExtractScriptBytes(ExtractScriptBytes),
+    /// Serialized box bytes
+    ExtractBytes(ExtractBytes),
+    /// Serialized box bytes without transaction id and output index
+    ExtractBytesWithNoRef(ExtractBytesWithNoRef),
+    /// Box id
+    ExtractId(ExtractId),
+    /// Box creation height and transaction id with output index
+    ExtractCreationInfo(ExtractCreationInfo),
+    /// Extract register's value (box.RX properties in ErgoScript)
+    ExtractRegisterAs(Spanned<ExtractRegisterAs>),
+    /// Extract value of variable from context by its id
+    GetVar(Spanned<GetVar>),
+    /// Deserialize and evaluate the expression stored in a context variable
+    DeserializeContext(DeserializeContext),
+    /// Deserialize and evaluate the expression stored in a register of SELF box
+    DeserializeRegister(DeserializeRegister),
+    /// Embedding of Boolean values to SigmaProp
+    BoolToSigmaProp(BoolToSigmaProp),
+    /// AND conjunction for sigma propositions
+    SigmaAnd(SigmaAnd),
+    /// OR conjunction for sigma propositions
+    SigmaOr(SigmaOr),
+    /// THRESHOLD conjunction for sigma propositions
+    Atleast(Atleast),
+    /// Create ProveDlog from PK
+    CreateProveDlog(CreateProveDlog),
+    /// Create ProveDhTuple from 4 points
+    CreateProveDhTuple(CreateProveDhTuple),
+    /// Serialized bytes of a SigmaProp value
+    SigmaPropBytes(SigmaPropBytes),
+    /// Returns the Option's value or error if no value
+    OptionGet(Spanned<OptionGet>),
+    /// Returns the Option's value or the default value if the Option is empty
+    OptionGetOrElse(OptionGetOrElse),
+    /// Returns false if the option is None, true otherwise
+    OptionIsDefined(OptionIsDefined),
+    /// Tuple of elements
+    Tuple(Tuple),
+    /// Select a field of the tuple value
+    SelectField(SelectField),
+    /// Calc Blake2b 256-bit hash
+    CalcBlake2b256(CalcBlake2b256),
+    /// Calc SHA-256 hash
+    CalcSha256(CalcSha256),
+    /// Byte-wise XOR of two byte arrays
+    Xor(Xor),
+    /// Convert Long to byte array
+    LongToByteArray(LongToByteArray),
+    /// Convert byte array to Long
+    ByteArrayToLong(ByteArrayToLong),
+    /// Convert byte array to BigInt
+    ByteArrayToBigInt(ByteArrayToBigInt),
+    /// Exponentiate op for GroupElement
+    Exponentiate(Exponentiate),
+    /// Multiply two GroupElement values
+    MultiplyGroup(MultiplyGroup),
+    /// Decode GroupElement from bytes
+    DecodePoint(DecodePoint),
+    /// Generator of the elliptic curve group
+    GroupGenerator(GroupGenerator),
+    /// Numerical upcast
+    Upcast(Upcast),
+    /// Numerical downcast
+    Downcast(Downcast),
+    /// Modulo operation by the secp256k1 group order
+    ModQ(ModQ),
+    /// Addition modulo the secp256k1 group order
+    PlusModQ(PlusModQ),
+    /// Subtraction modulo the secp256k1 group order
+    MinusModQ(MinusModQ),
+    /// Negation of a numeric value
+    Negation(Negation),
+    /// Invocation of object's property (method without arguments)
+    PropertyCall(Spanned<PropertyCall>),
+    /// Invocation of object's method
+    MethodCall(Spanned<MethodCall>),
 }
 
 impl Expr {
@@ -33,6 +212,115 @@ impl Expr {
         match self {
             Expr::Const(v) => v.tpe.clone(),
             Expr::ConstPlaceholder(v) => v.tpe.clone(),
+            Expr::BinOp(op) => op.expr().tpe(),
+            Expr::LogicalNot(v) => v.tpe(),
+            Expr::BlockValue(v) => v.expr().tpe(),
+            Expr::ValDef(v) => v.expr().tpe(),
+            Expr::ValUse(v) => v.tpe.clone(),
+            Expr::FuncValue(v) => v.tpe(),
+            Expr::Apply(v) => v.expr().tpe(),
+            Expr::Map(v) => v.expr().tpe(),
+            Expr::Filter(v) => v.expr().tpe(),
+            Expr::Fold(v) => v.expr().tpe(),
+            Expr::Exists(v) => v.expr().tpe(),
+            Expr::ForAll(v) => v.expr().tpe(),
+            Expr::ByIndex(v) => v.expr().tpe(),
+            Expr::Slice(v) => v.expr().tpe(),
+            Expr::Append(v) => v.expr().tpe(),
+            Expr::SizeOf(v) => v.tpe(),
+            Expr::GlobalVars(v) => v.tpe(),
+            Expr::Context(v) => v.tpe(),
+            Expr::ExtractAmount(v) => v.tpe(),
+            Expr::ExtractScriptBytes(v) => v.tpe(),
+            Expr::ExtractBytes(v) => v.tpe(),
+            Expr::ExtractBytesWithNoRef(v) => v.tpe(),
+            Expr::ExtractId(v) => v.tpe(),
+            Expr::ExtractCreationInfo(v) => v.tpe(),
+            Expr::ExtractRegisterAs(v) => v.expr().tpe(),
+            Expr::GetVar(v) => v.expr().tpe(),
+            Expr::DeserializeContext(v) => v.tpe(),
+            Expr::DeserializeRegister(v) => v.tpe(),
+            Expr::BoolToSigmaProp(v) => v.tpe(),
+            Expr::SigmaAnd(v) => v.tpe(),
+            Expr::SigmaOr(v) => v.tpe(),
+            Expr::Atleast(v) => v.tpe(),
+            Expr::CreateProveDlog(v) => v.tpe(),
+            Expr::CreateProveDhTuple(v) => v.tpe(),
+            Expr::SigmaPropBytes(v) => v.tpe(),
+            Expr::OptionGet(v) => v.expr().tpe(),
+            Expr::OptionGetOrElse(v) => v.tpe(),
+            Expr::OptionIsDefined(v) => v.tpe(),
+            Expr::Tuple(v) => v.tpe(),
+            Expr::SelectField(v) => v.tpe(),
+            Expr::CalcBlake2b256(v) => v.tpe(),
+            Expr::CalcSha256(v) => v.tpe(),
+            Expr::Xor(v) => v.tpe(),
+            Expr::LongToByteArray(v) => v.tpe(),
+            Expr::ByteArrayToLong(v) => v.tpe(),
+            Expr::ByteArrayToBigInt(v) => v.tpe(),
+            Expr::Exponentiate(v) => v.tpe(),
+            Expr::MultiplyGroup(v) => v.tpe(),
+            Expr::DecodePoint(v) => v.tpe(),
+            Expr::GroupGenerator(v) => v.tpe(),
+            Expr::Upcast(v) => v.tpe(),
+            Expr::Downcast(v) => v.tpe(),
+            Expr::ModQ(v) => v.tpe(),
+            Expr::PlusModQ(v) => v.tpe(),
+            Expr::MinusModQ(v) => v.tpe(),
+            Expr::Negation(v) => v.tpe(),
+            Expr::PropertyCall(v) => v.expr().tpe(),
+            Expr::MethodCall(v) => v.expr().tpe(),
+        }
+    }
+
+    /// Check if the expression type is the expected one
+    pub fn check_post_eval_tpe(
+        &self,
+        expected_tpe: &SType,
+    ) -> Result<(), InvalidExprEvalTypeError> {
+        let expr_tpe = self.tpe();
+        if &expr_tpe == expected_tpe {
+            Ok(())
+        } else {
+            Err(InvalidExprEvalTypeError(format!(
+                "expected: {0:?}, got: {1:?}",
+                expected_tpe, expr_tpe
+            )))
+        }
+    }
+
+    /// Element type of the collection, returns an error if the expression type is not SColl
+    pub fn coll_elem_tpe(&self) -> Result<SType, InvalidArgumentError> {
+        match self.tpe() {
+            SType::SColl(elem_tpe) => Ok(*elem_tpe),
+            tpe => Err(InvalidArgumentError(format!(
+                "expected SColl, got: {0:?}",
+                tpe
+            ))),
+        }
+    }
+
+    /// Type of the value inside the Option, returns an error if the expression type is not
+    /// SOption
+    pub fn option_elem_tpe(&self) -> Result<SType, InvalidArgumentError> {
+        match self.tpe() {
+            SType::SOption(elem_tpe) => Ok(*elem_tpe),
+            tpe => Err(InvalidArgumentError(format!(
+                "expected SOption, got: {0:?}",
+                tpe
+            ))),
+        }
+    }
+
+    /// Signature of the function, returns an error if the expression type is not SFunc with
+    /// the expected argument types
+    pub fn lambda_sfunc(&self, expected_t_dom: &[SType]) -> Result<SFunc, InvalidArgumentError> {
+        match self.tpe() {
+            SType::SFunc(sfunc) if sfunc.t_dom == expected_t_dom => Ok(sfunc),
+            tpe => Err(InvalidArgumentError(format!(
+                "expected SFunc with arguments {0:?}, got: {1:?}",
+                expected_t_dom, tpe
+            ))),
         }
     }
 
@@ -74,6 +362,12 @@ impl From<BoundedVecOutOfBounds> for InvalidArgumentError {
     }
 }
 
+impl From<TypeUnificationError> for InvalidArgumentError {
+    fn from(e: TypeUnificationError) -> Self {
+        InvalidArgumentError(format!("TypeUnificationError: {0}", e))
+    }
+}
+
 impl<T: TryFrom<Expr>> TryExtractFrom<Expr> for T {
     fn try_extract_from(v: Expr) -> Result<Self, TryExtractFromError> {
         let res: Result<Self, TryExtractFromError> = v.clone().try_into().map_err(|_| {
@@ -145,15 +439,67 @@ pub(crate) mod arbitrary {
             SType::SLong => any_with::<Constant>(SType::SColl(Box::new(SType::SLong)).into())
                 .prop_map(Expr::Const)
                 .boxed(),
+            SType::SSigmaProp => {
+                any_with::<Constant>(SType::SColl(Box::new(SType::SSigmaProp)).into())
+                    .prop_map(Expr::Const)
+                    .boxed()
+            }
             _ => todo!("Collection of {0:?} is not yet implemented", elem_tpe),
         }
     }
 
+    fn bool_nested_expr(depth: usize) -> BoxedStrategy<Expr> {
+        prop_oneof![
+            any_with::<BinOp>(ArbExprParams {
+                tpe: SType::SBoolean,
+                depth,
+            })
+            .prop_map_into(),
+            any_with::<LogicalNot>(depth).prop_map_into(),
+        ]
+        .boxed()
+    }
+
+    fn sigma_prop_nested_expr(depth: usize) -> BoxedStrategy<Expr> {
+        prop_oneof![
+            any_with::<BoolToSigmaProp>(depth).prop_map_into(),
+            any_with::<SigmaAnd>(depth).prop_map_into(),
+            any_with::<SigmaOr>(depth).prop_map_into(),
+        ]
+        .boxed()
+    }
+
+    fn numeric_nested_expr(tpe: &SType, depth: usize) -> BoxedStrategy<Expr> {
+        any_with::<BinOp>(ArbExprParams {
+            tpe: tpe.clone(),
+            depth,
+        })
+        .prop_map_into()
+        .boxed()
+    }
+
+    fn nested_expr(tpe: &SType, depth: usize) -> BoxedStrategy<Expr> {
+        match tpe {
+            SType::SBoolean => bool_nested_expr(depth),
+            SType::SSigmaProp => sigma_prop_nested_expr(depth),
+            SType::SByte | SType::SShort | SType::SInt | SType::SLong | SType::SBigInt => {
+                numeric_nested_expr(tpe, depth)
+            }
+            t => constant(t),
+        }
+    }
+
     fn non_nested_expr(tpe: &SType) -> BoxedStrategy<Expr> {
         match tpe {
             SType::SAny => any_non_nested_expr(),
             SType::SBoolean => bool_non_nested_expr(),
             SType::SColl(elem_type) => coll_non_nested_expr(elem_type),
+            SType::SBox => {
+                prop_oneof![Just(GlobalVars::SelfBox.into()), constant(&SType::SBox)].boxed()
+            }
+            SType::SGroupElement => {
+                prop_oneof![Just(GroupGenerator.into()), constant(&SType::SGroupElement)].boxed()
+            }
             t => constant(t),
         }
     }
@@ -163,15 +509,20 @@ pub(crate) mod arbitrary {
         type Strategy = BoxedStrategy<Self>;
 
         fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
-            #[allow(clippy::match_single_binding)]
-            match args.tpe {
-                _ => prop_oneof![
+            if args.depth == 0 {
+                prop_oneof![
                     any_with::<Constant>(args.tpe.clone().into())
                         .prop_map(Expr::Const)
                         .boxed(),
                     non_nested_expr(&args.tpe)
                 ]
-                .boxed(),
+                .boxed()
+            } else {
+                prop_oneof![
+                    nested_expr(&args.tpe, args.depth - 1),
+                    non_nested_expr(&args.tpe)
+                ]
+                .boxed()
             }
         }
     }
@@ -0,0 +1,91 @@
+//! Tuple of box creation height and transaction id with output index
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::OneArgOp;
+use super::unary_op::OneArgOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::types::stuple::STuple;
+use crate::types::stype::SType;
+
+/// Tuple of height when the box was created and transaction id with the box index in the outputs
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractCreationInfo {
+    /// Box
+    pub input: Box<Expr>,
+}
+
+impl ExtractCreationInfo {
+    /// Create new object, returns an error if the input is not a box
+    pub fn new(input: Expr) -> Result<Self, InvalidArgumentError> {
+        input.check_post_eval_tpe(&SType::SBox)?;
+        Ok(ExtractCreationInfo {
+            input: input.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        STuple::pair(SType::SInt, SType::SColl(SType::SByte.into())).into()
+    }
+}
+
+impl HasStaticOpCode for ExtractCreationInfo {
+    const OP_CODE: OpCode = OpCode::EXTRACT_CREATION_INFO;
+}
+
+impl OneArgOp for ExtractCreationInfo {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl OneArgOpTryBuild for ExtractCreationInfo {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        Self::new(input)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for ExtractCreationInfo {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any_with::<Expr>(ArbExprParams {
+                tpe: SType::SBox,
+                depth: 0,
+            })
+            .prop_map(|input| Self {
+                input: input.into(),
+            })
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<ExtractCreationInfo>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Extract register of a box
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Extract register's value (box.RX properties in ErgoScript).
+/// For type param `T` expr evaluates to `Option[T]`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractRegisterAs {
+    /// Box
+    pub input: Box<Expr>,
+    /// Register id to extract value from
+    pub register_id: i8,
+    /// Type of the register value (`T` in `Option[T]`)
+    pub elem_tpe: SType,
+}
+
+impl ExtractRegisterAs {
+    /// Create new object, returns an error if the input is not a box or the expected type is
+    /// not an Option
+    pub fn new(input: Expr, register_id: i8, tpe: SType) -> Result<Self, InvalidArgumentError> {
+        input.check_post_eval_tpe(&SType::SBox)?;
+        let elem_tpe = match tpe {
+            SType::SOption(t) => Ok(*t),
+            _ => Err(InvalidArgumentError(format!(
+                "ExtractRegisterAs: expected tpe to be SOption, got: {0:?}",
+                tpe
+            ))),
+        }?;
+        Ok(ExtractRegisterAs {
+            input: input.into(),
+            register_id,
+            elem_tpe,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SOption(self.elem_tpe.clone().into())
+    }
+}
+
+impl HasStaticOpCode for ExtractRegisterAs {
+    const OP_CODE: OpCode = OpCode::EXTRACT_REGISTER_AS;
+}
+
+impl SigmaSerializable for ExtractRegisterAs {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input.sigma_serialize(w)?;
+        w.put_i8(self.register_id)?;
+        self.elem_tpe.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let register_id = r.get_i8()?;
+        let elem_tpe = SType::sigma_parse(r)?;
+        Ok(ExtractRegisterAs::new(
+            input,
+            register_id,
+            SType::SOption(elem_tpe.into()),
+        )?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for ExtractRegisterAs {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SBox,
+                    depth: 0,
+                }),
+                0..=9i8,
+                prop_oneof![
+                    Just(SType::SInt),
+                    Just(SType::SLong),
+                    Just(SType::SColl(SType::SByte.into())),
+                    Just(SType::SSigmaProp),
+                ],
+            )
+                .prop_map(|(input, register_id, elem_tpe)| Self {
+                    input: input.into(),
+                    register_id,
+                    elem_tpe,
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<ExtractRegisterAs>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_non_option_tpe() {
+        assert!(ExtractRegisterAs::new(
+            crate::mir::global_vars::GlobalVars::SelfBox.into(),
+            4,
+            SType::SInt
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! Lambda function
+
+use std::fmt::Display;
+
+use super::expr::Expr;
+use super::val_def::ValId;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::sfunc::SFunc;
+use crate::types::stype::SType;
+
+#[cfg(feature = "arbitrary")]
+use proptest_derive::Arbitrary;
+
+/// Argument parameter for the user-defined function [`FuncValue`]
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub struct FuncArg {
+    /// Value id (defined with ValDef)
+    pub idx: ValId,
+    /// Value type
+    pub tpe: SType,
+}
+
+impl SigmaSerializable for FuncArg {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.idx.sigma_serialize(w)?;
+        self.tpe.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let idx = ValId::sigma_parse(r)?;
+        let tpe = SType::sigma_parse(r)?;
+        Ok(FuncArg { idx, tpe })
+    }
+}
+
+impl Display for FuncArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v{}: {}", self.idx, self.tpe)
+    }
+}
+
+/// User-defined function
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FuncValue {
+    args: Vec<FuncArg>,
+    body: Box<Expr>,
+    tpe: SType,
+}
+
+impl FuncValue {
+    /// Create new FuncValue
+    pub fn new(args: Vec<FuncArg>, body: Expr) -> Self {
+        let t_dom = args.iter().map(|fa| fa.tpe.clone()).collect::<Vec<SType>>();
+        let t_range = body.tpe();
+        FuncValue {
+            args,
+            body: body.into(),
+            tpe: SFunc::new(t_dom, t_range).into(),
+        }
+    }
+
+    /// Function arguments
+    pub fn args(&self) -> &[FuncArg] {
+        self.args.as_ref()
+    }
+
+    /// Function body
+    pub fn body(&self) -> &Expr {
+        &self.body
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.tpe.clone()
+    }
+}
+
+impl HasStaticOpCode for FuncValue {
+    const OP_CODE: OpCode = OpCode::FUNC_VALUE;
+}
+
+impl SigmaSerializable for FuncValue {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        w.put_usize_as_u32_unwrapped(self.args.len())?;
+        self.args.iter().try_for_each(|a| a.sigma_serialize(w))?;
+        self.body.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let args_count = r.get_u32()?;
+        let mut args = Vec::with_capacity(args_count as usize);
+        for _ in 0..args_count {
+            let arg = FuncArg::sigma_parse(r)?;
+            r.val_def_type_store().insert(arg.idx, arg.tpe.clone());
+            args.push(arg);
+        }
+        let body = Expr::sigma_parse(r)?;
+        Ok(FuncValue::new(args, body))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::collection::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for FuncValue {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                vec(any::<FuncArg>(), 1..3),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SBoolean,
+                    depth: 1,
+                }),
+            )
+                .prop_map(|(args, body)| Self::new(args, body))
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<FuncValue>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
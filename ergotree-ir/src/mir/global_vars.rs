@@ -0,0 +1,83 @@
+//! Global variables
+
+use std::fmt::Display;
+
+use crate::has_opcode::HasOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+#[cfg(feature = "arbitrary")]
+use proptest_derive::Arbitrary;
+
+/// Predefined global variables
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum GlobalVars {
+    /// Tx inputs
+    Inputs,
+    /// Tx outputs
+    Outputs,
+    /// Current blockchain height
+    Height,
+    /// ErgoBox instance, which script is being evaluated
+    SelfBox,
+    /// Encoded public key of the miner who created the block
+    MinerPubKey,
+}
+
+impl GlobalVars {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        match self {
+            GlobalVars::Inputs => SType::SColl(Box::new(SType::SBox)),
+            GlobalVars::Outputs => SType::SColl(Box::new(SType::SBox)),
+            GlobalVars::Height => SType::SInt,
+            GlobalVars::SelfBox => SType::SBox,
+            GlobalVars::MinerPubKey => SType::SColl(Box::new(SType::SByte)),
+        }
+    }
+}
+
+impl HasOpCode for GlobalVars {
+    /// Op code (serialization)
+    fn op_code(&self) -> OpCode {
+        match self {
+            GlobalVars::SelfBox => OpCode::SELF_BOX,
+            GlobalVars::Inputs => OpCode::INPUTS,
+            GlobalVars::Outputs => OpCode::OUTPUTS,
+            GlobalVars::Height => OpCode::HEIGHT,
+            GlobalVars::MinerPubKey => OpCode::MINER_PUBKEY,
+        }
+    }
+}
+
+impl Display for GlobalVars {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GlobalVars::SelfBox => write!(f, "SELF"),
+            GlobalVars::Inputs => write!(f, "INPUTS"),
+            GlobalVars::Outputs => write!(f, "OUTPUTS"),
+            GlobalVars::Height => write!(f, "HEIGHT"),
+            GlobalVars::MinerPubKey => write!(f, "minerPubKey"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::mir::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<GlobalVars>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Generator of the elliptic curve group
+
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Generator of the elliptic curve group (`groupGenerator` in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct GroupGenerator;
+
+impl GroupGenerator {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SGroupElement
+    }
+}
+
+impl HasStaticOpCode for GroupGenerator {
+    const OP_CODE: OpCode = OpCode::GROUP_GENERATOR;
+}
+
+impl SigmaSerializable for GroupGenerator {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, _w: &mut W) -> SigmaSerializeResult {
+        Ok(())
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(_r: &mut R) -> Result<Self, SigmaParsingError> {
+        Ok(GroupGenerator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mir::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = GroupGenerator.into();
+        assert_eq!(sigma_serialize_roundtrip(&expr), expr);
+    }
+}
@@ -0,0 +1,241 @@
+//! Invocation of object's method
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::smethod::MethodId;
+use crate::types::smethod::SMethod;
+use crate::types::stype::SType;
+use crate::types::stype_companion::TypeId;
+
+/// Invocation of object's method (i.e. `g.negate()`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct MethodCall {
+    /// Object on which the method is called
+    pub obj: Box<Expr>,
+    /// Method
+    pub method: SMethod,
+    /// Arguments passed to the method on invocation
+    pub args: Vec<Expr>,
+}
+
+impl MethodCall {
+    /// Create new object with the method signature specialized for the object and argument
+    /// types, returns an error if the object or argument types do not match the method signature
+    pub fn new(obj: Expr, method: SMethod, args: Vec<Expr>) -> Result<Self, InvalidArgumentError> {
+        let method = method.specialize_for(obj.tpe(), args.iter().map(|a| a.tpe()).collect())?;
+        let t_dom = &method.tpe().t_dom;
+        let obj_and_arg_types: Vec<SType> = std::iter::once(&obj)
+            .chain(args.iter())
+            .map(|e| e.tpe())
+            .collect();
+        if t_dom != &obj_and_arg_types {
+            return Err(InvalidArgumentError(format!(
+                "MethodCall: expected object and argument types {0:?}, got {1:?}",
+                t_dom, obj_and_arg_types
+            )));
+        }
+        Ok(Self {
+            obj: obj.into(),
+            method,
+            args,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        *self.method.tpe().t_range.clone()
+    }
+}
+
+impl HasStaticOpCode for MethodCall {
+    const OP_CODE: OpCode = OpCode::METHOD_CALL;
+}
+
+impl SigmaSerializable for MethodCall {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        w.put_u8(self.method.type_id().value())?;
+        w.put_u8(self.method.method_id().value())?;
+        self.obj.sigma_serialize(w)?;
+        w.put_usize_as_u32_unwrapped(self.args.len())?;
+        self.args.iter().try_for_each(|a| a.sigma_serialize(w))
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let type_id = TypeId(r.get_u8()?);
+        let method_id = MethodId(r.get_u8()?);
+        let obj = Expr::sigma_parse(r)?;
+        let args_count = r.get_u32()?;
+        // args count is untrusted, do not preallocate
+        let mut args = Vec::new();
+        for _ in 0..args_count {
+            args.push(Expr::sigma_parse(r)?);
+        }
+        let method = SMethod::from_ids(type_id, method_id)?;
+        Ok(Self::new(obj, method, args)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::types::scoll;
+    use crate::types::sgroup_elem;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    fn arb_expr(tpe: SType) -> BoxedStrategy<Expr> {
+        any_with::<Expr>(ArbExprParams { tpe, depth: 0 })
+    }
+
+    fn coll_int() -> SType {
+        SType::SColl(SType::SInt.into())
+    }
+
+    impl Arbitrary for MethodCall {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                arb_expr(SType::SGroupElement).prop_map(|obj| (
+                    obj,
+                    sgroup_elem::NEGATE_METHOD.clone(),
+                    vec![]
+                )),
+                (
+                    arb_expr(coll_int()),
+                    arb_expr(SType::SInt),
+                    arb_expr(SType::SInt)
+                )
+                    .prop_map(|(obj, index, default)| (
+                        obj,
+                        scoll::GET_OR_ELSE_METHOD.clone(),
+                        vec![index, default]
+                    )),
+                (
+                    arb_expr(coll_int()),
+                    arb_expr(SType::SInt),
+                    arb_expr(SType::SInt)
+                )
+                    .prop_map(|(obj, index, value)| (
+                        obj,
+                        scoll::UPDATED_METHOD.clone(),
+                        vec![index, value]
+                    )),
+                (
+                    arb_expr(coll_int()),
+                    arb_expr(SType::SInt),
+                    arb_expr(SType::SInt)
+                )
+                    .prop_map(|(obj, elem, from)| (
+                        obj,
+                        scoll::INDEX_OF_METHOD.clone(),
+                        vec![elem, from]
+                    )),
+                (
+                    arb_expr(coll_int()),
+                    arb_expr(SType::SInt),
+                    arb_expr(coll_int()),
+                    arb_expr(SType::SInt)
+                )
+                    .prop_map(|(obj, from, patch, replaced)| (
+                        obj,
+                        scoll::PATCH_METHOD.clone(),
+                        vec![from, patch, replaced]
+                    )),
+                (
+                    arb_expr(coll_int()),
+                    arb_expr(coll_int()),
+                    arb_expr(coll_int())
+                )
+                    .prop_map(|(obj, indexes, values)| (
+                        obj,
+                        scoll::UPDATE_MANY_METHOD.clone(),
+                        vec![indexes, values]
+                    )),
+                (
+                    arb_expr(coll_int()),
+                    arb_expr(SType::SColl(SType::SBoolean.into()))
+                )
+                    .prop_map(|(obj, other)| (
+                        obj,
+                        scoll::ZIP_METHOD.clone(),
+                        vec![other]
+                    )),
+            ]
+            .prop_map(|(obj, method, args)| Self::new(obj, method, args).unwrap())
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::mir::group_generator::GroupGenerator;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::scoll;
+    use crate::types::sgroup_elem;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<MethodCall>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn parse_huge_args_count() {
+        let mut bytes = vec![
+            sgroup_elem::TYPE_ID.value(),
+            sgroup_elem::NEGATE_METHOD_ID.value(),
+        ];
+        bytes.append(&mut Expr::from(GroupGenerator).sigma_serialize_bytes().unwrap());
+        // VLQ-encoded u32::MAX args count followed by nothing
+        bytes.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0x0f]);
+        assert!(MethodCall::sigma_parse_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn new_specializes_generic_method() {
+        let coll: Expr = vec![1i64, 2].into();
+        let mc = MethodCall::new(
+            coll,
+            scoll::GET_OR_ELSE_METHOD.clone(),
+            vec![0i32.into(), 1i64.into()],
+        )
+        .unwrap();
+        assert_eq!(mc.tpe(), SType::SLong);
+        let coll: Expr = vec![1i64, 2].into();
+        assert!(MethodCall::new(
+            coll,
+            scoll::GET_OR_ELSE_METHOD.clone(),
+            vec![0i32.into(), 1i32.into()],
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn new_wrong_types() {
+        let method = sgroup_elem::NEGATE_METHOD.clone();
+        assert!(MethodCall::new(1i32.into(), method.clone(), vec![]).is_err());
+        assert!(MethodCall::new(GroupGenerator.into(), method.clone(), vec![1i32.into()]).is_err());
+        assert!(MethodCall::new(GroupGenerator.into(), method, vec![]).is_ok());
+    }
+}
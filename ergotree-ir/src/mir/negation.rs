@@ -0,0 +1,105 @@
+//! Negation of a numeric value
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::OneArgOp;
+use super::unary_op::OneArgOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+/// Negation of a numeric value (`-x` in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Negation {
+    /// Input expr of numeric type
+    pub input: Box<Expr>,
+}
+
+impl Negation {
+    /// Create new object, returns an error if the input is not of numeric type
+    pub fn new(input: Expr) -> Result<Self, InvalidArgumentError> {
+        let input_tpe = input.tpe();
+        if !input_tpe.is_numeric() {
+            return Err(InvalidArgumentError(format!(
+                "Negation: expected input of numeric type, got: {0:?}",
+                input_tpe
+            )));
+        }
+        Ok(Self {
+            input: input.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.input.tpe()
+    }
+}
+
+impl HasStaticOpCode for Negation {
+    const OP_CODE: OpCode = OpCode::NEGATION;
+}
+
+impl OneArgOp for Negation {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl OneArgOpTryBuild for Negation {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        Self::new(input)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Negation {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                Just(SType::SByte),
+                Just(SType::SShort),
+                Just(SType::SInt),
+                Just(SType::SLong),
+                Just(SType::SBigInt),
+            ]
+            .prop_flat_map(|tpe| any_with::<Expr>(ArbExprParams { tpe, depth: 0 }))
+            .prop_map(|input| Self {
+                input: input.into(),
+            })
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Negation>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_non_numeric() {
+        assert!(Negation::new(true.into()).is_err());
+    }
+}
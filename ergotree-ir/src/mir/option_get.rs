@@ -0,0 +1,94 @@
+//! Returns the Option's value or error if no value
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::OneArgOp;
+use super::unary_op::OneArgOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+/// Returns the Option's value or error if no value
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct OptionGet {
+    /// Object of SOption type
+    pub input: Box<Expr>,
+    /// Type of the Option's value
+    pub elem_tpe: SType,
+}
+
+impl OptionGet {
+    /// Create new object, returns an error if the input is not of SOption type
+    pub fn new(input: Expr) -> Result<Self, InvalidArgumentError> {
+        let elem_tpe = input.option_elem_tpe()?;
+        Ok(OptionGet {
+            input: input.into(),
+            elem_tpe,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.elem_tpe.clone()
+    }
+}
+
+impl HasStaticOpCode for OptionGet {
+    const OP_CODE: OpCode = OpCode::OPTION_GET;
+}
+
+impl OneArgOp for OptionGet {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl OneArgOpTryBuild for OptionGet {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        Self::new(input)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::get_var::GetVar;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for OptionGet {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any::<GetVar>()
+                .prop_map(|get_var| Self::new(get_var.into()).unwrap())
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<OptionGet>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_non_option_input() {
+        assert!(OptionGet::new(1i32.into()).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+//! Returns the Option's value or the default value if the Option is empty
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Returns the Option's value or the default value if the Option is empty
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct OptionGetOrElse {
+    /// Object of SOption type
+    pub input: Box<Expr>,
+    /// Default value, returned if the Option is empty
+    pub default: Box<Expr>,
+    /// Type of the Option's value
+    pub elem_tpe: SType,
+}
+
+impl OptionGetOrElse {
+    /// Create new object, returns an error if the input is not of SOption type or the default
+    /// value type does not match the Option's value type
+    pub fn new(input: Expr, default: Expr) -> Result<Self, InvalidArgumentError> {
+        let elem_tpe = input.option_elem_tpe()?;
+        default.check_post_eval_tpe(&elem_tpe)?;
+        Ok(OptionGetOrElse {
+            input: input.into(),
+            default: default.into(),
+            elem_tpe,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.elem_tpe.clone()
+    }
+}
+
+impl HasStaticOpCode for OptionGetOrElse {
+    const OP_CODE: OpCode = OpCode::OPTION_GET_OR_ELSE;
+}
+
+impl SigmaSerializable for OptionGetOrElse {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input.sigma_serialize(w)?;
+        self.default.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let default = Expr::sigma_parse(r)?;
+        Ok(Self::new(input, default)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::mir::get_var::GetVar;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for OptionGetOrElse {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any::<GetVar>()
+                .prop_flat_map(|get_var| {
+                    (
+                        Just(get_var.clone()),
+                        any_with::<Expr>(ArbExprParams {
+                            tpe: get_var.var_tpe,
+                            depth: 0,
+                        }),
+                    )
+                })
+                .prop_map(|(get_var, default)| Self::new(get_var.into(), default).unwrap())
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::mir::get_var::GetVar;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<OptionGetOrElse>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_wrong_default_type() {
+        let get_var: Expr = GetVar {
+            var_id: 1,
+            var_tpe: SType::SInt,
+        }
+        .into();
+        assert!(OptionGetOrElse::new(get_var, 1i64.into()).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+//! Returns false if the option is None, true otherwise
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use super::unary_op::OneArgOp;
+use super::unary_op::OneArgOpTryBuild;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+/// Returns false if the option is None, true otherwise
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct OptionIsDefined {
+    /// Object of SOption type
+    pub input: Box<Expr>,
+}
+
+impl OptionIsDefined {
+    /// Create new object, returns an error if the input is not of SOption type
+    pub fn new(input: Expr) -> Result<Self, InvalidArgumentError> {
+        input.option_elem_tpe()?;
+        Ok(OptionIsDefined {
+            input: input.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SBoolean
+    }
+}
+
+impl HasStaticOpCode for OptionIsDefined {
+    const OP_CODE: OpCode = OpCode::OPTION_IS_DEFINED;
+}
+
+impl OneArgOp for OptionIsDefined {
+    fn input(&self) -> &Expr {
+        &self.input
+    }
+}
+
+impl OneArgOpTryBuild for OptionIsDefined {
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError> {
+        Self::new(input)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::get_var::GetVar;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for OptionIsDefined {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any::<GetVar>()
+                .prop_map(|get_var| Self {
+                    input: Box::new(get_var.into()),
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<OptionIsDefined>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_non_option_input() {
+        assert!(OptionIsDefined::new(1i32.into()).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+//! Addition modulo the secp256k1 group order
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Addition of two BigInt values modulo the secp256k1 group order (`x.plusModQ(y)` in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct PlusModQ {
+    /// Left operand (BigInt)
+    pub left: Box<Expr>,
+    /// Right operand (BigInt)
+    pub right: Box<Expr>,
+}
+
+impl PlusModQ {
+    /// Create new object, returns an error if any of the operands is not of SBigInt type
+    pub fn new(left: Expr, right: Expr) -> Result<Self, InvalidArgumentError> {
+        left.check_post_eval_tpe(&SType::SBigInt)?;
+        right.check_post_eval_tpe(&SType::SBigInt)?;
+        Ok(PlusModQ {
+            left: left.into(),
+            right: right.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SBigInt
+    }
+}
+
+impl HasStaticOpCode for PlusModQ {
+    const OP_CODE: OpCode = OpCode::PLUS_MOD_Q;
+}
+
+impl SigmaSerializable for PlusModQ {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.left.sigma_serialize(w)?;
+        self.right.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let left = Expr::sigma_parse(r)?;
+        let right = Expr::sigma_parse(r)?;
+        Ok(Self::new(left, right)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for PlusModQ {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            let bigint_expr = || {
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SBigInt,
+                    depth: 0,
+                })
+            };
+            (bigint_expr(), bigint_expr())
+                .prop_map(|(left, right)| Self {
+                    left: left.into(),
+                    right: right.into(),
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<PlusModQ>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_wrong_operand_type() {
+        assert!(PlusModQ::new(1i64.into(), 1i64.into()).is_err());
+    }
+}
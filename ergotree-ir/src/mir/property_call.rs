@@ -0,0 +1,145 @@
+//! Invocation of object's property (method without arguments)
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::smethod::MethodId;
+use crate::types::smethod::SMethod;
+use crate::types::stype::SType;
+use crate::types::stype_companion::TypeId;
+
+/// Invocation of object's property (i.e. `CONTEXT.dataInputs`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct PropertyCall {
+    /// Object on which the property is called
+    pub obj: Box<Expr>,
+    /// Property (method without arguments)
+    pub method: SMethod,
+}
+
+impl PropertyCall {
+    /// Create new object with the method signature specialized for the object type, returns an
+    /// error if the object type does not match the method signature or the method has arguments
+    pub fn new(obj: Expr, method: SMethod) -> Result<Self, InvalidArgumentError> {
+        if method.tpe().t_dom.len() != 1 {
+            return Err(InvalidArgumentError(format!(
+                "PropertyCall: expected method without arguments, got {0:?}",
+                method
+            )));
+        }
+        let method = method.specialize_for(obj.tpe(), Vec::new())?;
+        obj.check_post_eval_tpe(&method.tpe().t_dom[0])?;
+        Ok(Self {
+            obj: obj.into(),
+            method,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        *self.method.tpe().t_range.clone()
+    }
+}
+
+impl HasStaticOpCode for PropertyCall {
+    const OP_CODE: OpCode = OpCode::PROPERTY_CALL;
+}
+
+impl SigmaSerializable for PropertyCall {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        w.put_u8(self.method.type_id().value())?;
+        w.put_u8(self.method.method_id().value())?;
+        self.obj.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let type_id = TypeId(r.get_u8()?);
+        let method_id = MethodId(r.get_u8()?);
+        let obj = Expr::sigma_parse(r)?;
+        let method = SMethod::from_ids(type_id, method_id)?;
+        Ok(Self::new(obj, method)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::context::Context;
+    use crate::mir::expr::arbitrary::ArbExprParams;
+    use crate::types::scoll;
+    use crate::types::scontext;
+    use crate::types::sgroup_elem;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for PropertyCall {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                prop_oneof![
+                    Just(scontext::DATA_INPUTS_PROPERTY.clone()),
+                    Just(scontext::INPUTS_PROPERTY.clone()),
+                    Just(scontext::OUTPUTS_PROPERTY.clone()),
+                    Just(scontext::HEIGHT_PROPERTY.clone()),
+                    Just(scontext::SELF_PROPERTY.clone()),
+                    Just(scontext::SELF_BOX_INDEX_PROPERTY.clone()),
+                    Just(scontext::MINER_PUBKEY_PROPERTY.clone()),
+                ]
+                .prop_map(|method| Self {
+                    obj: Box::new(Context.into()),
+                    method,
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SGroupElement,
+                    depth: 0,
+                })
+                .prop_map(|obj| Self {
+                    obj: obj.into(),
+                    method: sgroup_elem::GET_ENCODED_METHOD.clone(),
+                }),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SColl(SType::SByte.into()),
+                    depth: 0,
+                })
+                .prop_map(|obj| Self::new(obj, scoll::INDICES_METHOD.clone()).unwrap()),
+            ]
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::mir::context::Context;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::scontext;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<PropertyCall>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_wrong_obj_type() {
+        assert!(PropertyCall::new(1i32.into(), scontext::DATA_INPUTS_PROPERTY.clone()).is_err());
+        assert!(PropertyCall::new(Context.into(), scontext::DATA_INPUTS_PROPERTY.clone()).is_ok());
+    }
+}
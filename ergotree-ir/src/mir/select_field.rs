@@ -0,0 +1,174 @@
+//! Select a field of the tuple value
+
+use std::convert::TryFrom;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Tuple field access index (1..=255)
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct TupleFieldIndex(u8);
+
+/// Error for tuple index being out of bounds (1..=255)
+#[derive(Debug)]
+pub struct TupleFieldIndexOutBounds;
+
+impl TryFrom<u8> for TupleFieldIndex {
+    type Error = TupleFieldIndexOutBounds;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value >= 1 {
+            Ok(TupleFieldIndex(value))
+        } else {
+            Err(TupleFieldIndexOutBounds)
+        }
+    }
+}
+
+impl From<TupleFieldIndex> for u8 {
+    fn from(v: TupleFieldIndex) -> Self {
+        v.0
+    }
+}
+
+impl TupleFieldIndex {
+    /// Returns a zero-based index
+    pub fn zero_based(&self) -> usize {
+        (self.0 - 1) as usize
+    }
+}
+
+/// Select a field of the tuple value (`t._1` in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SelectField {
+    /// Tuple value
+    pub input: Box<Expr>,
+    /// 1-based tuple field index (input._1 has field_index of 1)
+    pub field_index: TupleFieldIndex,
+    /// Field type
+    pub field_tpe: SType,
+}
+
+impl SelectField {
+    /// Create new object, returns an error if the input is not a tuple or the field index is
+    /// out of the tuple bounds
+    pub fn new(input: Expr, field_index: TupleFieldIndex) -> Result<Self, InvalidArgumentError> {
+        match input.tpe() {
+            SType::STuple(t) => match t.items.get(field_index.zero_based()) {
+                Some(field_tpe) => Ok(SelectField {
+                    input: Box::new(input),
+                    field_index,
+                    field_tpe: field_tpe.clone(),
+                }),
+                None => Err(InvalidArgumentError(format!(
+                    "SelectField field index is out of bounds. {0:?}, tuple type: {1:?}",
+                    field_index, t
+                ))),
+            },
+            tpe => Err(InvalidArgumentError(format!(
+                "SelectField expected tuple type, got {0:?}",
+                tpe
+            ))),
+        }
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.field_tpe.clone()
+    }
+}
+
+impl HasStaticOpCode for SelectField {
+    const OP_CODE: OpCode = OpCode::SELECT_FIELD;
+}
+
+impl SigmaSerializable for SelectField {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input.sigma_serialize(w)?;
+        w.put_u8(self.field_index.into())?;
+        Ok(())
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let field_index = r.get_u8()?;
+        let field_index = TupleFieldIndex::try_from(field_index).map_err(|_| {
+            SigmaParsingError::ValueOutOfBounds(format!(
+                "invalid tuple field index: {0}",
+                field_index
+            ))
+        })?;
+        Ok(SelectField::new(input, field_index)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::tuple::Tuple;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for SelectField {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            any::<Tuple>()
+                .prop_flat_map(|tuple| {
+                    let len = tuple.items.len() as u8;
+                    (Just(tuple), 1..=len)
+                })
+                .prop_map(|(tuple, index)| {
+                    Self::new(tuple.into(), TupleFieldIndex::try_from(index).unwrap()).unwrap()
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::mir::tuple::Tuple;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<SelectField>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn field_index_zero() {
+        assert!(TupleFieldIndex::try_from(0u8).is_err());
+    }
+
+    #[test]
+    fn new_index_out_of_bounds() {
+        let tuple: Expr = Tuple::new(vec![1i32.into(), 2i64.into()]).unwrap().into();
+        assert!(SelectField::new(tuple, TupleFieldIndex::try_from(3u8).unwrap()).is_err());
+    }
+
+    #[test]
+    fn new_non_tuple_input() {
+        assert!(SelectField::new(1i32.into(), TupleFieldIndex::try_from(1u8).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+//! AND conjunction for sigma propositions
+
+use std::convert::TryInto;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::sigma_protocol::sigma_boolean::SigmaConjectureItems;
+use crate::types::stype::SType;
+
+/// AND conjunction for sigma propositions ("&&" on SigmaProp values in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SigmaAnd {
+    /// Items of the conjunction (of SSigmaProp type)
+    pub items: SigmaConjectureItems<Expr>,
+}
+
+impl SigmaAnd {
+    /// Create new object, returns an error if any of the items is not of SSigmaProp type
+    pub fn new(items: Vec<Expr>) -> Result<Self, InvalidArgumentError> {
+        for item in &items {
+            item.check_post_eval_tpe(&SType::SSigmaProp)?;
+        }
+        Ok(Self {
+            items: items.try_into()?,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SSigmaProp
+    }
+}
+
+impl HasStaticOpCode for SigmaAnd {
+    const OP_CODE: OpCode = OpCode::SIGMA_AND;
+}
+
+impl SigmaSerializable for SigmaAnd {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        w.put_usize_as_u32_unwrapped(self.items.len())?;
+        self.items.iter().try_for_each(|i| i.sigma_serialize(w))
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let items_count = r.get_u32()?;
+        let mut items = Vec::with_capacity(items_count as usize);
+        for _ in 0..items_count {
+            items.push(Expr::sigma_parse(r)?);
+        }
+        Ok(Self::new(items)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    impl Arbitrary for SigmaAnd {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = usize;
+
+        fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+            vec(
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SSigmaProp,
+                    depth: args,
+                }),
+                2..5,
+            )
+            .prop_map(|items| Self::new(items).unwrap())
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any_with::<SigmaAnd>(1)) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_wrong_item_type() {
+        assert!(SigmaAnd::new(vec![true.into(), false.into()]).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+//! Tuple of elements
+
+use std::convert::TryInto;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stuple::STuple;
+use crate::types::stuple::TupleItems;
+use crate::types::stype::SType;
+
+/// Tuple of elements
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Tuple {
+    /// Tuple items
+    pub items: TupleItems<Expr>,
+}
+
+impl Tuple {
+    /// Create new object, returns an error if the number of items is not in 2..=255
+    pub fn new(items: Vec<Expr>) -> Result<Self, InvalidArgumentError> {
+        Ok(Tuple {
+            items: items.try_into()?,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::STuple(STuple {
+            items: self.items.clone().mapped(|e| e.tpe()),
+        })
+    }
+}
+
+impl HasStaticOpCode for Tuple {
+    const OP_CODE: OpCode = OpCode::TUPLE;
+}
+
+impl SigmaSerializable for Tuple {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        w.put_u8(self.items.len() as u8)?;
+        self.items.iter().try_for_each(|i| i.sigma_serialize(w))
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let items_count = r.get_u8()?;
+        let mut items = Vec::with_capacity(items_count as usize);
+        for _ in 0..items_count {
+            items.push(Expr::sigma_parse(r)?);
+        }
+        Ok(Self::new(items)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::unwrap_used)]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Tuple {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            vec(
+                prop_oneof![
+                    Just(SType::SBoolean),
+                    Just(SType::SInt),
+                    Just(SType::SLong),
+                    Just(SType::SColl(SType::SByte.into())),
+                ]
+                .prop_flat_map(|tpe| any_with::<Expr>(ArbExprParams { tpe, depth: 0 })),
+                2..5,
+            )
+            .prop_map(|items| Self::new(items).unwrap())
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Tuple>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_single_item() {
+        assert!(Tuple::new(vec![1i32.into()]).is_err());
+    }
+}
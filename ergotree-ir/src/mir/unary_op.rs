@@ -0,0 +1,34 @@
+//! Unary operations
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasOpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+
+/// Operation with one argument
+pub trait OneArgOp {
+    /// Argument of the operation
+    fn input(&self) -> &Expr;
+}
+
+/// Construct the operation from the argument, checking the argument type
+pub trait OneArgOpTryBuild: Sized {
+    /// Create the operation from the argument
+    fn try_build(input: Expr) -> Result<Self, InvalidArgumentError>;
+}
+
+impl<T: OneArgOp + HasOpCode + OneArgOpTryBuild> SigmaSerializable for T {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input().sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let op = Self::try_build(input)?;
+        Ok(op)
+    }
+}
@@ -0,0 +1,127 @@
+//! Numerical upcast
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Numerical upcast (`x.toLong` for `x: Int` in ErgoScript)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Upcast {
+    /// Numerical value to cast
+    pub input: Box<Expr>,
+    /// Target type
+    pub tpe: SType,
+}
+
+impl Upcast {
+    /// Create new object, returns an error if the input or target type is not numeric
+    pub fn new(input: Expr, target_tpe: SType) -> Result<Self, InvalidArgumentError> {
+        let input_tpe = input.tpe();
+        if !input_tpe.is_numeric() {
+            return Err(InvalidArgumentError(format!(
+                "Upcast: expected input of numeric type, got: {0:?}",
+                input_tpe
+            )));
+        }
+        if !target_tpe.is_numeric() {
+            return Err(InvalidArgumentError(format!(
+                "Upcast: expected target type to be numeric, got: {0:?}",
+                target_tpe
+            )));
+        }
+        Ok(Upcast {
+            input: input.into(),
+            tpe: target_tpe,
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.tpe.clone()
+    }
+}
+
+impl HasStaticOpCode for Upcast {
+    const OP_CODE: OpCode = OpCode::UPCAST;
+}
+
+impl SigmaSerializable for Upcast {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.input.sigma_serialize(w)?;
+        self.tpe.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let input = Expr::sigma_parse(r)?;
+        let tpe = SType::sigma_parse(r)?;
+        Ok(Upcast::new(input, tpe)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Upcast {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                Just((SType::SByte, SType::SShort)),
+                Just((SType::SShort, SType::SInt)),
+                Just((SType::SInt, SType::SLong)),
+                Just((SType::SLong, SType::SBigInt)),
+            ]
+            .prop_flat_map(|(input_tpe, tpe)| {
+                (
+                    any_with::<Expr>(ArbExprParams {
+                        tpe: input_tpe,
+                        depth: 0,
+                    }),
+                    Just(tpe),
+                )
+            })
+            .prop_map(|(input, tpe)| Self {
+                input: input.into(),
+                tpe,
+            })
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Upcast>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_non_numeric() {
+        assert!(Upcast::new(true.into(), SType::SLong).is_err());
+        assert!(Upcast::new(1i32.into(), SType::SBoolean).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! Variable definition
+
+use std::fmt::Display;
+
+use super::expr::Expr;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+extern crate derive_more;
+use derive_more::From;
+
+#[cfg(feature = "arbitrary")]
+use proptest_derive::Arbitrary;
+
+/// Variable id
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, PartialOrd, Ord, From)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub struct ValId(pub u32);
+
+impl ValId {
+    pub(crate) fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        w.put_u32(self.0)?;
+        Ok(())
+    }
+
+    pub(crate) fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let id = r.get_u32()?;
+        Ok(ValId(id))
+    }
+}
+
+impl Display for ValId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// IR node for let-bound expressions `let x = rhs` which is ValDef.
+/// These nodes are used to represent ErgoTrees after common sub-expression elimination.
+/// This representation is more compact in serialized form.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ValDef {
+    /// unique identifier of the variable in the current scope
+    pub id: ValId,
+    /// expression the variable is bound to
+    pub rhs: Box<Expr>,
+}
+
+impl ValDef {
+    /// Type
+    pub fn tpe(&self) -> SType {
+        self.rhs.tpe()
+    }
+}
+
+impl HasStaticOpCode for ValDef {
+    const OP_CODE: OpCode = OpCode::VAL_DEF;
+}
+
+impl SigmaSerializable for ValDef {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.id.sigma_serialize(w)?;
+        self.rhs.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let id = ValId::sigma_parse(r)?;
+        let rhs = Expr::sigma_parse(r)?;
+        r.val_def_type_store().insert(id, rhs.tpe());
+        Ok(ValDef {
+            id,
+            rhs: Box::new(rhs),
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for ValDef {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any::<ValId>(),
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SInt,
+                    depth: 1,
+                }),
+            )
+                .prop_map(|(id, rhs)| Self {
+                    id,
+                    rhs: Box::new(rhs),
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<ValDef>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
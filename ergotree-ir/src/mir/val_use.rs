@@ -0,0 +1,40 @@
+//! Variable reference
+
+use super::val_def::ValId;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Special node which represents a reference to ValDef in was introduced as result of CSE.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ValUse {
+    /// Variable id
+    pub val_id: ValId,
+    /// Variable type
+    pub tpe: SType,
+}
+
+impl HasStaticOpCode for ValUse {
+    const OP_CODE: OpCode = OpCode::VAL_USE;
+}
+
+impl SigmaSerializable for ValUse {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.val_id.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let val_id = ValId::sigma_parse(r)?;
+        let tpe = r
+            .val_def_type_store()
+            .get(&val_id)
+            .ok_or(SigmaParsingError::ValDefIdNotFound(val_id))?
+            .clone();
+        Ok(ValUse { val_id, tpe })
+    }
+}
@@ -9,16 +9,22 @@ use sigma_util::AsVecI8;
 
 use crate::bigint256::BigInt256;
 use crate::chain::ergo_box::ErgoBox;
+use crate::mir::avl_tree_data::AvlTreeData;
 use crate::sigma_protocol::sigma_boolean::SigmaProp;
 use crate::types::stuple::TupleItems;
 use crate::types::stype::LiftIntoSType;
 use crate::types::stype::SType;
 use ergo_chain_types::EcPoint;
+use ergo_chain_types::Header;
+use ergo_chain_types::PreHeader;
 
 use super::constant::Literal;
 use super::constant::TryExtractFrom;
 use super::constant::TryExtractFromError;
 use super::constant::TryExtractInto;
+use super::expr::Expr;
+use super::func_value::FuncArg;
+use super::val_def::ValId;
 
 extern crate derive_more;
 use derive_more::From;
@@ -40,7 +46,7 @@ impl NativeColl {
 }
 
 /// Collection elements
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub enum CollKind<T> {
     /// Collection elements stored as a vector of Rust values
     NativeColl(NativeColl),
@@ -53,6 +59,52 @@ pub enum CollKind<T> {
     },
 }
 
+/// Collections are compared structurally (element type and items), regardless of how the
+/// elements are stored
+impl<T> PartialEq for CollKind<T>
+where
+    T: PartialEq + From<i8>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CollKind::NativeColl(l), CollKind::NativeColl(r)) => l == r,
+            (
+                CollKind::WrappedColl {
+                    elem_tpe: l_tpe,
+                    items: l_items,
+                },
+                CollKind::WrappedColl {
+                    elem_tpe: r_tpe,
+                    items: r_items,
+                },
+            ) => l_tpe == r_tpe && l_items == r_items,
+            (
+                CollKind::NativeColl(NativeColl::CollByte(bytes)),
+                CollKind::WrappedColl {
+                    elem_tpe: SType::SByte,
+                    items,
+                },
+            )
+            | (
+                CollKind::WrappedColl {
+                    elem_tpe: SType::SByte,
+                    items,
+                },
+                CollKind::NativeColl(NativeColl::CollByte(bytes)),
+            ) => {
+                bytes.len() == items.len()
+                    && bytes
+                        .iter()
+                        .zip(items)
+                        .all(|(b, item)| &T::from(*b) == item)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T> Eq for CollKind<T> where T: Eq + From<i8> {}
+
 impl<T> CollKind<T>
 where
     T: PartialEq + Eq + Clone,
@@ -105,6 +157,19 @@ where
         }
     }
 
+    /// Number of items in the collection
+    pub fn len(&self) -> usize {
+        match self {
+            CollKind::NativeColl(NativeColl::CollByte(coll_byte)) => coll_byte.len(),
+            CollKind::WrappedColl { items, .. } => items.len(),
+        }
+    }
+
+    /// Returns true if the collection has no items
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return items, as vector of Values
     pub fn as_vec(&self) -> Vec<T> {
         match self {
@@ -121,6 +186,17 @@ where
     }
 }
 
+/// Lambda (closure)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Lambda {
+    /// Argument placeholders
+    pub args: Vec<FuncArg>,
+    /// Body
+    pub body: Box<Expr>,
+    /// Values bound in the scope where the function was defined (ordered by id)
+    pub captured_env: Vec<(ValId, Value)>,
+}
+
 /// Runtime value
 #[derive(PartialEq, Eq, Debug, Clone, From)]
 pub enum Value {
@@ -148,6 +224,19 @@ pub enum Value {
     Tup(TupleItems<Value>),
     /// Optional value
     Opt(Box<Option<Value>>),
+    /// Closure
+    Lambda(Lambda),
+    /// Box
+    CBox(Arc<ErgoBox>),
+    /// AVL tree
+    AvlTree(Box<AvlTreeData>),
+    /// Context (blockchain state)
+    #[from(ignore)]
+    Context,
+    /// Block header
+    Header(Box<Header>),
+    /// Header with predictable data
+    PreHeader(Box<PreHeader>),
 }
 
 impl Value {
@@ -169,6 +258,24 @@ impl From<EcPoint> for Value {
     }
 }
 
+impl From<AvlTreeData> for Value {
+    fn from(v: AvlTreeData) -> Self {
+        Value::AvlTree(Box::new(v))
+    }
+}
+
+impl From<Header> for Value {
+    fn from(v: Header) -> Self {
+        Value::Header(Box::new(v))
+    }
+}
+
+impl From<PreHeader> for Value {
+    fn from(v: PreHeader) -> Self {
+        Value::PreHeader(Box::new(v))
+    }
+}
+
 impl From<Vec<i8>> for Value {
     fn from(v: Vec<i8>) -> Self {
         Value::Coll(CollKind::NativeColl(NativeColl::CollByte(v)))
@@ -199,6 +306,8 @@ impl From<Literal> for Value {
             Literal::Unit => Value::Unit,
             Literal::SigmaProp(s) => Value::SigmaProp(s),
             Literal::GroupElement(e) => Value::GroupElement(e),
+            Literal::CBox(b) => Value::CBox(b),
+            Literal::AvlTree(a) => Value::AvlTree(a),
             Literal::Coll(coll) => {
                 let converted_coll = match coll {
                     CollKind::NativeColl(n) => CollKind::NativeColl(n),
@@ -266,6 +375,21 @@ impl std::fmt::Display for Value {
             Value::BigInt(v) => v.fmt(f),
             Value::SigmaProp(v) => v.fmt(f),
             Value::GroupElement(v) => v.fmt(f),
+            Value::CBox(v) => write!(f, "ErgoBox({})", v.box_id()),
+            Value::AvlTree(v) => write!(f, "AvlTree({:?})", v),
+            Value::Context => write!(f, "CONTEXT"),
+            Value::Header(v) => write!(f, "Header({})", v.id),
+            Value::PreHeader(v) => write!(f, "PreHeader(parent: {})", v.parent_id),
+            Value::Lambda(v) => {
+                write!(f, "lambda(")?;
+                for (i, arg) in v.args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    arg.fmt(f)?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -281,6 +405,8 @@ impl StoreWrapped for BigInt256 {}
 impl StoreWrapped for Arc<ErgoBox> {}
 impl StoreWrapped for EcPoint {}
 impl StoreWrapped for SigmaProp {}
+impl StoreWrapped for Header {}
+impl StoreWrapped for AvlTreeData {}
 impl<T: StoreWrapped> StoreWrapped for Option<T> {}
 impl<T> StoreWrapped for Vec<T> {}
 impl StoreWrapped for Value {}
@@ -485,6 +611,54 @@ impl<T: TryExtractFrom<Value> + StoreWrapped> TryExtractFrom<Vec<Value>> for Vec
     }
 }
 
+impl TryExtractFrom<Value> for Arc<ErgoBox> {
+    fn try_extract_from(c: Value) -> Result<Self, TryExtractFromError> {
+        match c {
+            Value::CBox(b) => Ok(b),
+            _ => Err(TryExtractFromError(format!(
+                "expected ErgoBox, found {:?}",
+                c
+            ))),
+        }
+    }
+}
+
+impl TryExtractFrom<Value> for AvlTreeData {
+    fn try_extract_from(c: Value) -> Result<Self, TryExtractFromError> {
+        match c {
+            Value::AvlTree(a) => Ok(*a),
+            _ => Err(TryExtractFromError(format!(
+                "expected AvlTree, found {:?}",
+                c
+            ))),
+        }
+    }
+}
+
+impl TryExtractFrom<Value> for Header {
+    fn try_extract_from(c: Value) -> Result<Self, TryExtractFromError> {
+        match c {
+            Value::Header(h) => Ok(*h),
+            _ => Err(TryExtractFromError(format!(
+                "expected Header, found {:?}",
+                c
+            ))),
+        }
+    }
+}
+
+impl TryExtractFrom<Value> for PreHeader {
+    fn try_extract_from(c: Value) -> Result<Self, TryExtractFromError> {
+        match c {
+            Value::PreHeader(ph) => Ok(*ph),
+            _ => Err(TryExtractFromError(format!(
+                "expected PreHeader, found {:?}",
+                c
+            ))),
+        }
+    }
+}
+
 impl<T: TryExtractFrom<Value>> TryExtractFrom<Value> for Option<T> {
     fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
         match v {
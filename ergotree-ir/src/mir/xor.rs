@@ -0,0 +1,109 @@
+//! Byte-wise XOR of two byte arrays
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+use crate::has_opcode::HasStaticOpCode;
+use crate::serialization::op_code::OpCode;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::sigma_byte_writer::SigmaByteWrite;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+/// Byte-wise XOR of two byte arrays (`xor(left, right)` in ErgoScript).
+/// The result has the length of the shorter array
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Xor {
+    /// Left operand (Coll[Byte])
+    pub left: Box<Expr>,
+    /// Right operand (Coll[Byte])
+    pub right: Box<Expr>,
+}
+
+impl Xor {
+    /// Create new object, returns an error if any of the operands is not of Coll[Byte] type
+    pub fn new(left: Expr, right: Expr) -> Result<Self, InvalidArgumentError> {
+        let bytes_tpe = SType::SColl(SType::SByte.into());
+        left.check_post_eval_tpe(&bytes_tpe)?;
+        right.check_post_eval_tpe(&bytes_tpe)?;
+        Ok(Xor {
+            left: left.into(),
+            right: right.into(),
+        })
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::SColl(SType::SByte.into())
+    }
+}
+
+impl HasStaticOpCode for Xor {
+    const OP_CODE: OpCode = OpCode::XOR;
+}
+
+impl SigmaSerializable for Xor {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
+        self.left.sigma_serialize(w)?;
+        self.right.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SigmaParsingError> {
+        let left = Expr::sigma_parse(r)?;
+        let right = Expr::sigma_parse(r)?;
+        Ok(Self::new(left, right)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+/// Arbitrary impl
+mod arbitrary {
+    use crate::mir::expr::arbitrary::ArbExprParams;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    impl Arbitrary for Xor {
+        type Strategy = BoxedStrategy<Self>;
+        type Parameters = ();
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            let bytes_expr = || {
+                any_with::<Expr>(ArbExprParams {
+                    tpe: SType::SColl(SType::SByte.into()),
+                    depth: 0,
+                })
+            };
+            (bytes_expr(), bytes_expr())
+                .prop_map(|(left, right)| Self {
+                    left: left.into(),
+                    right: right.into(),
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<Xor>()) {
+            let expr: Expr = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn new_wrong_operand_type() {
+        assert!(Xor::new(vec![1u8].into(), 1i32.into()).is_err());
+    }
+}
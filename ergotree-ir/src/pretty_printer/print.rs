@@ -1,7 +1,67 @@
 use thiserror::Error;
 
+use crate::mir::apply::Apply;
+use crate::mir::atleast::Atleast;
+use crate::mir::bin_op::BinOp;
+use crate::mir::block::BlockValue;
+use crate::mir::bool_to_sigma::BoolToSigmaProp;
+use crate::mir::byte_array_to_bigint::ByteArrayToBigInt;
+use crate::mir::byte_array_to_long::ByteArrayToLong;
+use crate::mir::calc_blake2b256::CalcBlake2b256;
+use crate::mir::calc_sha256::CalcSha256;
+use crate::mir::coll_append::Append;
+use crate::mir::coll_by_index::ByIndex;
+use crate::mir::coll_exists::Exists;
+use crate::mir::coll_filter::Filter;
+use crate::mir::coll_fold::Fold;
+use crate::mir::coll_forall::ForAll;
+use crate::mir::coll_map::Map;
+use crate::mir::coll_size::SizeOf;
+use crate::mir::coll_slice::Slice;
 use crate::mir::constant::Constant;
+use crate::mir::context::Context;
+use crate::mir::create_prove_dh_tuple::CreateProveDhTuple;
+use crate::mir::create_provedlog::CreateProveDlog;
+use crate::mir::decode_point::DecodePoint;
+use crate::mir::deserialize_context::DeserializeContext;
+use crate::mir::deserialize_register::DeserializeRegister;
+use crate::mir::downcast::Downcast;
+use crate::mir::exponentiate::Exponentiate;
 use crate::mir::expr::Expr;
+use crate::mir::extract_amount::ExtractAmount;
+use crate::mir::extract_bytes::ExtractBytes;
+use crate::mir::extract_bytes_with_no_ref::ExtractBytesWithNoRef;
+use crate::mir::extract_creation_info::ExtractCreationInfo;
+use crate::mir::extract_id::ExtractId;
+use crate::mir::extract_reg_as::ExtractRegisterAs;
+use crate::mir::extract_script_bytes::ExtractScriptBytes;
+use crate::mir::func_value::FuncValue;
+use crate::mir::get_var::GetVar;
+use crate::mir::global_vars::GlobalVars;
+use crate::mir::group_generator::GroupGenerator;
+use crate::mir::logical_not::LogicalNot;
+use crate::mir::long_to_byte_array::LongToByteArray;
+use crate::mir::method_call::MethodCall;
+use crate::mir::minus_mod_q::MinusModQ;
+use crate::mir::mod_q::ModQ;
+use crate::mir::multiply_group::MultiplyGroup;
+use crate::mir::negation::Negation;
+use crate::mir::option_get::OptionGet;
+use crate::mir::option_get_or_else::OptionGetOrElse;
+use crate::mir::option_is_defined::OptionIsDefined;
+use crate::mir::plus_mod_q::PlusModQ;
+use crate::mir::property_call::PropertyCall;
+use crate::mir::select_field::SelectField;
+use crate::mir::sigma_and::SigmaAnd;
+use crate::mir::sigma_or::SigmaOr;
+use crate::mir::sigma_prop_bytes::SigmaPropBytes;
+use crate::mir::tuple::Tuple;
+use crate::mir::upcast::Upcast;
+use crate::mir::val_def::ValDef;
+use crate::mir::val_use::ValUse;
+use crate::mir::xor::Xor;
+use crate::source_span::SourceSpan;
+use crate::source_span::Spanned;
 
 use super::PosTrackingWriter;
 use super::Printer;
@@ -35,6 +95,64 @@ impl Print for Expr {
         match self {
             Expr::Const(v) => v.print(w),
             Expr::ConstPlaceholder(_) => Ok(self.clone()),
+            Expr::BinOp(v) => v.expr().print(w),
+            Expr::LogicalNot(v) => v.print(w),
+            Expr::BlockValue(v) => v.expr().print(w),
+            Expr::ValDef(v) => v.expr().print(w),
+            Expr::ValUse(v) => v.print(w),
+            Expr::FuncValue(v) => v.print(w),
+            Expr::Apply(v) => v.expr().print(w),
+            Expr::Map(v) => v.expr().print(w),
+            Expr::Filter(v) => v.expr().print(w),
+            Expr::Fold(v) => v.expr().print(w),
+            Expr::Exists(v) => v.expr().print(w),
+            Expr::ForAll(v) => v.expr().print(w),
+            Expr::ByIndex(v) => v.expr().print(w),
+            Expr::Slice(v) => v.expr().print(w),
+            Expr::Append(v) => v.expr().print(w),
+            Expr::SizeOf(v) => v.print(w),
+            Expr::GlobalVars(v) => v.print(w),
+            Expr::Context(v) => v.print(w),
+            Expr::ExtractAmount(v) => v.print(w),
+            Expr::ExtractScriptBytes(v) => v.print(w),
+            Expr::ExtractBytes(v) => v.print(w),
+            Expr::ExtractBytesWithNoRef(v) => v.print(w),
+            Expr::ExtractId(v) => v.print(w),
+            Expr::ExtractCreationInfo(v) => v.print(w),
+            Expr::ExtractRegisterAs(v) => v.expr().print(w),
+            Expr::GetVar(v) => v.expr().print(w),
+            Expr::DeserializeContext(v) => v.print(w),
+            Expr::DeserializeRegister(v) => v.print(w),
+            Expr::BoolToSigmaProp(v) => v.print(w),
+            Expr::SigmaAnd(v) => v.print(w),
+            Expr::SigmaOr(v) => v.print(w),
+            Expr::Atleast(v) => v.print(w),
+            Expr::CreateProveDlog(v) => v.print(w),
+            Expr::CreateProveDhTuple(v) => v.print(w),
+            Expr::SigmaPropBytes(v) => v.print(w),
+            Expr::OptionGet(v) => v.expr().print(w),
+            Expr::OptionGetOrElse(v) => v.print(w),
+            Expr::OptionIsDefined(v) => v.print(w),
+            Expr::Tuple(v) => v.print(w),
+            Expr::SelectField(v) => v.print(w),
+            Expr::CalcBlake2b256(v) => v.print(w),
+            Expr::CalcSha256(v) => v.print(w),
+            Expr::Xor(v) => v.print(w),
+            Expr::LongToByteArray(v) => v.print(w),
+            Expr::ByteArrayToLong(v) => v.print(w),
+            Expr::ByteArrayToBigInt(v) => v.print(w),
+            Expr::Exponentiate(v) => v.print(w),
+            Expr::MultiplyGroup(v) => v.print(w),
+            Expr::DecodePoint(v) => v.print(w),
+            Expr::GroupGenerator(v) => v.print(w),
+            Expr::Upcast(v) => v.print(w),
+            Expr::Downcast(v) => v.print(w),
+            Expr::ModQ(v) => v.print(w),
+            Expr::PlusModQ(v) => v.print(w),
+            Expr::MinusModQ(v) => v.print(w),
+            Expr::Negation(v) => v.print(w),
+            Expr::PropertyCall(v) => v.expr().print(w),
+            Expr::MethodCall(v) => v.expr().print(w),
         }
     }
 }
@@ -45,3 +163,1117 @@ impl Print for Constant {
         Ok(self.clone().into())
     }
 }
+
+impl Print for BinOp {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let left = self.left.print(w)?;
+        write!(w, " {} ", self.kind)?;
+        let right = self.right.print(w)?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: BinOp {
+                kind: self.kind,
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for BlockValue {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        writeln!(w, "{{")?;
+        w.inc_ident();
+        let mut items = Vec::new();
+        for item in &self.items {
+            w.print_indent()?;
+            items.push(item.print(w)?);
+            writeln!(w)?;
+        }
+        w.print_indent()?;
+        let res = self.result.print(w)?;
+        writeln!(w)?;
+        w.dec_ident();
+        w.print_indent()?;
+        write!(w, "}}")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: BlockValue {
+                items,
+                result: Box::new(res),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for ValDef {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        write!(w, "val v{} = ", self.id)?;
+        let rhs = self.rhs.print(w)?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: ValDef {
+                id: self.id,
+                rhs: Box::new(rhs),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for ValUse {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "v{}", self.val_id)?;
+        Ok(self.clone().into())
+    }
+}
+
+impl Print for LogicalNot {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "!")?;
+        let input = self.input.print(w)?;
+        Ok(LogicalNot {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for FuncValue {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let args = self
+            .args()
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(w, "{{({}) =>", args)?;
+        w.inc_ident();
+        w.print_indent()?;
+        let body = self.body().print(w)?;
+        writeln!(w)?;
+        w.dec_ident();
+        w.print_indent()?;
+        write!(w, "}}")?;
+        Ok(FuncValue::new(self.args().to_vec(), body).into())
+    }
+}
+
+impl Print for Apply {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let func = self.func.print(w)?;
+        write!(w, "(")?;
+        let mut args = Vec::new();
+        for (i, a) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(w, ", ")?;
+            }
+            args.push(a.print(w)?);
+        }
+        write!(w, ")")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: Apply {
+                func: Box::new(func),
+                args,
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for Map {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        write!(w, ".map(")?;
+        let mapper = self.mapper.print(w)?;
+        write!(w, ")")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: Map {
+                input: Box::new(input),
+                mapper: Box::new(mapper),
+                mapper_sfunc: self.mapper_sfunc.clone(),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for Filter {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        write!(w, ".filter(")?;
+        let condition = self.condition.print(w)?;
+        write!(w, ")")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: Filter {
+                input: Box::new(input),
+                condition: Box::new(condition),
+                elem_tpe: self.elem_tpe.clone(),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for Fold {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        write!(w, ".fold(")?;
+        let zero = self.zero.print(w)?;
+        write!(w, ", ")?;
+        let fold_op = self.fold_op.print(w)?;
+        write!(w, ")")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: Fold {
+                input: Box::new(input),
+                zero: Box::new(zero),
+                fold_op: Box::new(fold_op),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for Exists {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        write!(w, ".exists(")?;
+        let condition = self.condition.print(w)?;
+        write!(w, ")")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: Exists {
+                input: Box::new(input),
+                condition: Box::new(condition),
+                elem_tpe: self.elem_tpe.clone(),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for ForAll {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        write!(w, ".forall(")?;
+        let condition = self.condition.print(w)?;
+        write!(w, ")")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: ForAll {
+                input: Box::new(input),
+                condition: Box::new(condition),
+                elem_tpe: self.elem_tpe.clone(),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for ByIndex {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        let (index, default) = match &self.default {
+            Some(default) => {
+                write!(w, ".getOrElse(")?;
+                let index = self.index.print(w)?;
+                write!(w, ", ")?;
+                let default = default.print(w)?;
+                write!(w, ")")?;
+                (index, Some(Box::new(default)))
+            }
+            None => {
+                write!(w, "(")?;
+                let index = self.index.print(w)?;
+                write!(w, ")")?;
+                (index, None)
+            }
+        };
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: ByIndex {
+                input: Box::new(input),
+                index: Box::new(index),
+                default,
+                elem_tpe: self.elem_tpe.clone(),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for Slice {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        write!(w, ".slice(")?;
+        let from = self.from.print(w)?;
+        write!(w, ", ")?;
+        let until = self.until.print(w)?;
+        write!(w, ")")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: Slice {
+                input: Box::new(input),
+                from: Box::new(from),
+                until: Box::new(until),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for Append {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        write!(w, ".append(")?;
+        let col_2 = self.col_2.print(w)?;
+        write!(w, ")")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: Append {
+                input: Box::new(input),
+                col_2: Box::new(col_2),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for SizeOf {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".size")?;
+        Ok(SizeOf {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for GlobalVars {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "{}", self)?;
+        Ok((*self).into())
+    }
+}
+
+impl Print for Context {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "CONTEXT")?;
+        Ok((*self).into())
+    }
+}
+
+impl Print for ExtractAmount {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".value")?;
+        Ok(ExtractAmount {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for ExtractScriptBytes {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".propositionBytes")?;
+        Ok(ExtractScriptBytes {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for ExtractBytes {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".bytes")?;
+        Ok(ExtractBytes {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for ExtractBytesWithNoRef {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".bytesWithoutRef")?;
+        Ok(ExtractBytesWithNoRef {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for ExtractId {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".id")?;
+        Ok(ExtractId {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for ExtractCreationInfo {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".creationInfo")?;
+        Ok(ExtractCreationInfo {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for ExtractRegisterAs {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        write!(w, ".R{}[{}]", self.register_id, self.elem_tpe)?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: ExtractRegisterAs {
+                input: Box::new(input),
+                register_id: self.register_id,
+                elem_tpe: self.elem_tpe.clone(),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for GetVar {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        write!(w, "getVar[{}]({})", self.var_tpe, self.var_id)?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: self.clone(),
+        }
+        .into())
+    }
+}
+
+impl Print for DeserializeContext {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "executeFromVar[{}]({})", self.tpe, self.id)?;
+        Ok(self.clone().into())
+    }
+}
+
+impl Print for DeserializeRegister {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "executeFromSelfReg[{}]({}", self.tpe, self.reg)?;
+        let default = match &self.default {
+            Some(default) => {
+                write!(w, ", ")?;
+                Some(Box::new(default.print(w)?))
+            }
+            None => None,
+        };
+        write!(w, ")")?;
+        Ok(DeserializeRegister {
+            reg: self.reg,
+            tpe: self.tpe.clone(),
+            default,
+        }
+        .into())
+    }
+}
+
+impl Print for BoolToSigmaProp {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "sigmaProp(")?;
+        let input = self.input.print(w)?;
+        write!(w, ")")?;
+        Ok(BoolToSigmaProp {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for SigmaAnd {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let mut is_first = true;
+        let items = self.items.try_mapped_ref(|item| {
+            if !is_first {
+                write!(w, " && ")?;
+            }
+            is_first = false;
+            item.print(w)
+        })?;
+        Ok(SigmaAnd { items }.into())
+    }
+}
+
+impl Print for SigmaOr {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let mut is_first = true;
+        let items = self.items.try_mapped_ref(|item| {
+            if !is_first {
+                write!(w, " || ")?;
+            }
+            is_first = false;
+            item.print(w)
+        })?;
+        Ok(SigmaOr { items }.into())
+    }
+}
+
+impl Print for Atleast {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "atLeast(")?;
+        let bound = self.bound.print(w)?;
+        write!(w, ", ")?;
+        let input = self.input.print(w)?;
+        write!(w, ")")?;
+        Ok(Atleast {
+            bound: Box::new(bound),
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for CreateProveDlog {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "proveDlog(")?;
+        let input = self.input.print(w)?;
+        write!(w, ")")?;
+        Ok(CreateProveDlog {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for CreateProveDhTuple {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "proveDHTuple(")?;
+        let g = self.g.print(w)?;
+        write!(w, ", ")?;
+        let h = self.h.print(w)?;
+        write!(w, ", ")?;
+        let u = self.u.print(w)?;
+        write!(w, ", ")?;
+        let v = self.v.print(w)?;
+        write!(w, ")")?;
+        Ok(CreateProveDhTuple {
+            g: Box::new(g),
+            h: Box::new(h),
+            u: Box::new(u),
+            v: Box::new(v),
+        }
+        .into())
+    }
+}
+
+impl Print for SigmaPropBytes {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".propBytes")?;
+        Ok(SigmaPropBytes {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for OptionGet {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let input = self.input.print(w)?;
+        write!(w, ".get")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: OptionGet {
+                input: Box::new(input),
+                elem_tpe: self.elem_tpe.clone(),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for OptionGetOrElse {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".getOrElse(")?;
+        let default = self.default.print(w)?;
+        write!(w, ")")?;
+        Ok(OptionGetOrElse {
+            input: Box::new(input),
+            default: Box::new(default),
+            elem_tpe: self.elem_tpe.clone(),
+        }
+        .into())
+    }
+}
+
+impl Print for OptionIsDefined {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".isDefined")?;
+        Ok(OptionIsDefined {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for Tuple {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "(")?;
+        let mut is_first = true;
+        let items = self.items.try_mapped_ref(|item| {
+            if !is_first {
+                write!(w, ", ")?;
+            }
+            is_first = false;
+            item.print(w)
+        })?;
+        write!(w, ")")?;
+        Ok(Tuple { items }.into())
+    }
+}
+
+impl Print for SelectField {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, "._{}", u8::from(self.field_index))?;
+        Ok(SelectField {
+            input: Box::new(input),
+            field_index: self.field_index,
+            field_tpe: self.field_tpe.clone(),
+        }
+        .into())
+    }
+}
+
+impl Print for CalcBlake2b256 {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "blake2b256(")?;
+        let input = self.input.print(w)?;
+        write!(w, ")")?;
+        Ok(CalcBlake2b256 {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for CalcSha256 {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "sha256(")?;
+        let input = self.input.print(w)?;
+        write!(w, ")")?;
+        Ok(CalcSha256 {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for LongToByteArray {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "longToByteArray(")?;
+        let input = self.input.print(w)?;
+        write!(w, ")")?;
+        Ok(LongToByteArray {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for ByteArrayToLong {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "byteArrayToLong(")?;
+        let input = self.input.print(w)?;
+        write!(w, ")")?;
+        Ok(ByteArrayToLong {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for ByteArrayToBigInt {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "byteArrayToBigInt(")?;
+        let input = self.input.print(w)?;
+        write!(w, ")")?;
+        Ok(ByteArrayToBigInt {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for Xor {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "xor(")?;
+        let left = self.left.print(w)?;
+        write!(w, ", ")?;
+        let right = self.right.print(w)?;
+        write!(w, ")")?;
+        Ok(Xor {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+        .into())
+    }
+}
+
+impl Print for Exponentiate {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let left = self.left.print(w)?;
+        write!(w, ".exp(")?;
+        let right = self.right.print(w)?;
+        write!(w, ")")?;
+        Ok(Exponentiate {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+        .into())
+    }
+}
+
+impl Print for MultiplyGroup {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let left = self.left.print(w)?;
+        write!(w, ".multiply(")?;
+        let right = self.right.print(w)?;
+        write!(w, ")")?;
+        Ok(MultiplyGroup {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+        .into())
+    }
+}
+
+impl Print for DecodePoint {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "decodePoint(")?;
+        let input = self.input.print(w)?;
+        write!(w, ")")?;
+        Ok(DecodePoint {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for GroupGenerator {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "groupGenerator")?;
+        Ok((*self).into())
+    }
+}
+
+impl Print for Upcast {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".to{}", self.tpe)?;
+        Ok(Upcast {
+            input: Box::new(input),
+            tpe: self.tpe.clone(),
+        }
+        .into())
+    }
+}
+
+impl Print for Downcast {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".to{}", self.tpe)?;
+        Ok(Downcast {
+            input: Box::new(input),
+            tpe: self.tpe.clone(),
+        }
+        .into())
+    }
+}
+
+impl Print for ModQ {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let input = self.input.print(w)?;
+        write!(w, ".modQ")?;
+        Ok(ModQ {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for PlusModQ {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let left = self.left.print(w)?;
+        write!(w, ".plusModQ(")?;
+        let right = self.right.print(w)?;
+        write!(w, ")")?;
+        Ok(PlusModQ {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+        .into())
+    }
+}
+
+impl Print for MinusModQ {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let left = self.left.print(w)?;
+        write!(w, ".minusModQ(")?;
+        let right = self.right.print(w)?;
+        write!(w, ")")?;
+        Ok(MinusModQ {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+        .into())
+    }
+}
+
+impl Print for Negation {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        write!(w, "-")?;
+        let input = self.input.print(w)?;
+        Ok(Negation {
+            input: Box::new(input),
+        }
+        .into())
+    }
+}
+
+impl Print for PropertyCall {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let obj = self.obj.print(w)?;
+        write!(w, ".{}", self.method.name())?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: PropertyCall {
+                obj: Box::new(obj),
+                method: self.method.clone(),
+            },
+        }
+        .into())
+    }
+}
+
+impl Print for MethodCall {
+    fn print(&self, w: &mut dyn Printer) -> Result<Expr, PrintError> {
+        let offset = w.current_pos();
+        let obj = self.obj.print(w)?;
+        write!(w, ".{}(", self.method.name())?;
+        let args = self
+            .args
+            .iter()
+            .enumerate()
+            .map(|(i, a)| -> Result<Expr, PrintError> {
+                if i > 0 {
+                    write!(w, ", ")?;
+                }
+                a.print(w)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        write!(w, ")")?;
+        let length = w.current_pos() - offset;
+        Ok(Spanned {
+            source_span: SourceSpan { offset, length },
+            expr: MethodCall {
+                obj: Box::new(obj),
+                method: self.method.clone(),
+                args,
+            },
+        }
+        .into())
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+
+    use expect_test::expect;
+
+    use crate::mir::bin_op::ArithOp;
+    use crate::mir::bin_op::RelationOp;
+    use crate::mir::func_value::FuncArg;
+    use crate::mir::val_def::ValId;
+    use crate::types::scontext;
+    use crate::types::sgroup_elem;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn check_pretty(expr: Expr, expected_tree: expect_test::Expect) {
+        let mut w = PosTrackingWriter::new();
+        let _ = expr.print(&mut w).unwrap();
+        expected_tree.assert_eq(w.get_buf());
+    }
+
+    fn check_spans(expr: Expr, expected_tree: expect_test::Expect) {
+        let mut w = PosTrackingWriter::new();
+        let spanned_expr = expr.print(&mut w).unwrap();
+        expected_tree.assert_eq(format!("{:?}", spanned_expr).as_str());
+    }
+
+    #[test]
+    fn print_bin_op() {
+        let expr: Expr = BinOp {
+            kind: ArithOp::Plus.into(),
+            left: Box::new(4i32.into()),
+            right: Box::new(
+                BinOp {
+                    kind: ArithOp::Divide.into(),
+                    left: Box::new(2i32.into()),
+                    right: Box::new(0i32.into()),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        check_pretty(expr.clone(), expect!["4 + 2 / 0"]);
+        check_spans(
+            expr,
+            expect![[
+                r#"BinOp(Spanned { source_span: SourceSpan { offset: 0, length: 9 }, expr: BinOp { kind: Arith(Plus), left: Const("4: SInt"), right: BinOp(Spanned { source_span: SourceSpan { offset: 4, length: 5 }, expr: BinOp { kind: Arith(Divide), left: Const("2: SInt"), right: Const("0: SInt") } }) } })"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn print_logical_not() {
+        let expr: Expr = LogicalNot {
+            input: Box::new(
+                BinOp {
+                    kind: RelationOp::Gt.into(),
+                    left: Box::new(4i32.into()),
+                    right: Box::new(2i32.into()),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        check_pretty(expr, expect!["!4 > 2"]);
+    }
+
+    #[test]
+    fn print_block() {
+        let val_id = ValId(1);
+        let expr: Expr = BlockValue {
+            items: vec![ValDef {
+                id: val_id,
+                rhs: Box::new(4i32.into()),
+            }
+            .into()],
+            result: Box::new(
+                BinOp {
+                    kind: ArithOp::Plus.into(),
+                    left: Box::new(
+                        ValUse {
+                            val_id,
+                            tpe: SType::SInt,
+                        }
+                        .into(),
+                    ),
+                    right: Box::new(2i32.into()),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        check_pretty(
+            expr.clone(),
+            expect![[r#"
+            {
+              val v1 = 4
+              v1 + 2
+            }"#]],
+        );
+        check_spans(
+            expr,
+            expect![[
+                r#"BlockValue(Spanned { source_span: SourceSpan { offset: 0, length: 25 }, expr: BlockValue { items: [ValDef(Spanned { source_span: SourceSpan { offset: 4, length: 10 }, expr: ValDef { id: ValId(1), rhs: Const("4: SInt") } })], result: BinOp(Spanned { source_span: SourceSpan { offset: 17, length: 6 }, expr: BinOp { kind: Arith(Plus), left: ValUse(ValUse { val_id: ValId(1), tpe: SInt }), right: Const("2: SInt") } }) } })"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn print_apply() {
+        let val_id = ValId(1);
+        let func: Expr = FuncValue::new(
+            vec![FuncArg {
+                idx: val_id,
+                tpe: SType::SInt,
+            }],
+            BinOp {
+                kind: ArithOp::Plus.into(),
+                left: Box::new(
+                    ValUse {
+                        val_id,
+                        tpe: SType::SInt,
+                    }
+                    .into(),
+                ),
+                right: Box::new(1i32.into()),
+            }
+            .into(),
+        )
+        .into();
+        let expr: Expr = Apply::new(func, vec![2i32.into()]).unwrap().into();
+        check_pretty(
+            expr,
+            expect![[r#"
+            {(v1: Int) =>
+              v1 + 1
+            }(2)"#]],
+        );
+    }
+
+    #[test]
+    fn print_map() {
+        let val_id = ValId(1);
+        let mapper: Expr = FuncValue::new(
+            vec![FuncArg {
+                idx: val_id,
+                tpe: SType::SInt,
+            }],
+            BinOp {
+                kind: ArithOp::Multiply.into(),
+                left: Box::new(
+                    ValUse {
+                        val_id,
+                        tpe: SType::SInt,
+                    }
+                    .into(),
+                ),
+                right: Box::new(2i32.into()),
+            }
+            .into(),
+        )
+        .into();
+        let expr: Expr = SizeOf::new(Map::new(vec![1i32, 2i32].into(), mapper).unwrap().into())
+            .unwrap()
+            .into();
+        check_pretty(
+            expr,
+            expect![[r#"
+            [1, 2].map({(v1: Int) =>
+              v1 * 2
+            }).size"#]],
+        );
+    }
+
+    #[test]
+    fn print_extract_reg_as() {
+        let expr: Expr = ExtractRegisterAs::new(
+            GlobalVars::SelfBox.into(),
+            4,
+            SType::SOption(SType::SInt.into()),
+        )
+        .unwrap()
+        .into();
+        check_pretty(
+            expr,
+            expect![[r#"
+            SELF.R4[Int]"#]],
+        );
+    }
+
+    #[test]
+    fn print_option_get_or_else() {
+        let reg: Expr = ExtractRegisterAs::new(
+            GlobalVars::SelfBox.into(),
+            4,
+            SType::SOption(SType::SInt.into()),
+        )
+        .unwrap()
+        .into();
+        let expr: Expr = OptionGetOrElse::new(reg, 1i32.into()).unwrap().into();
+        check_pretty(
+            expr,
+            expect![[r#"
+            SELF.R4[Int].getOrElse(1)"#]],
+        );
+    }
+
+    #[test]
+    fn print_upcast() {
+        let expr: Expr = Upcast::new(GlobalVars::Height.into(), SType::SLong)
+            .unwrap()
+            .into();
+        check_pretty(
+            expr,
+            expect![[r#"
+            HEIGHT.toLong"#]],
+        );
+    }
+
+    #[test]
+    fn print_property_and_method_call() {
+        let data_inputs: Expr =
+            PropertyCall::new(Context.into(), scontext::DATA_INPUTS_PROPERTY.clone())
+                .unwrap()
+                .into();
+        check_pretty(
+            data_inputs,
+            expect![[r#"
+            CONTEXT.dataInputs"#]],
+        );
+        let negate: Expr = MethodCall::new(
+            GroupGenerator.into(),
+            sgroup_elem::NEGATE_METHOD.clone(),
+            vec![],
+        )
+        .unwrap()
+        .into();
+        check_pretty(
+            negate,
+            expect![[r#"
+            groupGenerator.negate()"#]],
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Serializers
 
+mod bin_op;
 mod constant;
 mod constant_placeholder;
 pub(crate) mod data;
@@ -12,6 +13,7 @@ pub(crate) mod types;
 pub mod constant_store;
 pub mod sigma_byte_reader;
 pub mod sigma_byte_writer;
+pub mod val_def_type_store;
 
 mod serializable;
 pub use serializable::*;
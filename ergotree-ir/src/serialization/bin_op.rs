@@ -0,0 +1,60 @@
+use super::op_code::OpCode;
+use super::sigma_byte_writer::SigmaByteWrite;
+use crate::mir::bin_op::BinOp;
+use crate::mir::bin_op::BinOpKind;
+use crate::mir::constant::Constant;
+use crate::mir::constant::Literal;
+use crate::mir::expr::Expr;
+use crate::serialization::sigma_byte_reader::SigmaByteRead;
+use crate::serialization::SigmaParsingError;
+use crate::serialization::SigmaSerializable;
+use crate::serialization::SigmaSerializeResult;
+use crate::types::stype::SType;
+
+pub fn bin_op_sigma_serialize<W: SigmaByteWrite>(
+    bin_op: &BinOp,
+    w: &mut W,
+) -> SigmaSerializeResult {
+    match (bin_op.left.as_ref(), bin_op.right.as_ref()) {
+        (
+            Expr::Const(Constant {
+                tpe: SType::SBoolean,
+                v: Literal::Boolean(l),
+            }),
+            Expr::Const(Constant {
+                tpe: SType::SBoolean,
+                v: Literal::Boolean(r),
+            }),
+        ) => {
+            // two boolean constants are packed into bits
+            OpCode::COLL_OF_BOOL_CONST.sigma_serialize(w)?;
+            w.put_bits(&[*l, *r])?;
+        }
+        _ => {
+            bin_op.left.sigma_serialize(w)?;
+            bin_op.right.sigma_serialize(w)?;
+        }
+    };
+    Ok(())
+}
+
+pub fn bin_op_sigma_parse<R: SigmaByteRead>(
+    op_kind: BinOpKind,
+    r: &mut R,
+) -> Result<Expr, SigmaParsingError> {
+    let tag = r.get_u8()?;
+    let (left, right) = if tag == OpCode::COLL_OF_BOOL_CONST.value() {
+        let bools = r.get_bits(2)?;
+        (Expr::from(bools[0]), Expr::from(bools[1]))
+    } else {
+        let left = Expr::parse_with_tag(r, tag)?;
+        let right = Expr::sigma_parse(r)?;
+        (left, right)
+    };
+    Ok(BinOp {
+        kind: op_kind,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+    .into())
+}
@@ -48,6 +48,11 @@ mod tests {
             prop_assert_eq![sigma_serialize_roundtrip(&v), v];
         }
 
+        #[test]
+        fn ser_box_roundtrip(v in any_with::<Constant>(ArbConstantParams::Exact(SType::SBox))) {
+            prop_assert_eq![sigma_serialize_roundtrip(&v), v];
+        }
+
     }
 
     #[test]
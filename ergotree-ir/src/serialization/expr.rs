@@ -1,9 +1,71 @@
+use super::bin_op::bin_op_sigma_parse;
+use super::bin_op::bin_op_sigma_serialize;
 use super::{op_code::OpCode, sigma_byte_writer::SigmaByteWrite};
 use crate::has_opcode::HasOpCode;
 use crate::has_opcode::HasStaticOpCode;
+use crate::mir::apply::Apply;
+use crate::mir::atleast::Atleast;
+use crate::mir::bin_op::ArithOp;
+use crate::mir::bin_op::LogicalOp;
+use crate::mir::bin_op::RelationOp;
+use crate::mir::block::BlockValue;
+use crate::mir::bool_to_sigma::BoolToSigmaProp;
+use crate::mir::byte_array_to_bigint::ByteArrayToBigInt;
+use crate::mir::byte_array_to_long::ByteArrayToLong;
+use crate::mir::calc_blake2b256::CalcBlake2b256;
+use crate::mir::calc_sha256::CalcSha256;
+use crate::mir::coll_append::Append;
+use crate::mir::coll_by_index::ByIndex;
+use crate::mir::coll_exists::Exists;
+use crate::mir::coll_filter::Filter;
+use crate::mir::coll_fold::Fold;
+use crate::mir::coll_forall::ForAll;
+use crate::mir::coll_map::Map;
+use crate::mir::coll_size::SizeOf;
+use crate::mir::coll_slice::Slice;
 use crate::mir::constant::Constant;
 use crate::mir::constant::ConstantPlaceholder;
+use crate::mir::context::Context;
+use crate::mir::create_prove_dh_tuple::CreateProveDhTuple;
+use crate::mir::create_provedlog::CreateProveDlog;
+use crate::mir::decode_point::DecodePoint;
+use crate::mir::deserialize_context::DeserializeContext;
+use crate::mir::deserialize_register::DeserializeRegister;
+use crate::mir::downcast::Downcast;
+use crate::mir::exponentiate::Exponentiate;
 use crate::mir::expr::Expr;
+use crate::mir::extract_amount::ExtractAmount;
+use crate::mir::extract_bytes::ExtractBytes;
+use crate::mir::extract_bytes_with_no_ref::ExtractBytesWithNoRef;
+use crate::mir::extract_creation_info::ExtractCreationInfo;
+use crate::mir::extract_id::ExtractId;
+use crate::mir::extract_reg_as::ExtractRegisterAs;
+use crate::mir::extract_script_bytes::ExtractScriptBytes;
+use crate::mir::func_value::FuncValue;
+use crate::mir::get_var::GetVar;
+use crate::mir::global_vars::GlobalVars;
+use crate::mir::group_generator::GroupGenerator;
+use crate::mir::logical_not::LogicalNot;
+use crate::mir::long_to_byte_array::LongToByteArray;
+use crate::mir::method_call::MethodCall;
+use crate::mir::minus_mod_q::MinusModQ;
+use crate::mir::mod_q::ModQ;
+use crate::mir::multiply_group::MultiplyGroup;
+use crate::mir::negation::Negation;
+use crate::mir::option_get::OptionGet;
+use crate::mir::option_get_or_else::OptionGetOrElse;
+use crate::mir::option_is_defined::OptionIsDefined;
+use crate::mir::plus_mod_q::PlusModQ;
+use crate::mir::property_call::PropertyCall;
+use crate::mir::select_field::SelectField;
+use crate::mir::sigma_and::SigmaAnd;
+use crate::mir::sigma_or::SigmaOr;
+use crate::mir::sigma_prop_bytes::SigmaPropBytes;
+use crate::mir::tuple::Tuple;
+use crate::mir::upcast::Upcast;
+use crate::mir::val_def::ValDef;
+use crate::mir::val_use::ValUse;
+use crate::mir::xor::Xor;
 use crate::serialization::SigmaSerializeResult;
 use crate::serialization::{
     sigma_byte_reader::SigmaByteRead, SigmaParsingError, SigmaSerializable,
@@ -35,6 +97,83 @@ impl Expr {
                         Ok(Expr::ConstPlaceholder(cp))
                     }
                 }
+                OpCode::PLUS => Ok(bin_op_sigma_parse(ArithOp::Plus.into(), r)?),
+                OpCode::MINUS => Ok(bin_op_sigma_parse(ArithOp::Minus.into(), r)?),
+                OpCode::MULTIPLY => Ok(bin_op_sigma_parse(ArithOp::Multiply.into(), r)?),
+                OpCode::DIVISION => Ok(bin_op_sigma_parse(ArithOp::Divide.into(), r)?),
+                OpCode::MAX => Ok(bin_op_sigma_parse(ArithOp::Max.into(), r)?),
+                OpCode::MIN => Ok(bin_op_sigma_parse(ArithOp::Min.into(), r)?),
+                OpCode::MODULO => Ok(bin_op_sigma_parse(ArithOp::Modulo.into(), r)?),
+                OpCode::EQ => Ok(bin_op_sigma_parse(RelationOp::Eq.into(), r)?),
+                OpCode::NEQ => Ok(bin_op_sigma_parse(RelationOp::NEq.into(), r)?),
+                OpCode::GT => Ok(bin_op_sigma_parse(RelationOp::Gt.into(), r)?),
+                OpCode::LT => Ok(bin_op_sigma_parse(RelationOp::Lt.into(), r)?),
+                OpCode::GE => Ok(bin_op_sigma_parse(RelationOp::Ge.into(), r)?),
+                OpCode::LE => Ok(bin_op_sigma_parse(RelationOp::Le.into(), r)?),
+                OpCode::BIN_AND => Ok(bin_op_sigma_parse(LogicalOp::And.into(), r)?),
+                OpCode::BIN_OR => Ok(bin_op_sigma_parse(LogicalOp::Or.into(), r)?),
+                OpCode::BIN_XOR => Ok(bin_op_sigma_parse(LogicalOp::Xor.into(), r)?),
+                LogicalNot::OP_CODE => Ok(LogicalNot::sigma_parse(r)?.into()),
+                BlockValue::OP_CODE => Ok(Expr::BlockValue(BlockValue::sigma_parse(r)?.into())),
+                ValDef::OP_CODE => Ok(Expr::ValDef(ValDef::sigma_parse(r)?.into())),
+                ValUse::OP_CODE => Ok(ValUse::sigma_parse(r)?.into()),
+                FuncValue::OP_CODE => Ok(FuncValue::sigma_parse(r)?.into()),
+                Apply::OP_CODE => Ok(Apply::sigma_parse(r)?.into()),
+                Map::OP_CODE => Ok(Map::sigma_parse(r)?.into()),
+                Filter::OP_CODE => Ok(Filter::sigma_parse(r)?.into()),
+                Fold::OP_CODE => Ok(Fold::sigma_parse(r)?.into()),
+                Exists::OP_CODE => Ok(Exists::sigma_parse(r)?.into()),
+                ForAll::OP_CODE => Ok(ForAll::sigma_parse(r)?.into()),
+                ByIndex::OP_CODE => Ok(ByIndex::sigma_parse(r)?.into()),
+                Slice::OP_CODE => Ok(Slice::sigma_parse(r)?.into()),
+                Append::OP_CODE => Ok(Append::sigma_parse(r)?.into()),
+                SizeOf::OP_CODE => Ok(SizeOf::sigma_parse(r)?.into()),
+                OpCode::HEIGHT => Ok(Expr::GlobalVars(GlobalVars::Height)),
+                OpCode::SELF_BOX => Ok(Expr::GlobalVars(GlobalVars::SelfBox)),
+                OpCode::INPUTS => Ok(Expr::GlobalVars(GlobalVars::Inputs)),
+                OpCode::OUTPUTS => Ok(Expr::GlobalVars(GlobalVars::Outputs)),
+                OpCode::MINER_PUBKEY => Ok(Expr::GlobalVars(GlobalVars::MinerPubKey)),
+                Context::OP_CODE => Ok(Context::sigma_parse(r)?.into()),
+                ExtractAmount::OP_CODE => Ok(ExtractAmount::sigma_parse(r)?.into()),
+                ExtractScriptBytes::OP_CODE => Ok(ExtractScriptBytes::sigma_parse(r)?.into()),
+                ExtractBytes::OP_CODE => Ok(ExtractBytes::sigma_parse(r)?.into()),
+                ExtractBytesWithNoRef::OP_CODE => Ok(ExtractBytesWithNoRef::sigma_parse(r)?.into()),
+                ExtractId::OP_CODE => Ok(ExtractId::sigma_parse(r)?.into()),
+                ExtractCreationInfo::OP_CODE => Ok(ExtractCreationInfo::sigma_parse(r)?.into()),
+                ExtractRegisterAs::OP_CODE => Ok(ExtractRegisterAs::sigma_parse(r)?.into()),
+                GetVar::OP_CODE => Ok(GetVar::sigma_parse(r)?.into()),
+                DeserializeContext::OP_CODE => Ok(DeserializeContext::sigma_parse(r)?.into()),
+                DeserializeRegister::OP_CODE => Ok(DeserializeRegister::sigma_parse(r)?.into()),
+                BoolToSigmaProp::OP_CODE => Ok(BoolToSigmaProp::sigma_parse(r)?.into()),
+                SigmaAnd::OP_CODE => Ok(SigmaAnd::sigma_parse(r)?.into()),
+                SigmaOr::OP_CODE => Ok(SigmaOr::sigma_parse(r)?.into()),
+                Atleast::OP_CODE => Ok(Atleast::sigma_parse(r)?.into()),
+                CreateProveDlog::OP_CODE => Ok(CreateProveDlog::sigma_parse(r)?.into()),
+                CreateProveDhTuple::OP_CODE => Ok(CreateProveDhTuple::sigma_parse(r)?.into()),
+                SigmaPropBytes::OP_CODE => Ok(SigmaPropBytes::sigma_parse(r)?.into()),
+                OptionGet::OP_CODE => Ok(OptionGet::sigma_parse(r)?.into()),
+                OptionGetOrElse::OP_CODE => Ok(OptionGetOrElse::sigma_parse(r)?.into()),
+                OptionIsDefined::OP_CODE => Ok(OptionIsDefined::sigma_parse(r)?.into()),
+                Tuple::OP_CODE => Ok(Tuple::sigma_parse(r)?.into()),
+                SelectField::OP_CODE => Ok(SelectField::sigma_parse(r)?.into()),
+                CalcBlake2b256::OP_CODE => Ok(CalcBlake2b256::sigma_parse(r)?.into()),
+                CalcSha256::OP_CODE => Ok(CalcSha256::sigma_parse(r)?.into()),
+                Xor::OP_CODE => Ok(Xor::sigma_parse(r)?.into()),
+                LongToByteArray::OP_CODE => Ok(LongToByteArray::sigma_parse(r)?.into()),
+                ByteArrayToLong::OP_CODE => Ok(ByteArrayToLong::sigma_parse(r)?.into()),
+                ByteArrayToBigInt::OP_CODE => Ok(ByteArrayToBigInt::sigma_parse(r)?.into()),
+                Exponentiate::OP_CODE => Ok(Exponentiate::sigma_parse(r)?.into()),
+                MultiplyGroup::OP_CODE => Ok(MultiplyGroup::sigma_parse(r)?.into()),
+                DecodePoint::OP_CODE => Ok(DecodePoint::sigma_parse(r)?.into()),
+                GroupGenerator::OP_CODE => Ok(GroupGenerator::sigma_parse(r)?.into()),
+                Upcast::OP_CODE => Ok(Upcast::sigma_parse(r)?.into()),
+                Downcast::OP_CODE => Ok(Downcast::sigma_parse(r)?.into()),
+                ModQ::OP_CODE => Ok(ModQ::sigma_parse(r)?.into()),
+                PlusModQ::OP_CODE => Ok(PlusModQ::sigma_parse(r)?.into()),
+                MinusModQ::OP_CODE => Ok(MinusModQ::sigma_parse(r)?.into()),
+                Negation::OP_CODE => Ok(Negation::sigma_parse(r)?.into()),
+                PropertyCall::OP_CODE => Ok(PropertyCall::sigma_parse(r)?.into()),
+                MethodCall::OP_CODE => Ok(MethodCall::sigma_parse(r)?.into()),
                 o => Err(SigmaParsingError::NotImplementedOpCode(format!(
                     "{0}(shift {1})",
                     o.value(),
@@ -67,6 +206,67 @@ impl SigmaSerializable for Expr {
                 None => c.sigma_serialize(w),
             },
             Expr::ConstPlaceholder(cp) => cp.sigma_serialize_w_opcode(w),
+            Expr::BinOp(op) => {
+                op.op_code().sigma_serialize(w)?;
+                bin_op_sigma_serialize(op.expr(), w)
+            }
+            Expr::LogicalNot(op) => op.sigma_serialize_w_opcode(w),
+            Expr::BlockValue(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::ValDef(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::ValUse(op) => op.sigma_serialize_w_opcode(w),
+            Expr::FuncValue(op) => op.sigma_serialize_w_opcode(w),
+            Expr::Apply(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::Map(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::Filter(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::Fold(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::Exists(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::ForAll(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::ByIndex(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::Slice(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::Append(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::SizeOf(op) => op.sigma_serialize_w_opcode(w),
+            Expr::GlobalVars(op) => op.op_code().sigma_serialize(w),
+            Expr::Context(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ExtractAmount(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ExtractScriptBytes(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ExtractBytes(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ExtractBytesWithNoRef(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ExtractId(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ExtractCreationInfo(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ExtractRegisterAs(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::GetVar(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::DeserializeContext(op) => op.sigma_serialize_w_opcode(w),
+            Expr::DeserializeRegister(op) => op.sigma_serialize_w_opcode(w),
+            Expr::BoolToSigmaProp(op) => op.sigma_serialize_w_opcode(w),
+            Expr::SigmaAnd(op) => op.sigma_serialize_w_opcode(w),
+            Expr::SigmaOr(op) => op.sigma_serialize_w_opcode(w),
+            Expr::Atleast(op) => op.sigma_serialize_w_opcode(w),
+            Expr::CreateProveDlog(op) => op.sigma_serialize_w_opcode(w),
+            Expr::CreateProveDhTuple(op) => op.sigma_serialize_w_opcode(w),
+            Expr::SigmaPropBytes(op) => op.sigma_serialize_w_opcode(w),
+            Expr::OptionGet(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::OptionGetOrElse(op) => op.sigma_serialize_w_opcode(w),
+            Expr::OptionIsDefined(op) => op.sigma_serialize_w_opcode(w),
+            Expr::Tuple(op) => op.sigma_serialize_w_opcode(w),
+            Expr::SelectField(op) => op.sigma_serialize_w_opcode(w),
+            Expr::CalcBlake2b256(op) => op.sigma_serialize_w_opcode(w),
+            Expr::CalcSha256(op) => op.sigma_serialize_w_opcode(w),
+            Expr::Xor(op) => op.sigma_serialize_w_opcode(w),
+            Expr::LongToByteArray(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ByteArrayToLong(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ByteArrayToBigInt(op) => op.sigma_serialize_w_opcode(w),
+            Expr::Exponentiate(op) => op.sigma_serialize_w_opcode(w),
+            Expr::MultiplyGroup(op) => op.sigma_serialize_w_opcode(w),
+            Expr::DecodePoint(op) => op.sigma_serialize_w_opcode(w),
+            Expr::GroupGenerator(op) => op.sigma_serialize_w_opcode(w),
+            Expr::Upcast(op) => op.sigma_serialize_w_opcode(w),
+            Expr::Downcast(op) => op.sigma_serialize_w_opcode(w),
+            Expr::ModQ(op) => op.sigma_serialize_w_opcode(w),
+            Expr::PlusModQ(op) => op.sigma_serialize_w_opcode(w),
+            Expr::MinusModQ(op) => op.sigma_serialize_w_opcode(w),
+            Expr::Negation(op) => op.sigma_serialize_w_opcode(w),
+            Expr::PropertyCall(op) => op.expr().sigma_serialize_w_opcode(w),
+            Expr::MethodCall(op) => op.expr().sigma_serialize_w_opcode(w),
         }
     }
 
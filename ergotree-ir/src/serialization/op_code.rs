@@ -38,19 +38,124 @@ impl OpCode {
     /// and the corresponding data.
     pub const LAST_CONSTANT_CODE: OpCode = OpCode(Self::LAST_DATA_TYPE.value() + 1);
 
+    // Variables
+    pub const VAL_USE: OpCode = Self::new_op_code(2);
     pub const CONSTANT_PLACEHOLDER: OpCode = Self::new_op_code(3);
 
+    // Conversion codes
+    pub const LONG_TO_BYTE_ARRAY: OpCode = Self::new_op_code(10);
+    pub const BYTE_ARRAY_TO_BIGINT: OpCode = Self::new_op_code(11);
+    pub const BYTE_ARRAY_TO_LONG: OpCode = Self::new_op_code(12);
+    pub const DOWNCAST: OpCode = Self::new_op_code(13);
+    pub const UPCAST: OpCode = Self::new_op_code(14);
+
+    // Constants
+    pub const GROUP_GENERATOR: OpCode = Self::new_op_code(18);
+
+    // Environment codes
+    pub const HEIGHT: OpCode = Self::new_op_code(51);
+    pub const INPUTS: OpCode = Self::new_op_code(52);
+    pub const OUTPUTS: OpCode = Self::new_op_code(53);
+    pub const SELF_BOX: OpCode = Self::new_op_code(55);
+    pub const MINER_PUBKEY: OpCode = Self::new_op_code(60);
+
+    // Collection and tree operations codes
+    pub const COLL_OF_BOOL_CONST: OpCode = Self::new_op_code(21);
+    pub const TUPLE: OpCode = Self::new_op_code(22);
+    pub const SELECT_FIELD: OpCode = Self::new_op_code(28);
+
     // Relation ops codes
+    pub const LT: OpCode = Self::new_op_code(31);
+    pub const LE: OpCode = Self::new_op_code(32);
+    pub const GT: OpCode = Self::new_op_code(33);
+    pub const GE: OpCode = Self::new_op_code(34);
+    pub const EQ: OpCode = Self::new_op_code(35);
+    pub const NEQ: OpCode = Self::new_op_code(36);
     pub const AND: OpCode = Self::new_op_code(38);
     pub const OR: OpCode = Self::new_op_code(39);
     pub const ATLEAST: OpCode = Self::new_op_code(40);
 
+    // Arithmetic codes
+    pub const MINUS: OpCode = Self::new_op_code(41);
+    pub const PLUS: OpCode = Self::new_op_code(42);
+    pub const XOR: OpCode = Self::new_op_code(43);
+    pub const MULTIPLY: OpCode = Self::new_op_code(44);
+    pub const DIVISION: OpCode = Self::new_op_code(45);
+    pub const MODULO: OpCode = Self::new_op_code(46);
+    pub const EXPONENTIATE: OpCode = Self::new_op_code(47);
+    pub const MULTIPLY_GROUP: OpCode = Self::new_op_code(48);
+    pub const MIN: OpCode = Self::new_op_code(49);
+    pub const MAX: OpCode = Self::new_op_code(50);
+
+    // Collection and tree operations codes
+    pub const MAP: OpCode = Self::new_op_code(61);
+    pub const EXISTS: OpCode = Self::new_op_code(62);
+    pub const FOR_ALL: OpCode = Self::new_op_code(63);
+    pub const FOLD: OpCode = Self::new_op_code(64);
+    pub const SIZE_OF: OpCode = Self::new_op_code(65);
+    pub const BY_INDEX: OpCode = Self::new_op_code(66);
+    pub const APPEND: OpCode = Self::new_op_code(67);
+    pub const SLICE: OpCode = Self::new_op_code(68);
+    pub const FILTER: OpCode = Self::new_op_code(69);
+
+    // Box-related
+    pub const EXTRACT_AMOUNT: OpCode = Self::new_op_code(81);
+    pub const EXTRACT_SCRIPT_BYTES: OpCode = Self::new_op_code(82);
+    pub const EXTRACT_BYTES: OpCode = Self::new_op_code(83);
+    pub const EXTRACT_BYTES_WITH_NO_REF: OpCode = Self::new_op_code(84);
+    pub const EXTRACT_ID: OpCode = Self::new_op_code(85);
+    pub const EXTRACT_REGISTER_AS: OpCode = Self::new_op_code(86);
+    pub const EXTRACT_CREATION_INFO: OpCode = Self::new_op_code(87);
+
     // Cryptographic operations codes
+    pub const CALC_BLAKE2B256: OpCode = Self::new_op_code(91);
+    pub const CALC_SHA256: OpCode = Self::new_op_code(92);
     pub const PROVE_DLOG: OpCode = Self::new_op_code(93);
     pub const PROVE_DIFFIE_HELLMAN_TUPLE: OpCode = Self::new_op_code(94);
+    pub const SIGMA_PROP_BYTES: OpCode = Self::new_op_code(96);
+    pub const BOOL_TO_SIGMA_PROP: OpCode = Self::new_op_code(97);
     pub const TRIVIAL_PROP_FALSE: OpCode = Self::new_op_code(98);
     pub const TRIVIAL_PROP_TRUE: OpCode = Self::new_op_code(99);
 
+    // Deserialization codes
+    pub const DESERIALIZE_CONTEXT: OpCode = Self::new_op_code(100);
+    pub const DESERIALIZE_REGISTER: OpCode = Self::new_op_code(101);
+    pub const VAL_DEF: OpCode = Self::new_op_code(102);
+    pub const BLOCK_VALUE: OpCode = Self::new_op_code(104);
+    pub const FUNC_VALUE: OpCode = Self::new_op_code(105);
+    pub const FUNCTION_APPLY: OpCode = Self::new_op_code(106);
+    pub const PROPERTY_CALL: OpCode = Self::new_op_code(107);
+    pub const METHOD_CALL: OpCode = Self::new_op_code(108);
+
+    // Context variables
+    pub const GET_VAR: OpCode = Self::new_op_code(115);
+
+    // Option ops
+    pub const OPTION_GET: OpCode = Self::new_op_code(116);
+    pub const OPTION_GET_OR_ELSE: OpCode = Self::new_op_code(117);
+    pub const OPTION_IS_DEFINED: OpCode = Self::new_op_code(118);
+
+    // Modular arithmetic operations codes
+    pub const MOD_Q: OpCode = Self::new_op_code(119);
+    pub const PLUS_MOD_Q: OpCode = Self::new_op_code(120);
+    pub const MINUS_MOD_Q: OpCode = Self::new_op_code(121);
+
+    // Sigma propositions
+    pub const SIGMA_AND: OpCode = Self::new_op_code(122);
+    pub const SIGMA_OR: OpCode = Self::new_op_code(123);
+
+    // Boolean operations codes
+    pub const BIN_OR: OpCode = Self::new_op_code(124);
+    pub const BIN_AND: OpCode = Self::new_op_code(125);
+    pub const DECODE_POINT: OpCode = Self::new_op_code(126);
+    pub const LOGICAL_NOT: OpCode = Self::new_op_code(127);
+    pub const BIN_XOR: OpCode = Self::new_op_code(132);
+
+    pub const CONTEXT: OpCode = Self::new_op_code(142);
+
+    // Numeric operations codes
+    pub const NEGATION: OpCode = Self::new_op_code(143);
+
     const fn new_op_code(shift: u8) -> OpCode {
         OpCode(Self::LAST_CONSTANT_CODE.value() + shift)
     }
@@ -1,7 +1,9 @@
 //! Serialization of Ergo types
 use crate::chain::ergo_box::RegisterValueError;
 use crate::ergo_tree::ErgoTreeHeaderError;
+use crate::mir::val_def::ValId;
 use crate::mir::{constant::TryExtractFromError, expr::InvalidArgumentError};
+use crate::types::smethod::SMethodLookupError;
 
 use super::{
     constant_store::ConstantStore,
@@ -69,6 +71,9 @@ pub enum SigmaParsingError {
     /// Constant with given index not found in constant store
     #[error("Constant with index {0} not found in constant store")]
     ConstantForPlaceholderNotFound(u32),
+    /// ValDef type for a given index not found in ValDefTypeStore store
+    #[error("ValDef type for an index {0:?} not found in ValDefTypeStore store")]
+    ValDefIdNotFound(ValId),
     /// Value out of bounds
     #[error("Value out of bounds: {0}")]
     ValueOutOfBounds(String),
@@ -78,6 +83,9 @@ pub enum SigmaParsingError {
     /// Invalid argument on node creation
     #[error("Invalid argument: {0:?}")]
     InvalidArgument(#[from] InvalidArgumentError),
+    /// Unknown type or method id in a method call
+    #[error("method lookup error: {0}")]
+    MethodLookup(#[from] SMethodLookupError),
     /// Feature not supported
     #[error("parsing not supported: {0}")]
     NotSupported(&'static str),
@@ -1,5 +1,6 @@
 //! Sigma byte stream writer
 use super::constant_store::ConstantStore;
+use super::val_def_type_store::ValDefTypeStore;
 use sigma_ser::vlq_encode::ReadSigmaVlqExt;
 use std::io::Cursor;
 use std::io::Read;
@@ -9,6 +10,8 @@ pub struct SigmaByteReader<R> {
     inner: R,
     constant_store: ConstantStore,
     substitute_placeholders: bool,
+    val_def_type_store: ValDefTypeStore,
+    was_deserialize: bool,
 }
 
 impl<R: Read> SigmaByteReader<R> {
@@ -18,6 +21,8 @@ impl<R: Read> SigmaByteReader<R> {
             inner: pr,
             constant_store,
             substitute_placeholders: false,
+            val_def_type_store: ValDefTypeStore::new(),
+            was_deserialize: false,
         }
     }
 
@@ -31,6 +36,8 @@ impl<R: Read> SigmaByteReader<R> {
             inner: pr,
             constant_store,
             substitute_placeholders: true,
+            val_def_type_store: ValDefTypeStore::new(),
+            was_deserialize: false,
         }
     }
 }
@@ -41,6 +48,8 @@ pub fn from_bytes<T: AsRef<[u8]>>(bytes: T) -> SigmaByteReader<Cursor<T>> {
         inner: Cursor::new(bytes),
         constant_store: ConstantStore::empty(),
         substitute_placeholders: false,
+        val_def_type_store: ValDefTypeStore::new(),
+        was_deserialize: false,
     }
 }
 
@@ -54,6 +63,15 @@ pub trait SigmaByteRead: ReadSigmaVlqExt {
 
     /// Set new constant store
     fn set_constant_store(&mut self, constant_store: ConstantStore);
+
+    /// ValDef types store (resolves tpe on ValUse parsing)
+    fn val_def_type_store(&mut self) -> &mut ValDefTypeStore;
+
+    /// Returns true if DeserializeContext or DeserializeRegister was parsed
+    fn was_deserialize(&self) -> bool;
+
+    /// Mark that DeserializeContext or DeserializeRegister was parsed
+    fn set_deserialize(&mut self, has_deserialize: bool);
 }
 
 impl<R: Read> Read for SigmaByteReader<R> {
@@ -74,4 +92,16 @@ impl<R: ReadSigmaVlqExt> SigmaByteRead for SigmaByteReader<R> {
     fn set_constant_store(&mut self, constant_store: ConstantStore) {
         self.constant_store = constant_store;
     }
+
+    fn val_def_type_store(&mut self) -> &mut ValDefTypeStore {
+        &mut self.val_def_type_store
+    }
+
+    fn was_deserialize(&self) -> bool {
+        self.was_deserialize
+    }
+
+    fn set_deserialize(&mut self, has_deserialize: bool) {
+        self.was_deserialize = has_deserialize;
+    }
 }
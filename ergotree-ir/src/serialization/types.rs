@@ -4,8 +4,10 @@ use crate::serialization::SigmaSerializeResult;
 use crate::serialization::{
     sigma_byte_reader::SigmaByteRead, SigmaParsingError, SigmaSerializable,
 };
+use crate::types::sfunc;
 use crate::types::stuple;
 use crate::types::stype::SType;
+use crate::types::stype_param;
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
 use std::convert::TryInto;
@@ -99,12 +101,19 @@ pub enum TypeCode {
 
     SANY = 97,
     SUNIT = 98,
+    SBOX = 99,
+    SAVL_TREE = 100,
+    SCONTEXT = 101,
+    SHEADER = 104,
+    SPREHEADER = 105,
+    STYPE_VAR = 103,
+
+    SFUNC = TypeCode::FIRST_FUNC_TYPE, // 112
 }
 
 impl TypeCode {
     /// SFunc types occupy remaining space of byte values [FirstFuncType .. 255]
-    #[allow(dead_code)]
-    const FIRST_FUNC_TYPE: u8 = OpCode::LAST_DATA_TYPE.value();
+    const FIRST_FUNC_TYPE: u8 = OpCode::LAST_DATA_TYPE.value() + 1;
     #[allow(dead_code)]
     const LAST_FUNC_TYPE: u8 = 255;
 
@@ -307,6 +316,34 @@ impl SType {
 
             TypeCode::SANY => SAny,
             TypeCode::SUNIT => SUnit,
+            TypeCode::SBOX => SBox,
+            TypeCode::SAVL_TREE => SAvlTree,
+            TypeCode::SCONTEXT => SContext,
+            TypeCode::SHEADER => SHeader,
+            TypeCode::SPREHEADER => SPreHeader,
+            TypeCode::STYPE_VAR => {
+                let name_len = r.get_u8()?;
+                let mut name_bytes = vec![0u8; name_len as usize];
+                r.read_exact(&mut name_bytes)?;
+                let name = String::from_utf8(name_bytes).map_err(|_| {
+                    SigmaParsingError::Misc("STypeVar: invalid UTF-8 in name".to_string())
+                })?;
+                STypeVar(stype_param::STypeVar::new(name))
+            }
+
+            TypeCode::SFUNC => {
+                let t_dom_len = r.get_u8()?;
+                let mut t_dom = Vec::with_capacity(t_dom_len as usize);
+                for _ in 0..t_dom_len {
+                    t_dom.push(SType::sigma_parse(r)?);
+                }
+                let t_range = SType::sigma_parse(r)?;
+                let tpe_params_len = r.get_u8()?;
+                if tpe_params_len != 0 {
+                    return Err(SigmaParsingError::NotSupported("SFunc type parameters"));
+                }
+                SFunc(sfunc::SFunc::new(t_dom, t_range))
+            }
         })
     }
 }
@@ -335,6 +372,11 @@ impl SigmaSerializable for SType {
             SType::SBigInt => TypeCode::SBIGINT.sigma_serialize(w),
             SType::SGroupElement => TypeCode::SGROUP_ELEMENT.sigma_serialize(w),
             SType::SSigmaProp => TypeCode::SSIGMAPROP.sigma_serialize(w),
+            SType::SBox => TypeCode::SBOX.sigma_serialize(w),
+            SType::SAvlTree => TypeCode::SAVL_TREE.sigma_serialize(w),
+            SType::SContext => TypeCode::SCONTEXT.sigma_serialize(w),
+            SType::SHeader => TypeCode::SHEADER.sigma_serialize(w),
+            SType::SPreHeader => TypeCode::SPREHEADER.sigma_serialize(w),
             SOption(elem_type) => match &**elem_type {
                 SBoolean => TypeCode::OPTION_BOOL.sigma_serialize(w),
                 SByte => TypeCode::OPTION_BYTE.sigma_serialize(w),
@@ -353,14 +395,16 @@ impl SigmaSerializable for SType {
                     SBigInt => TypeCode::OPTION_COLL_BIGINT.sigma_serialize(w),
                     SGroupElement => TypeCode::OPTION_COLL_GROUP_ELEMENT.sigma_serialize(w),
                     SSigmaProp => TypeCode::OPTION_COLL_SIGMAPROP.sigma_serialize(w),
-                    SAny | SUnit | SOption(_) | SColl(_) | STuple(_) => {
+                    SAny | SUnit | SBox | SAvlTree | SContext | SHeader | SPreHeader
+                    | SOption(_) | SColl(_) | STuple(_) | SFunc(_) | STypeVar(_) => {
                         // if not "embeddable" type fallback to generic Option type code following
                         // elem type code
                         TypeCode::OPTION.sigma_serialize(w)?;
                         elem_type.sigma_serialize(w)
                     }
                 },
-                SAny | SUnit | SOption(_) | STuple(_) => {
+                SAny | SUnit | SBox | SAvlTree | SContext | SHeader | SPreHeader | SOption(_)
+                | STuple(_) | SFunc(_) | STypeVar(_) => {
                     // if not "embeddable" type fallback to generic Option type code following
                     // elem type code
                     TypeCode::OPTION.sigma_serialize(w)?;
@@ -386,14 +430,16 @@ impl SigmaSerializable for SType {
                     SBigInt => TypeCode::NESTED_COLL_BIGINT.sigma_serialize(w),
                     SGroupElement => TypeCode::NESTED_COLL_GROUP_ELEMENT.sigma_serialize(w),
                     SSigmaProp => TypeCode::NESTED_COLL_SIGMAPROP.sigma_serialize(w),
-                    SAny | SUnit | SOption(_) | SColl(_) | STuple(_) => {
+                    SAny | SUnit | SBox | SAvlTree | SContext | SHeader | SPreHeader
+                    | SOption(_) | SColl(_) | STuple(_) | SFunc(_) | STypeVar(_) => {
                         // if not "embeddable" type fallback to generic Coll type code following
                         // elem type code
                         TypeCode::COLL.sigma_serialize(w)?;
                         elem_type.sigma_serialize(w)
                     }
                 },
-                SAny | SUnit | SOption(_) | STuple(_) => {
+                SAny | SUnit | SBox | SAvlTree | SContext | SHeader | SPreHeader | SOption(_)
+                | STuple(_) | SFunc(_) | STypeVar(_) => {
                     // if not "embeddable" type fallback to generic Coll type code following
                     // elem type code
                     TypeCode::COLL.sigma_serialize(w)?;
@@ -480,8 +526,10 @@ impl SigmaSerializable for SType {
                         t1.sigma_serialize(w)
                     }
                     (
-                        SAny | SUnit | SOption(_) | SColl(_) | STuple(_),
-                        SAny | SUnit | SOption(_) | SColl(_) | STuple(_),
+                        SAny | SUnit | SBox | SAvlTree | SContext | SHeader | SPreHeader
+                        | SOption(_) | SColl(_) | STuple(_) | SFunc(_) | STypeVar(_),
+                        SAny | SUnit | SBox | SAvlTree | SContext | SHeader | SPreHeader
+                        | SOption(_) | SColl(_) | STuple(_) | SFunc(_) | STypeVar(_),
                     ) => {
                         // Pair of non-primitive types (`(SBox, SAvlTree)`, `((Int, Byte), (Boolean,Box))`, etc.)
                         TypeCode::TUPLE_PAIR1.sigma_serialize(w)?;
@@ -508,6 +556,22 @@ impl SigmaSerializable for SType {
                     items.iter().try_for_each(|i| i.sigma_serialize(w))
                 }
             },
+            SType::SFunc(sfunc::SFunc { t_dom, t_range }) => {
+                TypeCode::SFUNC.sigma_serialize(w)?;
+                w.put_u8(t_dom.len() as u8)?;
+                t_dom.iter().try_for_each(|t| t.sigma_serialize(w))?;
+                t_range.sigma_serialize(w)?;
+                // type parameters (generic functions are not supported)
+                w.put_u8(0)?;
+                Ok(())
+            }
+            SType::STypeVar(v) => {
+                TypeCode::STYPE_VAR.sigma_serialize(w)?;
+                let name_bytes = v.name().as_bytes();
+                w.put_u8(name_bytes.len() as u8)?;
+                w.write_all(name_bytes)?;
+                Ok(())
+            }
         }
     }
 
@@ -533,4 +597,18 @@ mod tests {
             prop_assert_eq![sigma_serialize_roundtrip(&v), v];
         }
     }
+
+    #[test]
+    fn ser_roundtrip_type_var() {
+        let v = SType::SColl(SType::STypeVar(stype_param::STypeVar::iv()).into());
+        assert_eq![sigma_serialize_roundtrip(&v), v];
+    }
+
+    #[test]
+    fn ser_roundtrip_header_types() {
+        let v = SType::SColl(SType::SHeader.into());
+        assert_eq![sigma_serialize_roundtrip(&v), v];
+        let v = SType::SOption(SType::SPreHeader.into());
+        assert_eq![sigma_serialize_roundtrip(&v), v];
+    }
 }
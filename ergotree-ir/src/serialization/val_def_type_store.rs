@@ -0,0 +1,27 @@
+//! Type store for ValDef's defined in the tree being parsed
+
+use std::collections::HashMap;
+
+use crate::mir::val_def::ValId;
+use crate::types::stype::SType;
+
+/// Storage for ValDef types, used to resolve ValUse types on parsing
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct ValDefTypeStore(HashMap<ValId, SType>);
+
+impl ValDefTypeStore {
+    /// Empty store
+    pub fn new() -> Self {
+        ValDefTypeStore(HashMap::new())
+    }
+
+    /// Get a type for ValDef with the given id
+    pub fn get(&self, id: &ValId) -> Option<&SType> {
+        self.0.get(id)
+    }
+
+    /// Save a type for ValDef with the given id
+    pub fn insert(&mut self, id: ValId, tpe: SType) {
+        self.0.insert(id, tpe);
+    }
+}
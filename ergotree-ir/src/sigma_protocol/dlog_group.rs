@@ -27,6 +27,7 @@ use k256::Scalar;
 use num_bigint::Sign;
 use num_bigint::ToBigUint;
 use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
 use num_traits::ToPrimitive;
 use sigma_ser::ScorexSerializable;
 use std::convert::TryFrom;
@@ -84,6 +85,16 @@ pub fn bigint256_to_scalar(bi: BigInt256) -> Option<Scalar> {
     Scalar::from_repr(bytes.into()).into()
 }
 
+/// Create Scalar from BigInt256 reduced modulo the group order (negative values are
+/// mapped to `q - |bi| mod q`)
+pub fn bigint256_to_scalar_mod_q(bi: BigInt256) -> Scalar {
+    #[allow(clippy::unwrap_used)] // x mod q is in the range [0, q)
+    let bu = BigInt::from(bi).mod_floor(&order()).to_biguint().unwrap();
+    let bytes = biguint_to_bytes(&bu);
+    #[allow(clippy::unwrap_used)] // x mod q is in the range [0, q)
+    Scalar::from_repr(bytes.into()).unwrap()
+}
+
 impl SigmaSerializable for ergo_chain_types::EcPoint {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> SigmaSerializeResult {
         self.scorex_serialize(w)?;
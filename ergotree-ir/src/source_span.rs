@@ -1,6 +1,23 @@
 //! Source position for an IR node in the source code
 
+use crate::mir::apply::Apply;
+use crate::mir::bin_op::BinOp;
+use crate::mir::block::BlockValue;
+use crate::mir::coll_append::Append;
+use crate::mir::coll_by_index::ByIndex;
+use crate::mir::coll_exists::Exists;
+use crate::mir::coll_filter::Filter;
+use crate::mir::coll_fold::Fold;
+use crate::mir::coll_forall::ForAll;
+use crate::mir::coll_map::Map;
+use crate::mir::coll_slice::Slice;
 use crate::mir::expr::Expr;
+use crate::mir::extract_reg_as::ExtractRegisterAs;
+use crate::mir::get_var::GetVar;
+use crate::mir::method_call::MethodCall;
+use crate::mir::option_get::OptionGet;
+use crate::mir::property_call::PropertyCall;
+use crate::mir::val_def::ValDef;
 
 /// Source position for the Expr
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
@@ -61,12 +78,101 @@ impl<T> From<T> for Spanned<T> {
     }
 }
 
+macro_rules! into_expr {
+    ($variant: ident) => {
+        impl From<$variant> for Expr {
+            fn from(v: $variant) -> Self {
+                Expr::$variant(Spanned {
+                    source_span: SourceSpan::empty(),
+                    expr: v,
+                })
+            }
+        }
+    };
+}
+
+into_expr!(BinOp);
+into_expr!(BlockValue);
+into_expr!(ValDef);
+into_expr!(Apply);
+into_expr!(Map);
+into_expr!(Filter);
+into_expr!(Fold);
+into_expr!(Exists);
+into_expr!(ForAll);
+into_expr!(ByIndex);
+into_expr!(Slice);
+into_expr!(Append);
+into_expr!(ExtractRegisterAs);
+into_expr!(GetVar);
+into_expr!(OptionGet);
+into_expr!(PropertyCall);
+into_expr!(MethodCall);
+
 impl Expr {
     /// Source span for the Expr
     pub fn span(&self) -> SourceSpan {
         match self {
             Expr::Const(_) => SourceSpan::empty(),
             Expr::ConstPlaceholder(_) => SourceSpan::empty(),
+            Expr::BinOp(op) => op.source_span,
+            Expr::LogicalNot(_) => SourceSpan::empty(),
+            Expr::BlockValue(op) => op.source_span,
+            Expr::ValDef(op) => op.source_span,
+            Expr::ValUse(_) => SourceSpan::empty(),
+            Expr::FuncValue(_) => SourceSpan::empty(),
+            Expr::Apply(op) => op.source_span,
+            Expr::Map(op) => op.source_span,
+            Expr::Filter(op) => op.source_span,
+            Expr::Fold(op) => op.source_span,
+            Expr::Exists(op) => op.source_span,
+            Expr::ForAll(op) => op.source_span,
+            Expr::ByIndex(op) => op.source_span,
+            Expr::Slice(op) => op.source_span,
+            Expr::Append(op) => op.source_span,
+            Expr::SizeOf(_) => SourceSpan::empty(),
+            Expr::GlobalVars(_) => SourceSpan::empty(),
+            Expr::Context(_) => SourceSpan::empty(),
+            Expr::ExtractAmount(_) => SourceSpan::empty(),
+            Expr::ExtractScriptBytes(_) => SourceSpan::empty(),
+            Expr::ExtractBytes(_) => SourceSpan::empty(),
+            Expr::ExtractBytesWithNoRef(_) => SourceSpan::empty(),
+            Expr::ExtractId(_) => SourceSpan::empty(),
+            Expr::ExtractCreationInfo(_) => SourceSpan::empty(),
+            Expr::ExtractRegisterAs(op) => op.source_span,
+            Expr::GetVar(op) => op.source_span,
+            Expr::DeserializeContext(_) => SourceSpan::empty(),
+            Expr::DeserializeRegister(_) => SourceSpan::empty(),
+            Expr::BoolToSigmaProp(_) => SourceSpan::empty(),
+            Expr::SigmaAnd(_) => SourceSpan::empty(),
+            Expr::SigmaOr(_) => SourceSpan::empty(),
+            Expr::Atleast(_) => SourceSpan::empty(),
+            Expr::CreateProveDlog(_) => SourceSpan::empty(),
+            Expr::CreateProveDhTuple(_) => SourceSpan::empty(),
+            Expr::SigmaPropBytes(_) => SourceSpan::empty(),
+            Expr::OptionGet(op) => op.source_span,
+            Expr::OptionGetOrElse(_) => SourceSpan::empty(),
+            Expr::OptionIsDefined(_) => SourceSpan::empty(),
+            Expr::Tuple(_) => SourceSpan::empty(),
+            Expr::SelectField(_) => SourceSpan::empty(),
+            Expr::CalcBlake2b256(_) => SourceSpan::empty(),
+            Expr::CalcSha256(_) => SourceSpan::empty(),
+            Expr::Xor(_) => SourceSpan::empty(),
+            Expr::LongToByteArray(_) => SourceSpan::empty(),
+            Expr::ByteArrayToLong(_) => SourceSpan::empty(),
+            Expr::ByteArrayToBigInt(_) => SourceSpan::empty(),
+            Expr::Exponentiate(_) => SourceSpan::empty(),
+            Expr::MultiplyGroup(_) => SourceSpan::empty(),
+            Expr::DecodePoint(_) => SourceSpan::empty(),
+            Expr::GroupGenerator(_) => SourceSpan::empty(),
+            Expr::Upcast(_) => SourceSpan::empty(),
+            Expr::Downcast(_) => SourceSpan::empty(),
+            Expr::ModQ(_) => SourceSpan::empty(),
+            Expr::PlusModQ(_) => SourceSpan::empty(),
+            Expr::MinusModQ(_) => SourceSpan::empty(),
+            Expr::Negation(_) => SourceSpan::empty(),
+            Expr::PropertyCall(op) => op.source_span,
+            Expr::MethodCall(op) => op.source_span,
         }
     }
 }
@@ -2,5 +2,20 @@
 
 pub mod stype;
 
+/// Function type
+pub mod sfunc;
+
 /// Tuple type
 pub mod stuple;
+
+pub mod smethod;
+pub mod stype_companion;
+pub mod stype_param;
+pub mod type_unify;
+
+pub mod savltree;
+pub mod scoll;
+pub mod scontext;
+pub mod sgroup_elem;
+pub mod sheader;
+pub mod spreheader;
@@ -0,0 +1,210 @@
+//! SAvlTree type methods
+
+use crate::serialization::types::TypeCode;
+
+use super::sfunc::SFunc;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stuple::STuple;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::TypeId;
+use lazy_static::lazy_static;
+
+/// SAvlTree type id
+pub const TYPE_ID: TypeId = TypeId(TypeCode::SAVL_TREE.value());
+static S_AVL_TREE_TYPE_NAME: &str = "AvlTree";
+
+/// AvlTree.digest property method id
+pub const DIGEST_METHOD_ID: MethodId = MethodId(1);
+/// AvlTree.enabledOperations property method id
+pub const ENABLED_OPERATIONS_METHOD_ID: MethodId = MethodId(2);
+/// AvlTree.keyLength property method id
+pub const KEY_LENGTH_METHOD_ID: MethodId = MethodId(3);
+/// AvlTree.valueLengthOpt property method id
+pub const VALUE_LENGTH_OPT_METHOD_ID: MethodId = MethodId(4);
+/// AvlTree.isInsertAllowed property method id
+pub const IS_INSERT_ALLOWED_METHOD_ID: MethodId = MethodId(5);
+/// AvlTree.isUpdateAllowed property method id
+pub const IS_UPDATE_ALLOWED_METHOD_ID: MethodId = MethodId(6);
+/// AvlTree.isRemoveAllowed property method id
+pub const IS_REMOVE_ALLOWED_METHOD_ID: MethodId = MethodId(7);
+/// AvlTree.updateOperations method id
+pub const UPDATE_OPERATIONS_METHOD_ID: MethodId = MethodId(8);
+/// AvlTree.contains method id
+pub const CONTAINS_METHOD_ID: MethodId = MethodId(9);
+/// AvlTree.get method id
+pub const GET_METHOD_ID: MethodId = MethodId(10);
+/// AvlTree.getMany method id
+pub const GET_MANY_METHOD_ID: MethodId = MethodId(11);
+/// AvlTree.insert method id
+pub const INSERT_METHOD_ID: MethodId = MethodId(12);
+/// AvlTree.update method id
+pub const UPDATE_METHOD_ID: MethodId = MethodId(13);
+/// AvlTree.remove method id
+pub const REMOVE_METHOD_ID: MethodId = MethodId(14);
+/// AvlTree.updateDigest method id
+pub const UPDATE_DIGEST_METHOD_ID: MethodId = MethodId(15);
+
+fn bytes() -> SType {
+    SType::SColl(SType::SByte.into())
+}
+
+fn key_values() -> SType {
+    SType::SColl(SType::STuple(STuple::pair(bytes(), bytes())).into())
+}
+
+lazy_static! {
+    static ref DIGEST_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(SType::SAvlTree, "digest", bytes(), DIGEST_METHOD_ID);
+    static ref ENABLED_OPERATIONS_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SAvlTree,
+        "enabledOperations",
+        SType::SByte,
+        ENABLED_OPERATIONS_METHOD_ID
+    );
+    static ref KEY_LENGTH_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SAvlTree,
+        "keyLength",
+        SType::SInt,
+        KEY_LENGTH_METHOD_ID
+    );
+    static ref VALUE_LENGTH_OPT_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SAvlTree,
+        "valueLengthOpt",
+        SType::SOption(SType::SInt.into()),
+        VALUE_LENGTH_OPT_METHOD_ID
+    );
+    static ref IS_INSERT_ALLOWED_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SAvlTree,
+        "isInsertAllowed",
+        SType::SBoolean,
+        IS_INSERT_ALLOWED_METHOD_ID
+    );
+    static ref IS_UPDATE_ALLOWED_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SAvlTree,
+        "isUpdateAllowed",
+        SType::SBoolean,
+        IS_UPDATE_ALLOWED_METHOD_ID
+    );
+    static ref IS_REMOVE_ALLOWED_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SAvlTree,
+        "isRemoveAllowed",
+        SType::SBoolean,
+        IS_REMOVE_ALLOWED_METHOD_ID
+    );
+    static ref UPDATE_OPERATIONS_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATE_OPERATIONS_METHOD_ID,
+        name: "updateOperations",
+        tpe: SFunc::new(vec![SType::SAvlTree, SType::SByte], SType::SAvlTree),
+    };
+    static ref CONTAINS_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: CONTAINS_METHOD_ID,
+        name: "contains",
+        tpe: SFunc::new(vec![SType::SAvlTree, bytes(), bytes()], SType::SBoolean),
+    };
+    static ref GET_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: GET_METHOD_ID,
+        name: "get",
+        tpe: SFunc::new(
+            vec![SType::SAvlTree, bytes(), bytes()],
+            SType::SOption(bytes().into())
+        ),
+    };
+    static ref GET_MANY_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: GET_MANY_METHOD_ID,
+        name: "getMany",
+        tpe: SFunc::new(
+            vec![SType::SAvlTree, SType::SColl(bytes().into()), bytes()],
+            SType::SColl(SType::SOption(bytes().into()).into())
+        ),
+    };
+    static ref INSERT_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: INSERT_METHOD_ID,
+        name: "insert",
+        tpe: SFunc::new(
+            vec![SType::SAvlTree, key_values(), bytes()],
+            SType::SOption(SType::SAvlTree.into())
+        ),
+    };
+    static ref UPDATE_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATE_METHOD_ID,
+        name: "update",
+        tpe: SFunc::new(
+            vec![SType::SAvlTree, key_values(), bytes()],
+            SType::SOption(SType::SAvlTree.into())
+        ),
+    };
+    static ref REMOVE_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: REMOVE_METHOD_ID,
+        name: "remove",
+        tpe: SFunc::new(
+            vec![SType::SAvlTree, SType::SColl(bytes().into()), bytes()],
+            SType::SOption(SType::SAvlTree.into())
+        ),
+    };
+    static ref UPDATE_DIGEST_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATE_DIGEST_METHOD_ID,
+        name: "updateDigest",
+        tpe: SFunc::new(vec![SType::SAvlTree, bytes()], SType::SAvlTree),
+    };
+}
+
+lazy_static! {
+    /// AvlTree type companion
+    pub static ref TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        TYPE_ID,
+        S_AVL_TREE_TYPE_NAME,
+        vec![
+            DIGEST_METHOD_DESC.clone(),
+            ENABLED_OPERATIONS_METHOD_DESC.clone(),
+            KEY_LENGTH_METHOD_DESC.clone(),
+            VALUE_LENGTH_OPT_METHOD_DESC.clone(),
+            IS_INSERT_ALLOWED_METHOD_DESC.clone(),
+            IS_UPDATE_ALLOWED_METHOD_DESC.clone(),
+            IS_REMOVE_ALLOWED_METHOD_DESC.clone(),
+            UPDATE_OPERATIONS_METHOD_DESC.clone(),
+            CONTAINS_METHOD_DESC.clone(),
+            GET_METHOD_DESC.clone(),
+            GET_MANY_METHOD_DESC.clone(),
+            INSERT_METHOD_DESC.clone(),
+            UPDATE_METHOD_DESC.clone(),
+            REMOVE_METHOD_DESC.clone(),
+            UPDATE_DIGEST_METHOD_DESC.clone(),
+        ]
+    );
+}
+
+lazy_static! {
+    /// AvlTree.digest property
+    pub static ref DIGEST_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, DIGEST_METHOD_DESC.clone());
+    /// AvlTree.enabledOperations property
+    pub static ref ENABLED_OPERATIONS_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, ENABLED_OPERATIONS_METHOD_DESC.clone());
+    /// AvlTree.keyLength property
+    pub static ref KEY_LENGTH_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, KEY_LENGTH_METHOD_DESC.clone());
+    /// AvlTree.valueLengthOpt property
+    pub static ref VALUE_LENGTH_OPT_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, VALUE_LENGTH_OPT_METHOD_DESC.clone());
+    /// AvlTree.isInsertAllowed property
+    pub static ref IS_INSERT_ALLOWED_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, IS_INSERT_ALLOWED_METHOD_DESC.clone());
+    /// AvlTree.isUpdateAllowed property
+    pub static ref IS_UPDATE_ALLOWED_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, IS_UPDATE_ALLOWED_METHOD_DESC.clone());
+    /// AvlTree.isRemoveAllowed property
+    pub static ref IS_REMOVE_ALLOWED_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, IS_REMOVE_ALLOWED_METHOD_DESC.clone());
+    /// AvlTree.updateOperations method
+    pub static ref UPDATE_OPERATIONS_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, UPDATE_OPERATIONS_METHOD_DESC.clone());
+    /// AvlTree.contains method
+    pub static ref CONTAINS_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, CONTAINS_METHOD_DESC.clone());
+    /// AvlTree.get method
+    pub static ref GET_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, GET_METHOD_DESC.clone());
+    /// AvlTree.getMany method
+    pub static ref GET_MANY_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, GET_MANY_METHOD_DESC.clone());
+    /// AvlTree.insert method
+    pub static ref INSERT_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, INSERT_METHOD_DESC.clone());
+    /// AvlTree.update method
+    pub static ref UPDATE_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, UPDATE_METHOD_DESC.clone());
+    /// AvlTree.remove method
+    pub static ref REMOVE_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, REMOVE_METHOD_DESC.clone());
+    /// AvlTree.updateDigest method
+    pub static ref UPDATE_DIGEST_METHOD: SMethod = SMethod::new(&TYPE_COMPANION, UPDATE_DIGEST_METHOD_DESC.clone());
+}
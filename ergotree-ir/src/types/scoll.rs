@@ -0,0 +1,141 @@
+//! SCollection type methods
+
+use crate::serialization::types::TypeCode;
+
+use super::sfunc::SFunc;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stuple::STuple;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::TypeId;
+use super::stype_param::STypeVar;
+use lazy_static::lazy_static;
+
+/// SCollection type id
+pub const TYPE_ID: TypeId = TypeId(TypeCode::COLL.value());
+static S_COLL_TYPE_NAME: &str = "Coll";
+
+/// Coll.getOrElse method id
+pub const GET_OR_ELSE_METHOD_ID: MethodId = MethodId(2);
+/// Coll.indices property method id
+pub const INDICES_METHOD_ID: MethodId = MethodId(14);
+/// Coll.flatMap method id
+pub const FLATMAP_METHOD_ID: MethodId = MethodId(15);
+/// Coll.patch method id
+pub const PATCH_METHOD_ID: MethodId = MethodId(19);
+/// Coll.updated method id
+pub const UPDATED_METHOD_ID: MethodId = MethodId(20);
+/// Coll.updateMany method id
+pub const UPDATE_MANY_METHOD_ID: MethodId = MethodId(21);
+/// Coll.indexOf method id
+pub const INDEX_OF_METHOD_ID: MethodId = MethodId(26);
+/// Coll.zip method id
+pub const ZIP_METHOD_ID: MethodId = MethodId(29);
+
+fn iv() -> SType {
+    SType::STypeVar(STypeVar::iv())
+}
+
+fn ov() -> SType {
+    SType::STypeVar(STypeVar::ov())
+}
+
+fn coll(elem_tpe: SType) -> SType {
+    SType::SColl(elem_tpe.into())
+}
+
+lazy_static! {
+    static ref GET_OR_ELSE_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: GET_OR_ELSE_METHOD_ID,
+        name: "getOrElse",
+        tpe: SFunc::new(vec![coll(iv()), SType::SInt, iv()], iv()),
+    };
+    static ref INDICES_METHOD_DESC: SMethodDesc =
+        SMethodDesc::property(coll(iv()), "indices", coll(SType::SInt), INDICES_METHOD_ID);
+    static ref FLATMAP_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: FLATMAP_METHOD_ID,
+        name: "flatMap",
+        tpe: SFunc::new(
+            vec![coll(iv()), SFunc::new(vec![iv()], coll(ov())).into()],
+            coll(ov()),
+        ),
+    };
+    static ref PATCH_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: PATCH_METHOD_ID,
+        name: "patch",
+        tpe: SFunc::new(
+            vec![coll(iv()), SType::SInt, coll(iv()), SType::SInt],
+            coll(iv()),
+        ),
+    };
+    static ref UPDATED_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATED_METHOD_ID,
+        name: "updated",
+        tpe: SFunc::new(vec![coll(iv()), SType::SInt, iv()], coll(iv())),
+    };
+    static ref UPDATE_MANY_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: UPDATE_MANY_METHOD_ID,
+        name: "updateMany",
+        tpe: SFunc::new(vec![coll(iv()), coll(SType::SInt), coll(iv())], coll(iv()),),
+    };
+    static ref INDEX_OF_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: INDEX_OF_METHOD_ID,
+        name: "indexOf",
+        tpe: SFunc::new(vec![coll(iv()), iv(), SType::SInt], SType::SInt),
+    };
+    static ref ZIP_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: ZIP_METHOD_ID,
+        name: "zip",
+        tpe: SFunc::new(
+            vec![coll(iv()), coll(ov())],
+            coll(STuple::pair(iv(), ov()).into()),
+        ),
+    };
+}
+
+lazy_static! {
+    /// Coll type companion
+    pub static ref TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        TYPE_ID,
+        S_COLL_TYPE_NAME,
+        vec![
+            GET_OR_ELSE_METHOD_DESC.clone(),
+            INDICES_METHOD_DESC.clone(),
+            FLATMAP_METHOD_DESC.clone(),
+            PATCH_METHOD_DESC.clone(),
+            UPDATED_METHOD_DESC.clone(),
+            UPDATE_MANY_METHOD_DESC.clone(),
+            INDEX_OF_METHOD_DESC.clone(),
+            ZIP_METHOD_DESC.clone(),
+        ]
+    );
+}
+
+lazy_static! {
+    /// Coll.getOrElse method
+    pub static ref GET_OR_ELSE_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, GET_OR_ELSE_METHOD_DESC.clone());
+    /// Coll.indices property
+    pub static ref INDICES_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, INDICES_METHOD_DESC.clone());
+    /// Coll.flatMap method
+    pub static ref FLATMAP_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, FLATMAP_METHOD_DESC.clone());
+    /// Coll.patch method
+    pub static ref PATCH_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, PATCH_METHOD_DESC.clone());
+    /// Coll.updated method
+    pub static ref UPDATED_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, UPDATED_METHOD_DESC.clone());
+    /// Coll.updateMany method
+    pub static ref UPDATE_MANY_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, UPDATE_MANY_METHOD_DESC.clone());
+    /// Coll.indexOf method
+    pub static ref INDEX_OF_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, INDEX_OF_METHOD_DESC.clone());
+    /// Coll.zip method
+    pub static ref ZIP_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, ZIP_METHOD_DESC.clone());
+}
@@ -0,0 +1,140 @@
+//! SContext type methods
+
+use crate::serialization::types::TypeCode;
+
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::TypeId;
+use lazy_static::lazy_static;
+
+/// SContext type id
+pub const TYPE_ID: TypeId = TypeId(TypeCode::SCONTEXT.value());
+static S_CONTEXT_TYPE_NAME: &str = "Context";
+
+/// Context.dataInputs property method id
+pub const DATA_INPUTS_PROPERTY_METHOD_ID: MethodId = MethodId(1);
+/// Context.headers property method id
+pub const HEADERS_PROPERTY_METHOD_ID: MethodId = MethodId(2);
+/// Context.preHeader property method id
+pub const PRE_HEADER_PROPERTY_METHOD_ID: MethodId = MethodId(3);
+/// Context.INPUTS property method id
+pub const INPUTS_PROPERTY_METHOD_ID: MethodId = MethodId(4);
+/// Context.OUTPUTS property method id
+pub const OUTPUTS_PROPERTY_METHOD_ID: MethodId = MethodId(5);
+/// Context.HEIGHT property method id
+pub const HEIGHT_PROPERTY_METHOD_ID: MethodId = MethodId(6);
+/// Context.SELF property method id
+pub const SELF_PROPERTY_METHOD_ID: MethodId = MethodId(7);
+/// Context.selfBoxIndex property method id
+pub const SELF_BOX_INDEX_PROPERTY_METHOD_ID: MethodId = MethodId(8);
+/// Context.minerPubKey property method id
+pub const MINER_PUBKEY_PROPERTY_METHOD_ID: MethodId = MethodId(10);
+
+lazy_static! {
+    static ref DATA_INPUTS_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SContext,
+        "dataInputs",
+        SType::SColl(SType::SBox.into()),
+        DATA_INPUTS_PROPERTY_METHOD_ID,
+    );
+    static ref HEADERS_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SContext,
+        "headers",
+        SType::SColl(SType::SHeader.into()),
+        HEADERS_PROPERTY_METHOD_ID,
+    );
+    static ref PRE_HEADER_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SContext,
+        "preHeader",
+        SType::SPreHeader,
+        PRE_HEADER_PROPERTY_METHOD_ID,
+    );
+    static ref INPUTS_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SContext,
+        "INPUTS",
+        SType::SColl(SType::SBox.into()),
+        INPUTS_PROPERTY_METHOD_ID,
+    );
+    static ref OUTPUTS_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SContext,
+        "OUTPUTS",
+        SType::SColl(SType::SBox.into()),
+        OUTPUTS_PROPERTY_METHOD_ID,
+    );
+    static ref HEIGHT_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SContext,
+        "HEIGHT",
+        SType::SInt,
+        HEIGHT_PROPERTY_METHOD_ID,
+    );
+    static ref SELF_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SContext,
+        "SELF",
+        SType::SBox,
+        SELF_PROPERTY_METHOD_ID,
+    );
+    static ref SELF_BOX_INDEX_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SContext,
+        "selfBoxIndex",
+        SType::SInt,
+        SELF_BOX_INDEX_PROPERTY_METHOD_ID,
+    );
+    static ref MINER_PUBKEY_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SContext,
+        "minerPubKey",
+        SType::SColl(SType::SByte.into()),
+        MINER_PUBKEY_PROPERTY_METHOD_ID,
+    );
+}
+
+lazy_static! {
+    /// Context type companion
+    pub static ref TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        TYPE_ID,
+        S_CONTEXT_TYPE_NAME,
+        vec![
+            DATA_INPUTS_PROPERTY_METHOD_DESC.clone(),
+            HEADERS_PROPERTY_METHOD_DESC.clone(),
+            PRE_HEADER_PROPERTY_METHOD_DESC.clone(),
+            INPUTS_PROPERTY_METHOD_DESC.clone(),
+            OUTPUTS_PROPERTY_METHOD_DESC.clone(),
+            HEIGHT_PROPERTY_METHOD_DESC.clone(),
+            SELF_PROPERTY_METHOD_DESC.clone(),
+            SELF_BOX_INDEX_PROPERTY_METHOD_DESC.clone(),
+            MINER_PUBKEY_PROPERTY_METHOD_DESC.clone(),
+        ]
+    );
+}
+
+lazy_static! {
+    /// Context.dataInputs property
+    pub static ref DATA_INPUTS_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, DATA_INPUTS_PROPERTY_METHOD_DESC.clone());
+    /// Context.headers property
+    pub static ref HEADERS_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, HEADERS_PROPERTY_METHOD_DESC.clone());
+    /// Context.preHeader property
+    pub static ref PRE_HEADER_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, PRE_HEADER_PROPERTY_METHOD_DESC.clone());
+    /// Context.INPUTS property
+    pub static ref INPUTS_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, INPUTS_PROPERTY_METHOD_DESC.clone());
+    /// Context.OUTPUTS property
+    pub static ref OUTPUTS_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, OUTPUTS_PROPERTY_METHOD_DESC.clone());
+    /// Context.HEIGHT property
+    pub static ref HEIGHT_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, HEIGHT_PROPERTY_METHOD_DESC.clone());
+    /// Context.SELF property
+    pub static ref SELF_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, SELF_PROPERTY_METHOD_DESC.clone());
+    /// Context.selfBoxIndex property
+    pub static ref SELF_BOX_INDEX_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, SELF_BOX_INDEX_PROPERTY_METHOD_DESC.clone());
+    /// Context.minerPubKey property
+    pub static ref MINER_PUBKEY_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, MINER_PUBKEY_PROPERTY_METHOD_DESC.clone());
+}
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use super::stype::SType;
+use super::type_unify::TypeSubst;
+
+/// Function signature type
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SFunc {
+    /// Function parameter types
+    pub t_dom: Vec<SType>,
+    /// Result type
+    pub t_range: Box<SType>,
+}
+
+impl SFunc {
+    /// Create new SFunc
+    pub fn new(t_dom: Vec<SType>, t_range: SType) -> Self {
+        Self {
+            t_dom,
+            t_range: t_range.into(),
+        }
+    }
+
+    /// Replace type variables with types from the given substitution
+    pub fn with_subst(self, subst: &TypeSubst) -> SFunc {
+        SFunc {
+            t_dom: self
+                .t_dom
+                .into_iter()
+                .map(|t| t.with_subst(subst))
+                .collect(),
+            t_range: self.t_range.with_subst(subst).into(),
+        }
+    }
+}
+
+impl Display for SFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, item) in self.t_dom.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            item.fmt(f)?;
+        }
+        write!(f, ") => ")?;
+        self.t_range.fmt(f)
+    }
+}
@@ -0,0 +1,53 @@
+//! SGroupElement type methods
+
+use crate::serialization::types::TypeCode;
+
+use super::sfunc::SFunc;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::TypeId;
+use lazy_static::lazy_static;
+
+/// SGroupElement type id
+pub const TYPE_ID: TypeId = TypeId(TypeCode::SGROUP_ELEMENT.value());
+static S_GROUP_ELEMENT_TYPE_NAME: &str = "GroupElement";
+
+/// GroupElement.getEncoded property method id
+pub const GET_ENCODED_METHOD_ID: MethodId = MethodId(2);
+/// GroupElement.negate method id
+pub const NEGATE_METHOD_ID: MethodId = MethodId(5);
+
+lazy_static! {
+    static ref GET_ENCODED_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SGroupElement,
+        "getEncoded",
+        SType::SColl(SType::SByte.into()),
+        GET_ENCODED_METHOD_ID,
+    );
+    static ref NEGATE_METHOD_DESC: SMethodDesc = SMethodDesc {
+        method_id: NEGATE_METHOD_ID,
+        name: "negate",
+        tpe: SFunc::new(vec![SType::SGroupElement], SType::SGroupElement),
+    };
+}
+
+lazy_static! {
+    /// GroupElement type companion
+    pub static ref TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        TYPE_ID,
+        S_GROUP_ELEMENT_TYPE_NAME,
+        vec![GET_ENCODED_METHOD_DESC.clone(), NEGATE_METHOD_DESC.clone()]
+    );
+}
+
+lazy_static! {
+    /// GroupElement.getEncoded property
+    pub static ref GET_ENCODED_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, GET_ENCODED_METHOD_DESC.clone());
+    /// GroupElement.negate method
+    pub static ref NEGATE_METHOD: SMethod =
+        SMethod::new(&TYPE_COMPANION, NEGATE_METHOD_DESC.clone());
+}
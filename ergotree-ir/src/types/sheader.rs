@@ -0,0 +1,212 @@
+//! SHeader type methods
+
+use crate::serialization::types::TypeCode;
+
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::TypeId;
+use lazy_static::lazy_static;
+
+/// SHeader type id
+pub const TYPE_ID: TypeId = TypeId(TypeCode::SHEADER.value());
+static S_HEADER_TYPE_NAME: &str = "Header";
+
+/// Header.id property method id
+pub const ID_PROPERTY_METHOD_ID: MethodId = MethodId(1);
+/// Header.version property method id
+pub const VERSION_PROPERTY_METHOD_ID: MethodId = MethodId(2);
+/// Header.parentId property method id
+pub const PARENT_ID_PROPERTY_METHOD_ID: MethodId = MethodId(3);
+/// Header.ADProofsRoot property method id
+pub const AD_PROOFS_ROOT_PROPERTY_METHOD_ID: MethodId = MethodId(4);
+/// Header.stateRoot property method id
+pub const STATE_ROOT_PROPERTY_METHOD_ID: MethodId = MethodId(5);
+/// Header.transactionsRoot property method id
+pub const TRANSACTIONS_ROOT_PROPERTY_METHOD_ID: MethodId = MethodId(6);
+/// Header.timestamp property method id
+pub const TIMESTAMP_PROPERTY_METHOD_ID: MethodId = MethodId(7);
+/// Header.nBits property method id
+pub const N_BITS_PROPERTY_METHOD_ID: MethodId = MethodId(8);
+/// Header.height property method id
+pub const HEIGHT_PROPERTY_METHOD_ID: MethodId = MethodId(9);
+/// Header.extensionRoot property method id
+pub const EXTENSION_ROOT_PROPERTY_METHOD_ID: MethodId = MethodId(10);
+/// Header.minerPk property method id
+pub const MINER_PK_PROPERTY_METHOD_ID: MethodId = MethodId(11);
+/// Header.powOnetimePk property method id
+pub const POW_ONETIME_PK_PROPERTY_METHOD_ID: MethodId = MethodId(12);
+/// Header.powNonce property method id
+pub const POW_NONCE_PROPERTY_METHOD_ID: MethodId = MethodId(13);
+/// Header.powDistance property method id
+pub const POW_DISTANCE_PROPERTY_METHOD_ID: MethodId = MethodId(14);
+/// Header.votes property method id
+pub const VOTES_PROPERTY_METHOD_ID: MethodId = MethodId(15);
+
+lazy_static! {
+    static ref ID_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "id",
+        SType::SColl(SType::SByte.into()),
+        ID_PROPERTY_METHOD_ID,
+    );
+    static ref VERSION_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "version",
+        SType::SByte,
+        VERSION_PROPERTY_METHOD_ID,
+    );
+    static ref PARENT_ID_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "parentId",
+        SType::SColl(SType::SByte.into()),
+        PARENT_ID_PROPERTY_METHOD_ID,
+    );
+    static ref AD_PROOFS_ROOT_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "ADProofsRoot",
+        SType::SColl(SType::SByte.into()),
+        AD_PROOFS_ROOT_PROPERTY_METHOD_ID,
+    );
+    static ref STATE_ROOT_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "stateRoot",
+        SType::SAvlTree,
+        STATE_ROOT_PROPERTY_METHOD_ID,
+    );
+    static ref TRANSACTIONS_ROOT_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "transactionsRoot",
+        SType::SColl(SType::SByte.into()),
+        TRANSACTIONS_ROOT_PROPERTY_METHOD_ID,
+    );
+    static ref TIMESTAMP_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "timestamp",
+        SType::SLong,
+        TIMESTAMP_PROPERTY_METHOD_ID,
+    );
+    static ref N_BITS_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "nBits",
+        SType::SLong,
+        N_BITS_PROPERTY_METHOD_ID,
+    );
+    static ref HEIGHT_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "height",
+        SType::SInt,
+        HEIGHT_PROPERTY_METHOD_ID,
+    );
+    static ref EXTENSION_ROOT_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "extensionRoot",
+        SType::SColl(SType::SByte.into()),
+        EXTENSION_ROOT_PROPERTY_METHOD_ID,
+    );
+    static ref MINER_PK_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "minerPk",
+        SType::SGroupElement,
+        MINER_PK_PROPERTY_METHOD_ID,
+    );
+    static ref POW_ONETIME_PK_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "powOnetimePk",
+        SType::SGroupElement,
+        POW_ONETIME_PK_PROPERTY_METHOD_ID,
+    );
+    static ref POW_NONCE_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "powNonce",
+        SType::SColl(SType::SByte.into()),
+        POW_NONCE_PROPERTY_METHOD_ID,
+    );
+    static ref POW_DISTANCE_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "powDistance",
+        SType::SBigInt,
+        POW_DISTANCE_PROPERTY_METHOD_ID,
+    );
+    static ref VOTES_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SHeader,
+        "votes",
+        SType::SColl(SType::SByte.into()),
+        VOTES_PROPERTY_METHOD_ID,
+    );
+}
+
+lazy_static! {
+    /// Header type companion
+    pub static ref TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        TYPE_ID,
+        S_HEADER_TYPE_NAME,
+        vec![
+            ID_PROPERTY_METHOD_DESC.clone(),
+            VERSION_PROPERTY_METHOD_DESC.clone(),
+            PARENT_ID_PROPERTY_METHOD_DESC.clone(),
+            AD_PROOFS_ROOT_PROPERTY_METHOD_DESC.clone(),
+            STATE_ROOT_PROPERTY_METHOD_DESC.clone(),
+            TRANSACTIONS_ROOT_PROPERTY_METHOD_DESC.clone(),
+            TIMESTAMP_PROPERTY_METHOD_DESC.clone(),
+            N_BITS_PROPERTY_METHOD_DESC.clone(),
+            HEIGHT_PROPERTY_METHOD_DESC.clone(),
+            EXTENSION_ROOT_PROPERTY_METHOD_DESC.clone(),
+            MINER_PK_PROPERTY_METHOD_DESC.clone(),
+            POW_ONETIME_PK_PROPERTY_METHOD_DESC.clone(),
+            POW_NONCE_PROPERTY_METHOD_DESC.clone(),
+            POW_DISTANCE_PROPERTY_METHOD_DESC.clone(),
+            VOTES_PROPERTY_METHOD_DESC.clone(),
+        ]
+    );
+}
+
+lazy_static! {
+    /// Header.id property
+    pub static ref ID_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, ID_PROPERTY_METHOD_DESC.clone());
+    /// Header.version property
+    pub static ref VERSION_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, VERSION_PROPERTY_METHOD_DESC.clone());
+    /// Header.parentId property
+    pub static ref PARENT_ID_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, PARENT_ID_PROPERTY_METHOD_DESC.clone());
+    /// Header.ADProofsRoot property
+    pub static ref AD_PROOFS_ROOT_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, AD_PROOFS_ROOT_PROPERTY_METHOD_DESC.clone());
+    /// Header.stateRoot property
+    pub static ref STATE_ROOT_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, STATE_ROOT_PROPERTY_METHOD_DESC.clone());
+    /// Header.transactionsRoot property
+    pub static ref TRANSACTIONS_ROOT_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, TRANSACTIONS_ROOT_PROPERTY_METHOD_DESC.clone());
+    /// Header.timestamp property
+    pub static ref TIMESTAMP_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, TIMESTAMP_PROPERTY_METHOD_DESC.clone());
+    /// Header.nBits property
+    pub static ref N_BITS_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, N_BITS_PROPERTY_METHOD_DESC.clone());
+    /// Header.height property
+    pub static ref HEIGHT_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, HEIGHT_PROPERTY_METHOD_DESC.clone());
+    /// Header.extensionRoot property
+    pub static ref EXTENSION_ROOT_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, EXTENSION_ROOT_PROPERTY_METHOD_DESC.clone());
+    /// Header.minerPk property
+    pub static ref MINER_PK_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, MINER_PK_PROPERTY_METHOD_DESC.clone());
+    /// Header.powOnetimePk property
+    pub static ref POW_ONETIME_PK_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, POW_ONETIME_PK_PROPERTY_METHOD_DESC.clone());
+    /// Header.powNonce property
+    pub static ref POW_NONCE_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, POW_NONCE_PROPERTY_METHOD_DESC.clone());
+    /// Header.powDistance property
+    pub static ref POW_DISTANCE_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, POW_DISTANCE_PROPERTY_METHOD_DESC.clone());
+    /// Header.votes property
+    pub static ref VOTES_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, VOTES_PROPERTY_METHOD_DESC.clone());
+}
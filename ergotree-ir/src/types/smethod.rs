@@ -0,0 +1,125 @@
+//! Object method(property) signature type
+
+use thiserror::Error;
+
+use super::sfunc::SFunc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::TypeId;
+use super::type_unify::unify_many;
+use super::type_unify::TypeUnificationError;
+
+/// Method id unique among the methods of the same object
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct MethodId(pub u8);
+
+impl MethodId {
+    /// Method id value
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+/// Object method signature
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SMethod {
+    /// Object type companion
+    obj_type: &'static STypeCompanion,
+    method_raw: SMethodDesc,
+}
+
+impl SMethod {
+    /// Create new SMethod
+    pub(crate) fn new(obj_type: &'static STypeCompanion, method_raw: SMethodDesc) -> SMethod {
+        SMethod {
+            obj_type,
+            method_raw,
+        }
+    }
+
+    /// Get method from type id and method id
+    pub fn from_ids(type_id: TypeId, method_id: MethodId) -> Result<Self, SMethodLookupError> {
+        let obj_type = STypeCompanion::type_by_id(type_id)?;
+        obj_type
+            .method_by_id(method_id)
+            .ok_or(SMethodLookupError::MethodNotFound(type_id, method_id))
+    }
+
+    /// Type of the object this method belongs to
+    pub fn obj_type(&self) -> &'static STypeCompanion {
+        self.obj_type
+    }
+
+    /// Type id of the object this method belongs to
+    pub fn type_id(&self) -> TypeId {
+        self.obj_type.type_id()
+    }
+
+    /// Method id
+    pub fn method_id(&self) -> MethodId {
+        self.method_raw.method_id
+    }
+
+    /// Method name
+    pub fn name(&self) -> &'static str {
+        self.method_raw.name
+    }
+
+    /// Method signature type, the first argument is the object type
+    pub fn tpe(&self) -> &SFunc {
+        &self.method_raw.tpe
+    }
+
+    /// Specialize the generic signature of the method for the given object and argument types
+    pub fn specialize_for(
+        self,
+        obj_tpe: SType,
+        args: Vec<SType>,
+    ) -> Result<SMethod, TypeUnificationError> {
+        let mut obj_and_args = vec![obj_tpe];
+        obj_and_args.extend(args);
+        let subst = unify_many(&self.method_raw.tpe.t_dom, &obj_and_args)?;
+        Ok(SMethod {
+            obj_type: self.obj_type,
+            method_raw: SMethodDesc {
+                tpe: self.method_raw.tpe.with_subst(&subst),
+                ..self.method_raw
+            },
+        })
+    }
+}
+
+/// Method info including name, id and type
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SMethodDesc {
+    pub(crate) name: &'static str,
+    pub(crate) method_id: MethodId,
+    pub(crate) tpe: SFunc,
+}
+
+impl SMethodDesc {
+    /// Create a property (method without arguments besides the object itself) descriptor
+    pub(crate) fn property(
+        obj_tpe: SType,
+        name: &'static str,
+        res_tpe: SType,
+        id: MethodId,
+    ) -> SMethodDesc {
+        SMethodDesc {
+            method_id: id,
+            name,
+            tpe: SFunc::new(vec![obj_tpe], res_tpe),
+        }
+    }
+}
+
+/// Method lookup error
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum SMethodLookupError {
+    /// Type with the given id has no companion (no methods)
+    #[error("type companion for type id {0:?} not found")]
+    TypeNotFound(TypeId),
+    /// Method with the given id is not found in the type
+    #[error("method id {1:?} not found in type with id {0:?}")]
+    MethodNotFound(TypeId, MethodId),
+}
@@ -0,0 +1,116 @@
+//! SPreHeader type methods
+
+use crate::serialization::types::TypeCode;
+
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::TypeId;
+use lazy_static::lazy_static;
+
+/// SPreHeader type id
+pub const TYPE_ID: TypeId = TypeId(TypeCode::SPREHEADER.value());
+static S_PRE_HEADER_TYPE_NAME: &str = "PreHeader";
+
+/// PreHeader.version property method id
+pub const VERSION_PROPERTY_METHOD_ID: MethodId = MethodId(1);
+/// PreHeader.parentId property method id
+pub const PARENT_ID_PROPERTY_METHOD_ID: MethodId = MethodId(2);
+/// PreHeader.timestamp property method id
+pub const TIMESTAMP_PROPERTY_METHOD_ID: MethodId = MethodId(3);
+/// PreHeader.nBits property method id
+pub const N_BITS_PROPERTY_METHOD_ID: MethodId = MethodId(4);
+/// PreHeader.height property method id
+pub const HEIGHT_PROPERTY_METHOD_ID: MethodId = MethodId(5);
+/// PreHeader.minerPk property method id
+pub const MINER_PK_PROPERTY_METHOD_ID: MethodId = MethodId(6);
+/// PreHeader.votes property method id
+pub const VOTES_PROPERTY_METHOD_ID: MethodId = MethodId(7);
+
+lazy_static! {
+    static ref VERSION_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SPreHeader,
+        "version",
+        SType::SByte,
+        VERSION_PROPERTY_METHOD_ID,
+    );
+    static ref PARENT_ID_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SPreHeader,
+        "parentId",
+        SType::SColl(SType::SByte.into()),
+        PARENT_ID_PROPERTY_METHOD_ID,
+    );
+    static ref TIMESTAMP_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SPreHeader,
+        "timestamp",
+        SType::SLong,
+        TIMESTAMP_PROPERTY_METHOD_ID,
+    );
+    static ref N_BITS_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SPreHeader,
+        "nBits",
+        SType::SLong,
+        N_BITS_PROPERTY_METHOD_ID,
+    );
+    static ref HEIGHT_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SPreHeader,
+        "height",
+        SType::SInt,
+        HEIGHT_PROPERTY_METHOD_ID,
+    );
+    static ref MINER_PK_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SPreHeader,
+        "minerPk",
+        SType::SGroupElement,
+        MINER_PK_PROPERTY_METHOD_ID,
+    );
+    static ref VOTES_PROPERTY_METHOD_DESC: SMethodDesc = SMethodDesc::property(
+        SType::SPreHeader,
+        "votes",
+        SType::SColl(SType::SByte.into()),
+        VOTES_PROPERTY_METHOD_ID,
+    );
+}
+
+lazy_static! {
+    /// PreHeader type companion
+    pub static ref TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        TYPE_ID,
+        S_PRE_HEADER_TYPE_NAME,
+        vec![
+            VERSION_PROPERTY_METHOD_DESC.clone(),
+            PARENT_ID_PROPERTY_METHOD_DESC.clone(),
+            TIMESTAMP_PROPERTY_METHOD_DESC.clone(),
+            N_BITS_PROPERTY_METHOD_DESC.clone(),
+            HEIGHT_PROPERTY_METHOD_DESC.clone(),
+            MINER_PK_PROPERTY_METHOD_DESC.clone(),
+            VOTES_PROPERTY_METHOD_DESC.clone(),
+        ]
+    );
+}
+
+lazy_static! {
+    /// PreHeader.version property
+    pub static ref VERSION_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, VERSION_PROPERTY_METHOD_DESC.clone());
+    /// PreHeader.parentId property
+    pub static ref PARENT_ID_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, PARENT_ID_PROPERTY_METHOD_DESC.clone());
+    /// PreHeader.timestamp property
+    pub static ref TIMESTAMP_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, TIMESTAMP_PROPERTY_METHOD_DESC.clone());
+    /// PreHeader.nBits property
+    pub static ref N_BITS_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, N_BITS_PROPERTY_METHOD_DESC.clone());
+    /// PreHeader.height property
+    pub static ref HEIGHT_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, HEIGHT_PROPERTY_METHOD_DESC.clone());
+    /// PreHeader.minerPk property
+    pub static ref MINER_PK_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, MINER_PK_PROPERTY_METHOD_DESC.clone());
+    /// PreHeader.votes property
+    pub static ref VOTES_PROPERTY: SMethod =
+        SMethod::new(&TYPE_COMPANION, VOTES_PROPERTY_METHOD_DESC.clone());
+}
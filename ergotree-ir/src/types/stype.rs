@@ -2,17 +2,25 @@
 
 use std::convert::TryInto;
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use impl_trait_for_tuples::impl_for_tuples;
 
 use crate::bigint256::BigInt256;
+use crate::chain::ergo_box::ErgoBox;
+use crate::mir::avl_tree_data::AvlTreeData;
 use crate::sigma_protocol::sigma_boolean::SigmaBoolean;
 use crate::sigma_protocol::sigma_boolean::SigmaProofOfKnowledgeTree;
 use crate::sigma_protocol::sigma_boolean::SigmaProp;
 use crate::sigma_protocol::sigma_boolean::{ProveDhTuple, ProveDlog};
 use ergo_chain_types::EcPoint;
+use ergo_chain_types::Header;
+use ergo_chain_types::PreHeader;
 
+use super::sfunc::SFunc;
 use super::stuple::STuple;
+use super::stype_param::STypeVar;
+use super::type_unify::TypeSubst;
 
 /// Every type descriptor is a tree represented by nodes in SType hierarchy.
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -37,12 +45,26 @@ pub enum SType {
     SGroupElement,
     /// Proposition which can be proven and verified by sigma protocol.
     SSigmaProp,
+    /// ErgoBox value
+    SBox,
+    /// AVL tree value
+    SAvlTree,
+    /// Context object ("CONTEXT" in ErgoScript)
+    SContext,
+    /// Block header
+    SHeader,
+    /// Header without proofs (predicted by a miner)
+    SPreHeader,
     /// Optional value
     SOption(Box<SType>),
     /// Collection of elements of the same type
     SColl(Box<SType>),
     /// Tuple (elements can have different types)
     STuple(STuple),
+    /// Function (signature)
+    SFunc(SFunc),
+    /// Type variable (generic signatures only)
+    STypeVar(STypeVar),
 }
 
 impl SType {
@@ -71,12 +93,34 @@ impl SType {
     }
 }
 
+impl SType {
+    /// Replace type variables with types from the given substitution
+    pub fn with_subst(self, subst: &TypeSubst) -> SType {
+        match self {
+            SType::STypeVar(ref v) => subst.get(v).cloned().unwrap_or(self),
+            SType::SOption(t) => SType::SOption(t.with_subst(subst).into()),
+            SType::SColl(t) => SType::SColl(t.with_subst(subst).into()),
+            SType::STuple(t) => SType::STuple(STuple {
+                items: t.items.mapped(|i| i.with_subst(subst)),
+            }),
+            SType::SFunc(f) => SType::SFunc(f.with_subst(subst)),
+            t => t,
+        }
+    }
+}
+
 impl From<STuple> for SType {
     fn from(v: STuple) -> Self {
         SType::STuple(v)
     }
 }
 
+impl From<SFunc> for SType {
+    fn from(v: SFunc) -> Self {
+        SType::SFunc(v)
+    }
+}
+
 impl std::fmt::Display for SType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -90,9 +134,16 @@ impl std::fmt::Display for SType {
             SType::SBigInt => write!(f, "BigInt"),
             SType::SGroupElement => write!(f, "GroupElement"),
             SType::SSigmaProp => write!(f, "SigmaProp"),
+            SType::SBox => write!(f, "Box"),
+            SType::SAvlTree => write!(f, "AvlTree"),
+            SType::SContext => write!(f, "Context"),
+            SType::SHeader => write!(f, "Header"),
+            SType::SPreHeader => write!(f, "PreHeader"),
             SType::SOption(t) => write!(f, "Option[{}]", t),
             SType::SColl(t) => write!(f, "Coll[{}]", t),
             SType::STuple(t) => write!(f, "{}", t),
+            SType::SFunc(t) => write!(f, "{}", t),
+            SType::STypeVar(t) => write!(f, "{}", t),
         }
     }
 }
@@ -175,6 +226,30 @@ impl LiftIntoSType for EcPoint {
     }
 }
 
+impl LiftIntoSType for Arc<ErgoBox> {
+    fn stype() -> SType {
+        SType::SBox
+    }
+}
+
+impl LiftIntoSType for AvlTreeData {
+    fn stype() -> SType {
+        SType::SAvlTree
+    }
+}
+
+impl LiftIntoSType for Header {
+    fn stype() -> SType {
+        SType::SHeader
+    }
+}
+
+impl LiftIntoSType for PreHeader {
+    fn stype() -> SType {
+        SType::SPreHeader
+    }
+}
+
 impl LiftIntoSType for BigInt256 {
     fn stype() -> SType {
         SType::SBigInt
@@ -238,7 +313,9 @@ pub(crate) mod tests {
                             prop::collection::vec(elem.clone(), 2..=5)
                                 .prop_map(|elems| SType::STuple(elems.try_into().unwrap())),
                             elem.clone().prop_map(|tpe| SType::SColl(Box::new(tpe))),
-                            elem.prop_map(|tpe| SType::SOption(Box::new(tpe))),
+                            elem.clone().prop_map(|tpe| SType::SOption(Box::new(tpe))),
+                            (prop::collection::vec(elem.clone(), 0..=3), elem)
+                                .prop_map(|(t_dom, t_range)| SFunc::new(t_dom, t_range).into()),
                         ]
                     },
                 )
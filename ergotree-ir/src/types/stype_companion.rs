@@ -0,0 +1,119 @@
+//! Type companions (types with methods)
+
+use lazy_static::lazy_static;
+
+use super::savltree;
+use super::scoll;
+use super::scontext;
+use super::sgroup_elem;
+use super::sheader;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::smethod::SMethodLookupError;
+use super::spreheader;
+
+/// Type id (type code of the type in the serialized form)
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub struct TypeId(pub u8);
+
+impl TypeId {
+    /// Type id value
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+/// Type companion holding the type methods
+#[derive(PartialEq, Eq, Debug)]
+pub struct STypeCompanion {
+    type_id: TypeId,
+    type_name: &'static str,
+    methods: Vec<SMethodDesc>,
+}
+
+lazy_static! {
+    static ref TYPE_COMPANIONS: Vec<&'static STypeCompanion> = vec![
+        &scontext::TYPE_COMPANION,
+        &sgroup_elem::TYPE_COMPANION,
+        &scoll::TYPE_COMPANION,
+        &sheader::TYPE_COMPANION,
+        &spreheader::TYPE_COMPANION,
+        &savltree::TYPE_COMPANION,
+    ];
+}
+
+impl STypeCompanion {
+    /// Create new type companion
+    pub(crate) fn new(
+        type_id: TypeId,
+        type_name: &'static str,
+        methods: Vec<SMethodDesc>,
+    ) -> STypeCompanion {
+        STypeCompanion {
+            type_id,
+            type_name,
+            methods,
+        }
+    }
+
+    /// Get type companion by type id
+    pub fn type_by_id(type_id: TypeId) -> Result<&'static STypeCompanion, SMethodLookupError> {
+        TYPE_COMPANIONS
+            .iter()
+            .find(|c| c.type_id == type_id)
+            .copied()
+            .ok_or(SMethodLookupError::TypeNotFound(type_id))
+    }
+
+    /// Type id
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Type name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// All methods of the type
+    pub fn methods(&'static self) -> Vec<SMethod> {
+        self.methods
+            .iter()
+            .map(|m| SMethod::new(self, m.clone()))
+            .collect()
+    }
+
+    /// Get method by method id
+    pub fn method_by_id(&'static self, method_id: MethodId) -> Option<SMethod> {
+        self.methods
+            .iter()
+            .find(|m| m.method_id == method_id)
+            .map(|m| SMethod::new(self, m.clone()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_method_ids() {
+        TYPE_COMPANIONS.iter().for_each(|c| {
+            let mut ids: Vec<u8> = c.methods.iter().map(|m| m.method_id.value()).collect();
+            ids.sort_unstable();
+            ids.dedup();
+            assert_eq!(ids.len(), c.methods.len(), "type {}", c.type_name);
+        });
+    }
+
+    #[test]
+    fn method_lookup() {
+        let m =
+            SMethod::from_ids(scontext::TYPE_ID, scontext::DATA_INPUTS_PROPERTY_METHOD_ID).unwrap();
+        assert_eq!(m, *scontext::DATA_INPUTS_PROPERTY);
+        assert!(SMethod::from_ids(TypeId(1), MethodId(1)).is_err());
+        assert!(SMethod::from_ids(scontext::TYPE_ID, MethodId(255)).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+//! Type parameters of generic signatures
+
+use std::fmt::Display;
+
+/// Type variable for generic signatures (i.e. `IV` in `Coll[IV]`)
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct STypeVar {
+    name: String,
+}
+
+impl STypeVar {
+    /// Create a type variable with the given name
+    pub fn new(name: String) -> Self {
+        STypeVar { name }
+    }
+
+    /// "T" type variable
+    pub fn t() -> Self {
+        STypeVar::new("T".to_string())
+    }
+
+    /// "IV" type variable (input value)
+    pub fn iv() -> Self {
+        STypeVar::new("IV".to_string())
+    }
+
+    /// "OV" type variable (output value)
+    pub fn ov() -> Self {
+        STypeVar::new("OV".to_string())
+    }
+
+    /// Type variable name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for STypeVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,128 @@
+//! Type unification for generic method signatures
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use super::sfunc::SFunc;
+use super::stype::SType;
+use super::stype_param::STypeVar;
+
+/// Substitution of type variables with concrete types
+pub type TypeSubst = HashMap<STypeVar, SType>;
+
+/// Types cannot be unified
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+#[error("type unification error: {0}")]
+pub struct TypeUnificationError(pub String);
+
+/// Find a substitution of type variables in `t1` that makes it equal to `t2`
+pub fn unify_one(t1: &SType, t2: &SType) -> Result<TypeSubst, TypeUnificationError> {
+    use SType::*;
+    match (t1, t2) {
+        (STypeVar(v1), STypeVar(v2)) if v1 == v2 => Ok(TypeSubst::new()),
+        (STypeVar(v), t) => Ok(std::iter::once((v.clone(), t.clone())).collect()),
+        (SColl(e1), SColl(e2)) | (SOption(e1), SOption(e2)) => unify_one(e1, e2),
+        (STuple(t1), STuple(t2)) if t1.items.len() == t2.items.len() => {
+            unify_many(t1.items.as_slice(), t2.items.as_slice())
+        }
+        (SFunc(f1), SFunc(f2)) => unify_many(
+            &func_types(f1).collect::<Vec<_>>(),
+            &func_types(f2).collect::<Vec<_>>(),
+        ),
+        (SAny, _) => Ok(TypeSubst::new()),
+        (t1, t2) if t1 == t2 => Ok(TypeSubst::new()),
+        (t1, t2) => Err(TypeUnificationError(format!(
+            "cannot unify {0} and {1}",
+            t1, t2
+        ))),
+    }
+}
+
+/// Find a substitution of type variables in `t1s` that makes them equal to `t2s` pairwise
+pub fn unify_many(t1s: &[SType], t2s: &[SType]) -> Result<TypeSubst, TypeUnificationError> {
+    if t1s.len() != t2s.len() {
+        return Err(TypeUnificationError(format!(
+            "different number of types {0:?} and {1:?}",
+            t1s, t2s
+        )));
+    }
+    let mut subst = TypeSubst::new();
+    for (t1, t2) in t1s.iter().zip(t2s) {
+        for (var, tpe) in unify_one(t1, t2)? {
+            match subst.get(&var) {
+                Some(existing) if existing != &tpe => {
+                    return Err(TypeUnificationError(format!(
+                        "type variable {0} is bound to both {1} and {2}",
+                        var, existing, tpe
+                    )))
+                }
+                _ => {
+                    subst.insert(var, tpe);
+                }
+            }
+        }
+    }
+    Ok(subst)
+}
+
+fn func_types(f: &SFunc) -> impl Iterator<Item = SType> + '_ {
+    f.t_dom
+        .iter()
+        .cloned()
+        .chain(std::iter::once(*f.t_range.clone()))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::types::stuple::STuple;
+
+    fn iv() -> SType {
+        SType::STypeVar(STypeVar::iv())
+    }
+
+    fn ov() -> SType {
+        SType::STypeVar(STypeVar::ov())
+    }
+
+    #[test]
+    fn unify_coll() {
+        let subst = unify_one(
+            &SType::SColl(iv().into()),
+            &SType::SColl(SType::SInt.into()),
+        )
+        .unwrap();
+        assert_eq!(subst.get(&STypeVar::iv()), Some(&SType::SInt));
+        assert!(unify_one(&SType::SColl(iv().into()), &SType::SInt).is_err());
+    }
+
+    #[test]
+    fn unify_func_and_tuple() {
+        let subst = unify_many(
+            &[
+                SType::SColl(iv().into()),
+                SFunc::new(vec![iv()], SType::SColl(ov().into())).into(),
+            ],
+            &[
+                SType::SColl(SType::SBox.into()),
+                SFunc::new(vec![SType::SBox], SType::SColl(SType::SByte.into())).into(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            SType::STuple(STuple::pair(iv(), ov())).with_subst(&subst),
+            SType::STuple(STuple::pair(SType::SBox, SType::SByte))
+        );
+    }
+
+    #[test]
+    fn unify_conflict() {
+        assert!(unify_many(
+            &[SType::SColl(iv().into()), iv()],
+            &[SType::SColl(SType::SInt.into()), SType::SLong]
+        )
+        .is_err());
+    }
+}